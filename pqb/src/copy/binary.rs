@@ -0,0 +1,451 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::copy::EncodeError;
+use crate::query::CopyStatement;
+use crate::table::ColumnType;
+use crate::table::write_column_type;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::TableName;
+use crate::value::Value;
+
+const SIGNATURE: &[u8] = b"PGCOPY\n\xff\r\n\0";
+
+/// Encode rows into the binary format of `COPY ... FROM STDIN (FORMAT binary)`.
+///
+/// The binary format carries no type information, so every column is declared with its
+/// [`ColumnType`], and each value is converted to the exact representation of that type. Integer
+/// values are widened or narrowed as long as they fit.
+///
+/// Supported column types are booleans, integers, floating-point numbers, `numeric` (from integers,
+/// floats or decimal strings), character types (from strings), `bytea` (the bytes of a string),
+/// `json` and `jsonb` (from JSON values or strings), `uuid`, and arrays of these types. Use the
+/// text encoders for other types, such as dates, timestamps and user-defined types.
+#[derive(Debug, Clone)]
+pub struct BinaryCopyEncoder {
+    table: TableName,
+    columns: Vec<(Iden, ColumnType)>,
+    buf: Vec<u8>,
+}
+
+impl BinaryCopyEncoder {
+    /// Create an encoder for rows of the given table.
+    pub fn new<T>(table: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        let mut buf = Vec::with_capacity(SIGNATURE.len() + 8);
+        buf.extend_from_slice(SIGNATURE);
+        // flags field and header extension area length
+        buf.extend_from_slice(&0i32.to_be_bytes());
+        buf.extend_from_slice(&0i32.to_be_bytes());
+        Self {
+            table: table.into(),
+            columns: vec![],
+            buf,
+        }
+    }
+
+    /// Add a column and its type.
+    ///
+    /// ## Panics
+    /// This method will panic if the encoder already has 32767 columns, the most a binary COPY
+    /// row can hold.
+    pub fn column<C>(mut self, name: C, ty: ColumnType) -> Self
+    where
+        C: IntoIden,
+    {
+        if self.columns.len() >= i16::MAX as usize {
+            panic!("A binary COPY row cannot have more than 32767 columns.");
+        }
+        self.columns.push((name.into_iden(), ty));
+        self
+    }
+
+    /// Build the COPY statement that reads the encoded data.
+    pub fn copy_statement(&self) -> CopyStatement {
+        CopyStatement::new()
+            .table(self.table.clone())
+            .columns(self.columns.iter().map(|(name, _)| name.clone()))
+            .from_stdin()
+            .format_binary()
+    }
+
+    /// Encode a row with one value per column.
+    ///
+    /// If the row cannot be encoded, nothing is written.
+    pub fn write_row(&mut self, row: &[Value]) -> Result<(), EncodeError> {
+        if row.len() != self.columns.len() {
+            return Err(EncodeError::ColumnCount {
+                expected: self.columns.len(),
+                actual: row.len(),
+            });
+        }
+
+        let start = self.buf.len();
+        // `column` keeps the number of columns within i16
+        let field_count = row.len() as i16;
+        self.buf.extend_from_slice(&field_count.to_be_bytes());
+        for ((name, ty), value) in self.columns.iter().zip(row) {
+            if let Err(message) = write_field(&mut self.buf, ty, value) {
+                self.buf.truncate(start);
+                return Err(EncodeError::InvalidValue {
+                    column: name.clone().into_inner().into_owned(),
+                    message,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Take the bytes encoded so far, e.g. to stream them to the server in chunks.
+    pub fn take_buffer(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.buf)
+    }
+
+    /// Write the file trailer and return the remaining bytes.
+    pub fn finish(mut self) -> Vec<u8> {
+        self.buf.extend_from_slice(&(-1i16).to_be_bytes());
+        self.buf
+    }
+}
+
+fn write_field(buf: &mut Vec<u8>, ty: &ColumnType, value: &Value) -> Result<(), String> {
+    if is_null(value) {
+        buf.extend_from_slice(&(-1i32).to_be_bytes());
+        return Ok(());
+    }
+
+    let start = buf.len();
+    buf.extend_from_slice(&[0; 4]);
+    write_body(buf, ty, value)?;
+    let len = i32::try_from(buf.len() - start - 4).map_err(|_| "value is too large".to_string())?;
+    buf[start..start + 4].copy_from_slice(&len.to_be_bytes());
+    Ok(())
+}
+
+fn write_body(buf: &mut Vec<u8>, ty: &ColumnType, value: &Value) -> Result<(), String> {
+    match (ty, value, as_i128(value)) {
+        (ColumnType::Boolean, Value::Bool(Some(b)), _) => buf.push(u8::from(*b)),
+        (ColumnType::SmallInt | ColumnType::SmallSerial, _, Some(i)) => {
+            let i = i16::try_from(i).map_err(|_| out_of_range(ty))?;
+            buf.extend_from_slice(&i.to_be_bytes());
+        }
+        (ColumnType::Int | ColumnType::Serial, _, Some(i)) => {
+            let i = i32::try_from(i).map_err(|_| out_of_range(ty))?;
+            buf.extend_from_slice(&i.to_be_bytes());
+        }
+        (ColumnType::BigInt | ColumnType::BigSerial, _, Some(i)) => {
+            let i = i64::try_from(i).map_err(|_| out_of_range(ty))?;
+            buf.extend_from_slice(&i.to_be_bytes());
+        }
+        (ColumnType::Float, Value::Float(Some(f)), _) => buf.extend_from_slice(&f.to_be_bytes()),
+        (ColumnType::Double, Value::Float(Some(f)), _) => {
+            buf.extend_from_slice(&f64::from(*f).to_be_bytes())
+        }
+        (ColumnType::Double, Value::Double(Some(f)), _) => buf.extend_from_slice(&f.to_be_bytes()),
+        (ColumnType::Numeric(_), Value::String(Some(s)), _) => write_numeric(buf, s)?,
+        (ColumnType::Numeric(_), Value::Float(Some(f)), _) => write_numeric(buf, &f.to_string())?,
+        (ColumnType::Numeric(_), Value::Double(Some(f)), _) => write_numeric(buf, &f.to_string())?,
+        (ColumnType::Numeric(_), _, Some(i)) => write_numeric(buf, &i.to_string())?,
+        (
+            ColumnType::Char(_) | ColumnType::Varchar(_) | ColumnType::Text,
+            Value::String(Some(s)),
+            _,
+        ) => {
+            if s.contains('\0') {
+                return Err("string contains a NUL character".to_string());
+            }
+            buf.extend_from_slice(s.as_bytes());
+        }
+        (ColumnType::Bytea, Value::String(Some(s)), _) => buf.extend_from_slice(s.as_bytes()),
+        (ColumnType::Json, Value::String(Some(s)), _) => buf.extend_from_slice(s.as_bytes()),
+        (ColumnType::JsonBinary, Value::String(Some(s)), _) => {
+            // jsonb format version
+            buf.push(1);
+            buf.extend_from_slice(s.as_bytes());
+        }
+        #[cfg(feature = "with-json")]
+        (ColumnType::Json, Value::Json(Some(v)), _) => {
+            buf.extend_from_slice(v.to_string().as_bytes())
+        }
+        #[cfg(feature = "with-json")]
+        (ColumnType::JsonBinary, Value::Json(Some(v)), _) => {
+            // jsonb format version
+            buf.push(1);
+            buf.extend_from_slice(v.to_string().as_bytes());
+        }
+        #[cfg(feature = "with-uuid")]
+        (ColumnType::Uuid, Value::Uuid(Some(u)), _) => buf.extend_from_slice(u.as_bytes()),
+        (ColumnType::Array(elem), Value::Array(Some(values)), _) => write_array(buf, elem, values)?,
+        _ if type_oid(ty).is_none() => {
+            return Err(format!(
+                "binary encoding of {} is not supported, use a text encoder",
+                type_name(ty)
+            ));
+        }
+        _ => {
+            return Err(format!(
+                "cannot encode {} value as {}",
+                value_kind(value),
+                type_name(ty)
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn write_array(buf: &mut Vec<u8>, elem: &ColumnType, values: &[Value]) -> Result<(), String> {
+    let mut elem = elem;
+    while let ColumnType::Array(inner) = elem {
+        elem = inner;
+    }
+    let oid = type_oid(elem).ok_or_else(|| {
+        format!(
+            "binary encoding of arrays of {} is not supported",
+            type_name(elem)
+        )
+    })?;
+
+    let mut dims = vec![];
+    let mut level = values;
+    loop {
+        dims.push(level.len());
+        match level.first() {
+            Some(Value::Array(Some(inner))) => level = inner,
+            _ => break,
+        }
+    }
+    let mut elements = vec![];
+    collect_elements(values, &dims, &mut elements)?;
+    if elements.is_empty() {
+        dims.clear();
+    }
+
+    let ndim = i32::try_from(dims.len()).map_err(|_| "too many array dimensions".to_string())?;
+    let has_null = elements.iter().any(|v| is_null(v));
+    buf.extend_from_slice(&ndim.to_be_bytes());
+    buf.extend_from_slice(&i32::from(has_null).to_be_bytes());
+    buf.extend_from_slice(&oid.to_be_bytes());
+    for dim in dims {
+        let len = i32::try_from(dim).map_err(|_| "array is too large".to_string())?;
+        buf.extend_from_slice(&len.to_be_bytes());
+        // lower bound
+        buf.extend_from_slice(&1i32.to_be_bytes());
+    }
+    for element in elements {
+        write_field(buf, elem, element)?;
+    }
+    Ok(())
+}
+
+fn collect_elements<'a>(
+    values: &'a [Value],
+    dims: &[usize],
+    out: &mut Vec<&'a Value>,
+) -> Result<(), String> {
+    if values.len() != dims[0] {
+        return Err("multidimensional arrays must have sub-arrays of matching length".to_string());
+    }
+    for value in values {
+        match (value, dims.len()) {
+            (Value::Array(Some(inner)), n) if n > 1 => collect_elements(inner, &dims[1..], out)?,
+            (Value::Array(_), _) => {
+                return Err(
+                    "multidimensional arrays must have sub-arrays of matching depth".to_string(),
+                );
+            }
+            (_, 1) => out.push(value),
+            _ => {
+                return Err(
+                    "multidimensional arrays must have sub-arrays of matching depth".to_string(),
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Write a decimal string in the binary format of `numeric`.
+fn write_numeric(buf: &mut Vec<u8>, s: &str) -> Result<(), String> {
+    const NUMERIC_POS: u16 = 0x0000;
+    const NUMERIC_NEG: u16 = 0x4000;
+    const NUMERIC_NAN: u16 = 0xC000;
+
+    let invalid = || format!("invalid numeric value: {s:?}");
+
+    if s.eq_ignore_ascii_case("nan") {
+        buf.extend_from_slice(&0i16.to_be_bytes());
+        buf.extend_from_slice(&0i16.to_be_bytes());
+        buf.extend_from_slice(&NUMERIC_NAN.to_be_bytes());
+        buf.extend_from_slice(&0u16.to_be_bytes());
+        return Ok(());
+    }
+
+    let (negative, unsigned) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (int_part, frac_part) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if (int_part.is_empty() && frac_part.is_empty())
+        || !int_part.bytes().all(|b| b.is_ascii_digit())
+        || !frac_part.bytes().all(|b| b.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+    let dscale = u16::try_from(frac_part.len())
+        .ok()
+        .filter(|scale| *scale <= 0x3FFF)
+        .ok_or_else(invalid)?;
+
+    // Group the digits in base 10000, aligned on the decimal point.
+    let int_part = int_part.trim_start_matches('0');
+    let mut digits = String::with_capacity(int_part.len() + frac_part.len() + 6);
+    for _ in 0..(4 - int_part.len() % 4) % 4 {
+        digits.push('0');
+    }
+    digits.push_str(int_part);
+    let int_groups = digits.len() / 4;
+    digits.push_str(frac_part);
+    for _ in 0..(4 - frac_part.len() % 4) % 4 {
+        digits.push('0');
+    }
+    let mut groups = digits
+        .as_bytes()
+        .chunks(4)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .fold(0i16, |acc, d| acc * 10 + i16::from(d - b'0'))
+        })
+        .collect::<Vec<_>>();
+
+    let mut weight = i32::try_from(int_groups).map_err(|_| invalid())? - 1;
+    let leading_zeros = groups.iter().take_while(|g| **g == 0).count();
+    groups.drain(..leading_zeros);
+    weight -= i32::try_from(leading_zeros).map_err(|_| invalid())?;
+    while groups.last() == Some(&0) {
+        groups.pop();
+    }
+
+    let (weight, sign) = if groups.is_empty() {
+        (0, NUMERIC_POS)
+    } else if negative {
+        (weight, NUMERIC_NEG)
+    } else {
+        (weight, NUMERIC_POS)
+    };
+    let ndigits = i16::try_from(groups.len()).map_err(|_| invalid())?;
+    let weight = i16::try_from(weight).map_err(|_| invalid())?;
+    buf.extend_from_slice(&ndigits.to_be_bytes());
+    buf.extend_from_slice(&weight.to_be_bytes());
+    buf.extend_from_slice(&sign.to_be_bytes());
+    buf.extend_from_slice(&dscale.to_be_bytes());
+    for group in groups {
+        buf.extend_from_slice(&group.to_be_bytes());
+    }
+    Ok(())
+}
+
+/// Return the OID of the built-in types that can be encoded as array elements.
+fn type_oid(ty: &ColumnType) -> Option<u32> {
+    let oid = match ty {
+        ColumnType::Boolean => 16,
+        ColumnType::Bytea => 17,
+        ColumnType::BigInt | ColumnType::BigSerial => 20,
+        ColumnType::SmallInt | ColumnType::SmallSerial => 21,
+        ColumnType::Int | ColumnType::Serial => 23,
+        ColumnType::Text => 25,
+        ColumnType::Json => 114,
+        ColumnType::Float => 700,
+        ColumnType::Double => 701,
+        ColumnType::Char(_) => 1042,
+        ColumnType::Varchar(_) => 1043,
+        ColumnType::Numeric(_) => 1700,
+        ColumnType::Uuid => 2950,
+        ColumnType::JsonBinary => 3802,
+        _ => return None,
+    };
+    Some(oid)
+}
+
+fn as_i128(value: &Value) -> Option<i128> {
+    match value {
+        Value::TinyInt(Some(i)) => Some(i128::from(*i)),
+        Value::SmallInt(Some(i)) => Some(i128::from(*i)),
+        Value::Int(Some(i)) => Some(i128::from(*i)),
+        Value::BigInt(Some(i)) => Some(i128::from(*i)),
+        Value::TinyUnsigned(Some(u)) => Some(i128::from(*u)),
+        Value::SmallUnsigned(Some(u)) => Some(i128::from(*u)),
+        Value::Unsigned(Some(u)) => Some(i128::from(*u)),
+        Value::BigUnsigned(Some(u)) => Some(i128::from(*u)),
+        _ => None,
+    }
+}
+
+pub(crate) fn is_null(value: &Value) -> bool {
+    match value {
+        Value::Bool(v) => v.is_none(),
+        Value::TinyInt(v) => v.is_none(),
+        Value::SmallInt(v) => v.is_none(),
+        Value::Int(v) => v.is_none(),
+        Value::BigInt(v) => v.is_none(),
+        Value::TinyUnsigned(v) => v.is_none(),
+        Value::SmallUnsigned(v) => v.is_none(),
+        Value::Unsigned(v) => v.is_none(),
+        Value::BigUnsigned(v) => v.is_none(),
+        Value::Float(v) => v.is_none(),
+        Value::Double(v) => v.is_none(),
+        Value::String(v) => v.is_none(),
+        Value::Array(v) => v.is_none(),
+        #[cfg(feature = "with-json")]
+        Value::Json(v) => v.is_none(),
+        #[cfg(feature = "with-uuid")]
+        Value::Uuid(v) => v.is_none(),
+    }
+}
+
+fn value_kind(value: &Value) -> &'static str {
+    match value {
+        Value::Bool(_) => "bool",
+        Value::TinyInt(_) => "i8",
+        Value::SmallInt(_) => "i16",
+        Value::Int(_) => "i32",
+        Value::BigInt(_) => "i64",
+        Value::TinyUnsigned(_) => "u8",
+        Value::SmallUnsigned(_) => "u16",
+        Value::Unsigned(_) => "u32",
+        Value::BigUnsigned(_) => "u64",
+        Value::Float(_) => "f32",
+        Value::Double(_) => "f64",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        #[cfg(feature = "with-json")]
+        Value::Json(_) => "json",
+        #[cfg(feature = "with-uuid")]
+        Value::Uuid(_) => "uuid",
+    }
+}
+
+fn type_name(ty: &ColumnType) -> String {
+    let mut name = String::new();
+    write_column_type(&mut name, ty);
+    name
+}
+
+fn out_of_range(ty: &ColumnType) -> String {
+    format!("value is out of range for {}", type_name(ty))
+}
@@ -0,0 +1,80 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Encoders for the data stream of `COPY ... FROM STDIN`.
+//!
+//! Loading rows with COPY avoids the bind parameter limit of multi-row INSERT statements and is
+//! much faster for large batches. Each encoder turns rows of [`Value`]s into the bytes expected by
+//! the server for one format, and builds the matching [`CopyStatement`]:
+//!
+//! ```
+//! use pqb::copy::TextCopyEncoder;
+//! use pqb::value::Value;
+//!
+//! let mut encoder = TextCopyEncoder::new("users").columns(["id", "name"]);
+//! encoder
+//!     .write_row(&[Value::from(1), Value::from("alice")])
+//!     .unwrap();
+//! assert_eq!(
+//!     encoder.copy_statement().to_sql(),
+//!     r#"COPY "users" ("id", "name") FROM STDIN (FORMAT text)"#
+//! );
+//! assert_eq!(encoder.finish(), b"1\talice\n");
+//! ```
+//!
+//! [`Value`]: crate::value::Value
+//! [`CopyStatement`]: crate::query::CopyStatement
+
+use std::fmt;
+
+mod binary;
+mod text;
+
+pub use self::binary::*;
+pub use self::text::*;
+
+/// Errors returned when a row cannot be encoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EncodeError {
+    /// The row does not have one value per column.
+    ColumnCount {
+        /// The number of columns of the encoder.
+        expected: usize,
+        /// The number of values in the row.
+        actual: usize,
+    },
+    /// A value cannot be encoded for its column.
+    InvalidValue {
+        /// The name of the column.
+        column: String,
+        /// Why the value cannot be encoded.
+        message: String,
+    },
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::ColumnCount { expected, actual } => {
+                write!(f, "expected {expected} values per row, found {actual}")
+            }
+            EncodeError::InvalidValue { column, message } => {
+                write!(f, "invalid value for column {column}: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {}
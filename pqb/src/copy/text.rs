@@ -0,0 +1,324 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::copy::EncodeError;
+use crate::copy::binary::is_null;
+use crate::query::CopyStatement;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::TableName;
+use crate::value::Value;
+
+/// Encode rows into the text format of `COPY ... FROM STDIN (FORMAT text)`.
+///
+/// Fields are separated by tabs and NULL is written as `\N`. Values are written in their text
+/// form and the server converts them to the column types, so this encoder works for any column
+/// type that accepts the text form of the value, e.g. dates and timestamps given as strings.
+#[derive(Debug, Clone)]
+pub struct TextCopyEncoder {
+    table: TableName,
+    columns: Vec<Iden>,
+    buf: Vec<u8>,
+}
+
+impl TextCopyEncoder {
+    /// Create an encoder for rows of the given table.
+    pub fn new<T>(table: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        Self {
+            table: table.into(),
+            columns: vec![],
+            buf: vec![],
+        }
+    }
+
+    /// Add a column.
+    ///
+    /// Without columns, rows are written for all the columns of the table.
+    pub fn column<C>(mut self, name: C) -> Self
+    where
+        C: IntoIden,
+    {
+        self.columns.push(name.into_iden());
+        self
+    }
+
+    /// Add columns.
+    pub fn columns<I, C>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+        C: IntoIden,
+    {
+        self.columns
+            .extend(names.into_iter().map(IntoIden::into_iden));
+        self
+    }
+
+    /// Build the COPY statement that reads the encoded data.
+    pub fn copy_statement(&self) -> CopyStatement {
+        CopyStatement::new()
+            .table(self.table.clone())
+            .columns(self.columns.clone())
+            .from_stdin()
+            .format_text()
+    }
+
+    /// Encode a row with one value per column.
+    ///
+    /// If the row cannot be encoded, nothing is written.
+    pub fn write_row(&mut self, row: &[Value]) -> Result<(), EncodeError> {
+        let fields = format_row(&self.columns, row)?;
+        let mut line = String::new();
+        for (i, field) in fields.iter().enumerate() {
+            if i > 0 {
+                line.push('\t');
+            }
+            match field {
+                None => line.push_str("\\N"),
+                Some(text) => {
+                    for ch in text.chars() {
+                        match ch {
+                            '\\' => line.push_str("\\\\"),
+                            '\n' => line.push_str("\\n"),
+                            '\r' => line.push_str("\\r"),
+                            '\t' => line.push_str("\\t"),
+                            '\u{8}' => line.push_str("\\b"),
+                            '\u{c}' => line.push_str("\\f"),
+                            '\u{b}' => line.push_str("\\v"),
+                            ch => line.push(ch),
+                        }
+                    }
+                }
+            }
+        }
+        line.push('\n');
+        self.buf.extend_from_slice(line.as_bytes());
+        Ok(())
+    }
+
+    /// Take the bytes encoded so far, e.g. to stream them to the server in chunks.
+    pub fn take_buffer(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.buf)
+    }
+
+    /// Return the remaining bytes.
+    pub fn finish(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+/// Encode rows into the CSV format of `COPY ... FROM STDIN (FORMAT csv)`.
+///
+/// Fields are separated by commas and NULL is written as an unquoted empty field, while an empty
+/// string is written as `""`. Like the text encoder, values are written in their text form.
+#[derive(Debug, Clone)]
+pub struct CsvCopyEncoder {
+    table: TableName,
+    columns: Vec<Iden>,
+    buf: Vec<u8>,
+}
+
+impl CsvCopyEncoder {
+    /// Create an encoder for rows of the given table.
+    pub fn new<T>(table: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        Self {
+            table: table.into(),
+            columns: vec![],
+            buf: vec![],
+        }
+    }
+
+    /// Add a column.
+    ///
+    /// Without columns, rows are written for all the columns of the table.
+    pub fn column<C>(mut self, name: C) -> Self
+    where
+        C: IntoIden,
+    {
+        self.columns.push(name.into_iden());
+        self
+    }
+
+    /// Add columns.
+    pub fn columns<I, C>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+        C: IntoIden,
+    {
+        self.columns
+            .extend(names.into_iter().map(IntoIden::into_iden));
+        self
+    }
+
+    /// Build the COPY statement that reads the encoded data.
+    pub fn copy_statement(&self) -> CopyStatement {
+        CopyStatement::new()
+            .table(self.table.clone())
+            .columns(self.columns.clone())
+            .from_stdin()
+            .format_csv()
+    }
+
+    /// Encode a row with one value per column.
+    ///
+    /// If the row cannot be encoded, nothing is written.
+    pub fn write_row(&mut self, row: &[Value]) -> Result<(), EncodeError> {
+        let fields = format_row(&self.columns, row)?;
+        let mut line = String::new();
+        for (i, field) in fields.iter().enumerate() {
+            if i > 0 {
+                line.push(',');
+            }
+            let Some(text) = field else {
+                continue;
+            };
+            // An empty string must be quoted to be told apart from NULL, and a lone `\.` would
+            // be read as the end-of-data marker.
+            let quoted = text.is_empty() || text == "\\." || text.contains([',', '"', '\n', '\r']);
+            if quoted {
+                line.push('"');
+                for ch in text.chars() {
+                    if ch == '"' {
+                        line.push('"');
+                    }
+                    line.push(ch);
+                }
+                line.push('"');
+            } else {
+                line.push_str(text);
+            }
+        }
+        line.push('\n');
+        self.buf.extend_from_slice(line.as_bytes());
+        Ok(())
+    }
+
+    /// Take the bytes encoded so far, e.g. to stream them to the server in chunks.
+    pub fn take_buffer(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.buf)
+    }
+
+    /// Return the remaining bytes.
+    pub fn finish(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+/// Convert the values of a row to their text form, or `None` for NULL.
+fn format_row(columns: &[Iden], row: &[Value]) -> Result<Vec<Option<String>>, EncodeError> {
+    if !columns.is_empty() && row.len() != columns.len() {
+        return Err(EncodeError::ColumnCount {
+            expected: columns.len(),
+            actual: row.len(),
+        });
+    }
+
+    let mut fields = Vec::with_capacity(row.len());
+    for (i, value) in row.iter().enumerate() {
+        if is_null(value) {
+            fields.push(None);
+            continue;
+        }
+        let mut text = String::new();
+        write_text_value(&mut text, value);
+        if text.contains('\0') {
+            let column = match columns.get(i) {
+                Some(column) => column.clone().into_inner().into_owned(),
+                None => format!("#{}", i + 1),
+            };
+            return Err(EncodeError::InvalidValue {
+                column,
+                message: "string contains a NUL character".to_string(),
+            });
+        }
+        fields.push(Some(text));
+    }
+    Ok(fields)
+}
+
+fn write_text_value(out: &mut String, value: &Value) {
+    match value {
+        Value::Bool(Some(b)) => out.push(if *b { 't' } else { 'f' }),
+        Value::TinyInt(Some(i)) => out.push_str(&i.to_string()),
+        Value::SmallInt(Some(i)) => out.push_str(&i.to_string()),
+        Value::Int(Some(i)) => out.push_str(&i.to_string()),
+        Value::BigInt(Some(i)) => out.push_str(&i.to_string()),
+        Value::TinyUnsigned(Some(u)) => out.push_str(&u.to_string()),
+        Value::SmallUnsigned(Some(u)) => out.push_str(&u.to_string()),
+        Value::Unsigned(Some(u)) => out.push_str(&u.to_string()),
+        Value::BigUnsigned(Some(u)) => out.push_str(&u.to_string()),
+        Value::Float(Some(f)) => write_float(out, f64::from(*f), &f.to_string()),
+        Value::Double(Some(f)) => write_float(out, *f, &f.to_string()),
+        Value::String(Some(s)) => out.push_str(s),
+        Value::Array(Some(values)) => write_array_literal(out, values),
+        #[cfg(feature = "with-json")]
+        Value::Json(Some(v)) => out.push_str(&v.to_string()),
+        #[cfg(feature = "with-uuid")]
+        Value::Uuid(Some(u)) => out.push_str(&u.hyphenated().to_string()),
+        _ => out.push_str("NULL"),
+    }
+}
+
+fn write_float(out: &mut String, f: f64, text: &str) {
+    if f.is_nan() {
+        out.push_str("NaN");
+    } else if f == f64::INFINITY {
+        out.push_str("Infinity");
+    } else if f == f64::NEG_INFINITY {
+        out.push_str("-Infinity");
+    } else {
+        out.push_str(text);
+    }
+}
+
+/// Write an array in the `{...}` literal form accepted by array input.
+pub(crate) fn write_array_literal(out: &mut String, values: &[Value]) {
+    out.push('{');
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        if is_null(value) {
+            out.push_str("NULL");
+        } else if let Value::Array(Some(inner)) = value {
+            write_array_literal(out, inner);
+        } else {
+            let mut text = String::new();
+            write_text_value(&mut text, value);
+            let quoted = text.is_empty()
+                || text.eq_ignore_ascii_case("NULL")
+                || text
+                    .chars()
+                    .any(|ch| matches!(ch, '{' | '}' | ',' | '"' | '\\') || ch.is_whitespace());
+            if quoted {
+                out.push('"');
+                for ch in text.chars() {
+                    if matches!(ch, '"' | '\\') {
+                        out.push('\\');
+                    }
+                    out.push(ch);
+                }
+                out.push('"');
+            } else {
+                out.push_str(&text);
+            }
+        }
+    }
+    out.push('}');
+}
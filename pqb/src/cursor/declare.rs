@@ -0,0 +1,115 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::query::Select;
+use crate::query::write_select;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::write_iden;
+use crate::writer::SqlWriter;
+
+/// DECLARE statement builder for cursors.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeclareCursor {
+    name: Option<Iden>,
+    binary: bool,
+    scroll: Option<bool>,
+    with_hold: bool,
+    query: Option<Box<Select>>,
+}
+
+impl DeclareCursor {
+    /// Create a new DECLARE statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_declare_cursor(&mut w, self);
+        w
+    }
+
+    /// Convert the DECLARE statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_declare_cursor(&mut sql, self);
+        sql
+    }
+
+    /// Set the cursor name.
+    pub fn name<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Return rows in binary rather than text format.
+    pub fn binary(mut self) -> Self {
+        self.binary = true;
+        self
+    }
+
+    /// Allow fetching rows backwards.
+    pub fn scroll(mut self) -> Self {
+        self.scroll = Some(true);
+        self
+    }
+
+    /// Forbid fetching rows backwards.
+    pub fn no_scroll(mut self) -> Self {
+        self.scroll = Some(false);
+        self
+    }
+
+    /// Keep the cursor open after the transaction that created it commits.
+    pub fn with_hold(mut self) -> Self {
+        self.with_hold = true;
+        self
+    }
+
+    /// Set the query whose rows the cursor returns.
+    pub fn query(mut self, query: Select) -> Self {
+        self.query = Some(Box::new(query));
+        self
+    }
+}
+
+fn write_declare_cursor<W: SqlWriter>(w: &mut W, declare: &DeclareCursor) {
+    w.push_str("DECLARE ");
+    if let Some(name) = &declare.name {
+        write_iden(w, name);
+    }
+    if declare.binary {
+        w.push_str(" BINARY");
+    }
+    match declare.scroll {
+        Some(true) => w.push_str(" SCROLL"),
+        Some(false) => w.push_str(" NO SCROLL"),
+        None => {}
+    }
+    w.push_str(" CURSOR");
+    if declare.with_hold {
+        w.push_str(" WITH HOLD");
+    }
+    if let Some(query) = &declare.query {
+        w.push_str(" FOR ");
+        write_select(w, query);
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::write_iden;
+use crate::writer::SqlWriter;
+
+/// Directions of FETCH and MOVE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum FetchDirection {
+    /// The next row.
+    Next,
+    /// The prior row.
+    Prior,
+    /// The first row.
+    First,
+    /// The last row.
+    Last,
+    /// The n-th row, counting from the end if negative.
+    Absolute(i64),
+    /// The n-th following row, or preceding row if negative.
+    Relative(i64),
+    /// The next n rows.
+    Count(i64),
+    /// All remaining rows.
+    All,
+    /// The next row.
+    Forward,
+    /// The next n rows.
+    ForwardCount(i64),
+    /// All remaining rows.
+    ForwardAll,
+    /// The prior row.
+    Backward,
+    /// The prior n rows.
+    BackwardCount(i64),
+    /// All prior rows.
+    BackwardAll,
+}
+
+/// FETCH statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fetch {
+    direction: Option<FetchDirection>,
+    cursor: Option<Iden>,
+}
+
+impl Fetch {
+    /// Create a new FETCH statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_fetch(&mut w, "FETCH", self.direction, self.cursor.as_ref());
+        w
+    }
+
+    /// Convert the FETCH statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_fetch(&mut sql, "FETCH", self.direction, self.cursor.as_ref());
+        sql
+    }
+
+    /// Set the direction, which defaults to the next row.
+    pub fn direction(mut self, direction: FetchDirection) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Set the cursor to fetch from.
+    pub fn cursor<N>(mut self, cursor: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.cursor = Some(cursor.into_iden());
+        self
+    }
+}
+
+/// MOVE statement builder.
+///
+/// MOVE repositions a cursor like FETCH, without returning rows.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Move {
+    direction: Option<FetchDirection>,
+    cursor: Option<Iden>,
+}
+
+impl Move {
+    /// Create a new MOVE statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_fetch(&mut w, "MOVE", self.direction, self.cursor.as_ref());
+        w
+    }
+
+    /// Convert the MOVE statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_fetch(&mut sql, "MOVE", self.direction, self.cursor.as_ref());
+        sql
+    }
+
+    /// Set the direction, which defaults to the next row.
+    pub fn direction(mut self, direction: FetchDirection) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Set the cursor to move.
+    pub fn cursor<N>(mut self, cursor: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.cursor = Some(cursor.into_iden());
+        self
+    }
+}
+
+fn write_fetch<W: SqlWriter>(
+    w: &mut W,
+    command: &str,
+    direction: Option<FetchDirection>,
+    cursor: Option<&Iden>,
+) {
+    w.push_str(command);
+    if let Some(direction) = direction {
+        w.push_char(' ');
+        match direction {
+            FetchDirection::Next => w.push_str("NEXT"),
+            FetchDirection::Prior => w.push_str("PRIOR"),
+            FetchDirection::First => w.push_str("FIRST"),
+            FetchDirection::Last => w.push_str("LAST"),
+            FetchDirection::Absolute(n) => w.push_fmt(format_args!("ABSOLUTE {n}")),
+            FetchDirection::Relative(n) => w.push_fmt(format_args!("RELATIVE {n}")),
+            FetchDirection::Count(n) => w.push_fmt(format_args!("{n}")),
+            FetchDirection::All => w.push_str("ALL"),
+            FetchDirection::Forward => w.push_str("FORWARD"),
+            FetchDirection::ForwardCount(n) => w.push_fmt(format_args!("FORWARD {n}")),
+            FetchDirection::ForwardAll => w.push_str("FORWARD ALL"),
+            FetchDirection::Backward => w.push_str("BACKWARD"),
+            FetchDirection::BackwardCount(n) => w.push_fmt(format_args!("BACKWARD {n}")),
+            FetchDirection::BackwardAll => w.push_str("BACKWARD ALL"),
+        }
+    }
+    if let Some(cursor) = cursor {
+        w.push_str(" FROM ");
+        write_iden(w, cursor);
+    }
+}
@@ -33,7 +33,8 @@ use crate::value::Value;
 use crate::writer::SqlWriter;
 
 /// SQL keywords.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 #[expect(missing_docs)]
 pub enum Keyword {
@@ -42,7 +43,15 @@ pub enum Keyword {
 }
 
 /// An arbitrary, dynamically-typed SQL expression.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// With the `serde` feature, expressions are externally tagged by variant name, and operators and
+/// values follow the same scheme. For example, `"id" = 1` is represented as:
+///
+/// ```json
+/// {"Binary": [{"Column": {"Column": ["id"]}}, "Equal", {"Value": {"Int": 1}}]}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 #[expect(missing_docs)]
 pub enum Expr {
@@ -51,6 +60,7 @@ pub enum Expr {
     Keyword(Keyword),
     Tuple(Vec<Expr>),
     Value(Value),
+    Param(u32),
     Unary(UnaryOp, Box<Expr>),
     Binary(Box<Expr>, BinaryOp, Box<Expr>),
     FunctionCall(FunctionCall),
@@ -107,6 +117,19 @@ impl Expr {
         Expr::Custom(expr.into())
     }
 
+    /// Express the positional parameter `$n`, e.g. of a prepared statement body.
+    ///
+    /// The parameter is written as is, so it is not numbered together with the bound values.
+    ///
+    /// ## Panics
+    /// This method will panic if `n` is zero.
+    pub fn param(n: u32) -> Self {
+        if n == 0 {
+            panic!("Parameter numbers start at 1.");
+        }
+        Expr::Param(n)
+    }
+
     /// Keyword `CURRENT_TIMESTAMP`.
     pub fn current_timestamp() -> Self {
         Expr::Keyword(Keyword::CurrentTimestamp)
@@ -445,7 +468,8 @@ impl Expr {
 }
 
 /// SubQuery operators
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 #[expect(missing_docs)]
 pub enum SubQueryOp {
@@ -456,7 +480,8 @@ pub enum SubQueryOp {
 }
 
 /// Unary operators.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 #[expect(missing_docs)]
 pub enum UnaryOp {
@@ -464,7 +489,8 @@ pub enum UnaryOp {
 }
 
 /// Binary operators.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 #[expect(missing_docs)]
 pub enum BinaryOp {
@@ -535,6 +561,7 @@ pub(crate) fn write_expr<W: SqlWriter>(w: &mut W, expr: &Expr) {
         }),
         Expr::Tuple(exprs) => write_tuple(w, exprs),
         Expr::Value(value) => w.push_param(value.clone()),
+        Expr::Param(n) => w.push_fmt(format_args!("${n}")),
         Expr::Unary(unary, expr) => write_unary_expr(w, unary, expr),
         Expr::Binary(lhs, op, rhs) => match (op, &**rhs) {
             (BinaryOp::In, Expr::Tuple(t)) if t.is_empty() => {
@@ -709,6 +736,7 @@ fn well_known_no_parentheses(expr: &Expr) -> bool {
         Expr::Column(_)
             | Expr::Tuple(_)
             | Expr::Value(_)
+            | Expr::Param(_)
             | Expr::Asterisk
             | Expr::Keyword(_)
             | Expr::FunctionCall(_)
@@ -19,11 +19,16 @@ use std::borrow::Cow;
 use crate::expr::BinaryOp;
 use crate::expr::Expr;
 use crate::expr::write_expr;
+use crate::table::ColumnType;
+use crate::table::write_column_type;
 use crate::types::IntoColumnRef;
 use crate::types::IntoIden;
+use crate::types::TableName;
+use crate::types::write_table_name;
 use crate::writer::SqlWriter;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum Func {
     Max,
     Min,
@@ -34,11 +39,16 @@ enum Func {
     Coalesce,
     Lower,
     Upper,
+    NextVal,
+    CurrVal,
+    SetVal,
+    PgNotify,
     Custom(Cow<'static, str>),
 }
 
 /// A function call expression.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FunctionCall {
     func: Func,
     args: Vec<Expr>,
@@ -155,6 +165,95 @@ impl FunctionCall {
         }
     }
 
+    /// Call `CAST` function with a column type, including user-defined types.
+    pub fn cast_as_type<V, T>(expr: V, ty: T) -> FunctionCall
+    where
+        V: Into<Expr>,
+        T: Into<ColumnType>,
+    {
+        let mut name = String::new();
+        write_column_type(&mut name, &ty.into());
+        FunctionCall {
+            func: Func::Cast,
+            args: vec![expr.into().binary(BinaryOp::As, Expr::custom(name))],
+        }
+    }
+
+    /// Create a NEXTVAL function call that advances the given sequence.
+    pub fn nextval<S>(sequence: S) -> Self
+    where
+        S: Into<TableName>,
+    {
+        Self {
+            func: Func::NextVal,
+            args: vec![sequence_name(sequence.into())],
+        }
+    }
+
+    /// Create a CURRVAL function call that returns the value most recently obtained by NEXTVAL
+    /// for the given sequence in the current session.
+    pub fn currval<S>(sequence: S) -> Self
+    where
+        S: Into<TableName>,
+    {
+        Self {
+            func: Func::CurrVal,
+            args: vec![sequence_name(sequence.into())],
+        }
+    }
+
+    /// Create a SETVAL function call that sets the current value of the given sequence.
+    pub fn setval<S, V>(sequence: S, value: V) -> Self
+    where
+        S: Into<TableName>,
+        V: Into<Expr>,
+    {
+        Self {
+            func: Func::SetVal,
+            args: vec![sequence_name(sequence.into()), value.into()],
+        }
+    }
+
+    /// Create a SETVAL function call that also sets whether the value has already been used.
+    ///
+    /// If `is_called` is false, the next NEXTVAL returns exactly `value`.
+    pub fn setval_with_is_called<S, V>(sequence: S, value: V, is_called: bool) -> Self
+    where
+        S: Into<TableName>,
+        V: Into<Expr>,
+    {
+        Self {
+            func: Func::SetVal,
+            args: vec![
+                sequence_name(sequence.into()),
+                value.into(),
+                Expr::value(is_called),
+            ],
+        }
+    }
+
+    /// Create a PG_NOTIFY function call that sends a notification on the given channel.
+    ///
+    /// The channel is passed as the identifier name, so it reaches the same channel as
+    /// [`Listen`] or [`Notify`] with the same identifier. Unlike NOTIFY, the payload can be any
+    /// expression, including a bound parameter.
+    ///
+    /// [`Listen`]: crate::session::Listen
+    /// [`Notify`]: crate::session::Notify
+    pub fn pg_notify<C, P>(channel: C, payload: P) -> Self
+    where
+        C: IntoIden,
+        P: Into<Expr>,
+    {
+        Self {
+            func: Func::PgNotify,
+            args: vec![
+                Expr::value(channel.into_iden().into_inner().into_owned()),
+                payload.into(),
+            ],
+        }
+    }
+
     /// Create a function call with custom name and arguments.
     pub fn custom<N, T, I>(name: N, args: I) -> Self
     where
@@ -186,6 +285,10 @@ pub(crate) fn write_function_call<W: SqlWriter>(w: &mut W, call: &FunctionCall)
         Func::Coalesce => w.push_str("COALESCE"),
         Func::Lower => w.push_str("LOWER"),
         Func::Upper => w.push_str("UPPER"),
+        Func::NextVal => w.push_str("NEXTVAL"),
+        Func::CurrVal => w.push_str("CURRVAL"),
+        Func::SetVal => w.push_str("SETVAL"),
+        Func::PgNotify => w.push_str("PG_NOTIFY"),
         Func::Custom(name) => w.push_str(name),
     }
     w.push_char('(');
@@ -198,6 +301,14 @@ pub(crate) fn write_function_call<W: SqlWriter>(w: &mut W, call: &FunctionCall)
     w.push_char(')');
 }
 
+/// Sequence functions take the sequence as a `regclass` text literal, so the name is quoted the
+/// same way it would be in a statement.
+fn sequence_name(sequence: TableName) -> Expr {
+    let mut name = String::new();
+    write_table_name(&mut name, &sequence);
+    Expr::value(name)
+}
+
 /// Express a column reference for use in aggregate functions.
 pub fn col<T>(col: T) -> Expr
 where
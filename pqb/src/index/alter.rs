@@ -0,0 +1,147 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::SqlWriterValues;
+use crate::expr::Expr;
+use crate::index::IndexOption;
+use crate::index::write_index_option_list;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::TableName;
+use crate::types::write_iden;
+use crate::types::write_table_name;
+use crate::writer::SqlWriter;
+
+/// ALTER INDEX statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlterIndex {
+    name: Option<TableName>,
+    if_exists: bool,
+    action: Option<IndexAlterAction>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum IndexAlterAction {
+    RenameTo(Iden),
+    SetTablespace(Iden),
+    AttachPartition(TableName),
+    Set(Vec<IndexOption>),
+}
+
+impl AlterIndex {
+    /// Create a new ALTER INDEX statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_alter_index(&mut w, self);
+        w
+    }
+
+    /// Convert the ALTER INDEX statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_alter_index(&mut sql, self);
+        sql
+    }
+
+    /// Set the index name.
+    pub fn name<T>(mut self, name: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Do not throw an error if the index does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Rename the index.
+    pub fn rename_to<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.action = Some(IndexAlterAction::RenameTo(name.into_iden()));
+        self
+    }
+
+    /// Move the index into another tablespace.
+    pub fn set_tablespace<T>(mut self, tablespace: T) -> Self
+    where
+        T: IntoIden,
+    {
+        self.action = Some(IndexAlterAction::SetTablespace(tablespace.into_iden()));
+        self
+    }
+
+    /// Attach the given index of a partition to this index of a partitioned table.
+    pub fn attach_partition<T>(mut self, index: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.action = Some(IndexAlterAction::AttachPartition(index.into()));
+        self
+    }
+
+    /// Change a storage parameter of the index.
+    pub fn set_option<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: IntoIden,
+        V: Into<Expr>,
+    {
+        let option = IndexOption::new(name, value);
+        match &mut self.action {
+            Some(IndexAlterAction::Set(options)) => options.push(option),
+            _ => self.action = Some(IndexAlterAction::Set(vec![option])),
+        }
+        self
+    }
+}
+
+fn write_alter_index<W: SqlWriter>(w: &mut W, alter: &AlterIndex) {
+    w.push_str("ALTER INDEX ");
+    if alter.if_exists {
+        w.push_str("IF EXISTS ");
+    }
+    if let Some(name) = &alter.name {
+        write_table_name(w, name);
+    }
+    match &alter.action {
+        Some(IndexAlterAction::RenameTo(name)) => {
+            w.push_str(" RENAME TO ");
+            write_iden(w, name);
+        }
+        Some(IndexAlterAction::SetTablespace(tablespace)) => {
+            w.push_str(" SET TABLESPACE ");
+            write_iden(w, tablespace);
+        }
+        Some(IndexAlterAction::AttachPartition(index)) => {
+            w.push_str(" ATTACH PARTITION ");
+            write_table_name(w, index);
+        }
+        Some(IndexAlterAction::Set(options)) => {
+            w.push_str(" SET ");
+            write_index_option_list(w, options);
+        }
+        None => {}
+    }
+}
@@ -17,7 +17,8 @@ use std::borrow::Cow;
 use crate::SqlWriterValues;
 use crate::expr::Expr;
 use crate::expr::write_expr;
-use crate::expr::write_tuple;
+use crate::index::IndexElement;
+use crate::index::write_index_element;
 use crate::types::Iden;
 use crate::types::IntoIden;
 use crate::types::TableRef;
@@ -27,17 +28,20 @@ use crate::writer::SqlWriter;
 
 /// CREATE INDEX statement builder.
 #[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CreateIndex {
     table: Option<TableRef>,
+    only: bool,
     concurrently: bool,
     if_not_exists: bool,
-    primary: bool,
     unique: bool,
     name: Option<Iden>,
-    columns: Vec<Expr>,
+    columns: Vec<IndexElement>,
     include_columns: Vec<Iden>,
+    nulls_not_distinct: bool,
     method: Option<IndexMethod>,
     options: Vec<IndexOption>,
+    tablespace: Option<Iden>,
     predicate: Option<Expr>,
 }
 
@@ -70,6 +74,12 @@ impl CreateIndex {
         self
     }
 
+    /// Do not recurse into partitions of a partitioned table.
+    pub fn only(mut self) -> Self {
+        self.only = true;
+        self
+    }
+
     /// Specify the index name.
     pub fn name<N>(mut self, name: N) -> Self
     where
@@ -84,7 +94,7 @@ impl CreateIndex {
     where
         T: IntoIden,
     {
-        self.columns.push(Expr::column(column.into_iden()));
+        self.columns.push(IndexElement::column(column));
         self
     }
 
@@ -93,7 +103,13 @@ impl CreateIndex {
     where
         E: Into<Expr>,
     {
-        self.columns.push(expr.into());
+        self.columns.push(IndexElement::expr(expr));
+        self
+    }
+
+    /// Add an index element with collation, operator class or ordering.
+    pub fn element(mut self, element: IndexElement) -> Self {
+        self.columns.push(element);
         self
     }
 
@@ -117,18 +133,21 @@ impl CreateIndex {
         self
     }
 
-    /// Set index as primary
-    pub fn primary(mut self) -> Self {
-        self.primary = true;
-        self
-    }
-
     /// Set index as unique
     pub fn unique(mut self) -> Self {
         self.unique = true;
         self
     }
 
+    /// Treat null values as equal in a unique index.
+    ///
+    /// ## Panics
+    /// Rendering the statement will panic if the index is not unique.
+    pub fn nulls_not_distinct(mut self) -> Self {
+        self.nulls_not_distinct = true;
+        self
+    }
+
     /// Create the index if it does not exist.
     pub fn if_not_exists(mut self) -> Self {
         self.if_not_exists = true;
@@ -188,6 +207,15 @@ impl CreateIndex {
         self
     }
 
+    /// Set the tablespace of the index.
+    pub fn tablespace<T>(mut self, tablespace: T) -> Self
+    where
+        T: IntoIden,
+    {
+        self.tablespace = Some(tablespace.into_iden());
+        self
+    }
+
     /// Set the predicate for a partial index.
     pub fn index_where<E>(mut self, expr: E) -> Self
     where
@@ -200,6 +228,7 @@ impl CreateIndex {
 
 /// Index access method for CREATE INDEX.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum IndexMethod {
     /// B-tree index method.
@@ -238,6 +267,7 @@ impl From<String> for IndexMethod {
 
 /// Storage parameter entry for CREATE INDEX.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IndexOption {
     name: Iden,
     value: Expr,
@@ -267,14 +297,15 @@ where
     }
 }
 
-fn write_create_index<W: SqlWriter>(w: &mut W, index: &CreateIndex) {
-    w.push_str("CREATE INDEX ");
-    if index.primary {
-        w.push_str("PRIMARY KEY ");
+pub(crate) fn write_create_index<W: SqlWriter>(w: &mut W, index: &CreateIndex) {
+    if index.nulls_not_distinct && !index.unique {
+        panic!("NULLS NOT DISTINCT is only allowed for unique indexes.");
     }
+    w.push_str("CREATE ");
     if index.unique {
         w.push_str("UNIQUE ");
     }
+    w.push_str("INDEX ");
     if index.concurrently {
         w.push_str("CONCURRENTLY ");
     }
@@ -286,6 +317,9 @@ fn write_create_index<W: SqlWriter>(w: &mut W, index: &CreateIndex) {
         w.push_char(' ');
     }
     w.push_str("ON ");
+    if index.only {
+        w.push_str("ONLY ");
+    }
     if let Some(table) = &index.table {
         write_table_ref(w, table);
     }
@@ -298,38 +332,24 @@ fn write_create_index<W: SqlWriter>(w: &mut W, index: &CreateIndex) {
     }
     write_index_columns(w, &index.columns);
     write_index_include(w, &index.include_columns);
-    write_index_options(w, &index.options);
-    write_index_predicate(w, &index.predicate);
-}
-
-/// Write table index definition inside CREATE TABLE statement.
-pub(crate) fn write_table_index<W: SqlWriter>(w: &mut W, index: &CreateIndex) {
-    if index.primary {
-        w.push_str("PRIMARY KEY ");
-    }
-    if index.unique {
-        w.push_str("UNIQUE ");
+    if index.nulls_not_distinct {
+        w.push_str(" NULLS NOT DISTINCT");
     }
-    write_index_columns(w, &index.columns);
-    write_index_include(w, &index.include_columns);
     write_index_options(w, &index.options);
+    if let Some(tablespace) = &index.tablespace {
+        w.push_str(" TABLESPACE ");
+        write_iden(w, tablespace);
+    }
+    write_index_predicate(w, &index.predicate);
 }
 
-fn write_index_columns<W: SqlWriter>(w: &mut W, columns: &[Expr]) {
+fn write_index_columns<W: SqlWriter>(w: &mut W, columns: &[IndexElement]) {
     w.push_str("(");
     for (i, col) in columns.iter().enumerate() {
         if i > 0 {
             w.push_str(", ");
         }
-        match col {
-            // Wrap opclass expressions in parentheses for disambiguation
-            Expr::Binary(_, _, _) | Expr::Unary(_, _) => {
-                write_tuple(w, std::slice::from_ref(col));
-            }
-            _ => {
-                write_expr(w, col);
-            }
-        }
+        write_index_element(w, col);
     }
     w.push_str(")");
 }
@@ -349,7 +369,7 @@ fn write_index_include<W: SqlWriter>(w: &mut W, columns: &[Iden]) {
     w.push_str(")");
 }
 
-fn write_index_method<W: SqlWriter>(w: &mut W, method: &IndexMethod) {
+pub(crate) fn write_index_method<W: SqlWriter>(w: &mut W, method: &IndexMethod) {
     match method {
         IndexMethod::Btree => w.push_str("btree"),
         IndexMethod::Hash => w.push_str("hash"),
@@ -359,12 +379,18 @@ fn write_index_method<W: SqlWriter>(w: &mut W, method: &IndexMethod) {
     }
 }
 
-fn write_index_options<W: SqlWriter>(w: &mut W, options: &[IndexOption]) {
+pub(crate) fn write_index_options<W: SqlWriter>(w: &mut W, options: &[IndexOption]) {
     if options.is_empty() {
         return;
     }
 
-    w.push_str(" WITH (");
+    w.push_str(" WITH ");
+    write_index_option_list(w, options);
+}
+
+/// Write storage parameters as a parenthesized `name = value` list.
+pub(crate) fn write_index_option_list<W: SqlWriter>(w: &mut W, options: &[IndexOption]) {
+    w.push_str("(");
     for (i, option) in options.iter().enumerate() {
         if i > 0 {
             w.push_str(", ");
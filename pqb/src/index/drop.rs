@@ -20,6 +20,7 @@ use crate::writer::SqlWriter;
 
 /// DROP INDEX statement builder.
 #[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DropIndex {
     indexes: Vec<TableName>,
     concurrently: bool,
@@ -0,0 +1,163 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::expr::Expr;
+use crate::expr::write_expr;
+use crate::expr::write_tuple;
+use crate::index::IndexOption;
+use crate::index::write_index_option_list;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::write_iden;
+use crate::writer::SqlWriter;
+
+/// A key column or expression of an index, with its collation, operator class and ordering.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IndexElement {
+    expr: Expr,
+    collation: Option<Iden>,
+    opclass: Option<Iden>,
+    opclass_params: Vec<IndexOption>,
+    direction: Option<SortDirection>,
+    nulls: Option<NullOrdering>,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum SortDirection {
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum NullOrdering {
+    First,
+    Last,
+}
+
+impl IndexElement {
+    /// Index a column.
+    pub fn column<T>(column: T) -> Self
+    where
+        T: IntoIden,
+    {
+        Self::expr(Expr::column(column.into_iden()))
+    }
+
+    /// Index an expression.
+    pub fn expr<E>(expr: E) -> Self
+    where
+        E: Into<Expr>,
+    {
+        Self {
+            expr: expr.into(),
+            collation: None,
+            opclass: None,
+            opclass_params: vec![],
+            direction: None,
+            nulls: None,
+        }
+    }
+
+    /// Set the collation, e.g. `"C"`.
+    pub fn collate<N>(mut self, collation: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.collation = Some(collation.into_iden());
+        self
+    }
+
+    /// Set the operator class, e.g. `text_pattern_ops`.
+    pub fn opclass<N>(mut self, opclass: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.opclass = Some(opclass.into_iden());
+        self
+    }
+
+    /// Add a parameter of the operator class.
+    pub fn opclass_param<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: IntoIden,
+        V: Into<Expr>,
+    {
+        self.opclass_params.push(IndexOption::new(name, value));
+        self
+    }
+
+    /// Sort in ascending order (the default).
+    pub fn asc(mut self) -> Self {
+        self.direction = Some(SortDirection::Asc);
+        self
+    }
+
+    /// Sort in descending order.
+    pub fn desc(mut self) -> Self {
+        self.direction = Some(SortDirection::Desc);
+        self
+    }
+
+    /// Sort nulls before non-nulls.
+    pub fn nulls_first(mut self) -> Self {
+        self.nulls = Some(NullOrdering::First);
+        self
+    }
+
+    /// Sort nulls after non-nulls.
+    pub fn nulls_last(mut self) -> Self {
+        self.nulls = Some(NullOrdering::Last);
+        self
+    }
+}
+
+impl From<Expr> for IndexElement {
+    fn from(expr: Expr) -> Self {
+        IndexElement::expr(expr)
+    }
+}
+
+pub(crate) fn write_index_element<W: SqlWriter>(w: &mut W, element: &IndexElement) {
+    match &element.expr {
+        // Wrap opclass expressions in parentheses for disambiguation
+        expr @ (Expr::Binary(_, _, _) | Expr::Unary(_, _)) => {
+            write_tuple(w, std::slice::from_ref(expr));
+        }
+        expr => write_expr(w, expr),
+    }
+    if let Some(collation) = &element.collation {
+        w.push_str(" COLLATE ");
+        write_iden(w, collation);
+    }
+    if let Some(opclass) = &element.opclass {
+        w.push_char(' ');
+        write_iden(w, opclass);
+        if !element.opclass_params.is_empty() {
+            w.push_char(' ');
+            write_index_option_list(w, &element.opclass_params);
+        }
+    }
+    match element.direction {
+        Some(SortDirection::Asc) => w.push_str(" ASC"),
+        Some(SortDirection::Desc) => w.push_str(" DESC"),
+        None => {}
+    }
+    match element.nulls {
+        Some(NullOrdering::First) => w.push_str(" NULLS FIRST"),
+        Some(NullOrdering::Last) => w.push_str(" NULLS LAST"),
+        None => {}
+    }
+}
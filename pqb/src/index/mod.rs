@@ -14,9 +14,15 @@
 
 //! Index definition and alterations statements.
 
+mod alter;
 mod create;
 mod drop;
+mod element;
+mod reindex;
 
+pub use self::alter::*;
 pub use self::create::*;
 pub use self::drop::*;
+pub use self::element::*;
+pub use self::reindex::*;
 pub use crate::types::DropBehavior;
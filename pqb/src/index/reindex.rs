@@ -0,0 +1,119 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::SqlWriterValues;
+use crate::types::SchemaName;
+use crate::types::TableName;
+use crate::types::write_schema_name;
+use crate::types::write_table_name;
+use crate::writer::SqlWriter;
+
+/// REINDEX statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Reindex {
+    target: Option<ReindexTarget>,
+    concurrently: bool,
+    verbose: bool,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum ReindexTarget {
+    Index(TableName),
+    Table(TableName),
+    Schema(SchemaName),
+}
+
+impl Reindex {
+    /// Create a new REINDEX statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_reindex(&mut w, self);
+        w
+    }
+
+    /// Convert the REINDEX statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_reindex(&mut sql, self);
+        sql
+    }
+
+    /// Rebuild the given index.
+    pub fn index<T>(mut self, index: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.target = Some(ReindexTarget::Index(index.into()));
+        self
+    }
+
+    /// Rebuild all indexes of the given table.
+    pub fn table<T>(mut self, table: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.target = Some(ReindexTarget::Table(table.into()));
+        self
+    }
+
+    /// Rebuild all indexes in the given schema.
+    pub fn schema<S>(mut self, schema: S) -> Self
+    where
+        S: Into<SchemaName>,
+    {
+        self.target = Some(ReindexTarget::Schema(schema.into()));
+        self
+    }
+
+    /// Rebuild without locking out writes.
+    ///
+    /// This cannot run inside a transaction block.
+    pub fn concurrently(mut self) -> Self {
+        self.concurrently = true;
+        self
+    }
+
+    /// Print a progress report as each index is rebuilt.
+    pub fn verbose(mut self) -> Self {
+        self.verbose = true;
+        self
+    }
+}
+
+fn write_reindex<W: SqlWriter>(w: &mut W, reindex: &Reindex) {
+    w.push_str("REINDEX ");
+    if reindex.verbose {
+        w.push_str("(VERBOSE) ");
+    }
+    w.push_str(match &reindex.target {
+        Some(ReindexTarget::Index(_)) => "INDEX ",
+        Some(ReindexTarget::Table(_)) => "TABLE ",
+        Some(ReindexTarget::Schema(_)) => "SCHEMA ",
+        None => "",
+    });
+    if reindex.concurrently {
+        w.push_str("CONCURRENTLY ");
+    }
+    match &reindex.target {
+        Some(ReindexTarget::Index(name) | ReindexTarget::Table(name)) => write_table_name(w, name),
+        Some(ReindexTarget::Schema(name)) => write_schema_name(w, name),
+        None => {}
+    }
+}
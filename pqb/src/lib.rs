@@ -29,14 +29,29 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![deny(missing_docs)]
 
+pub mod copy;
+pub mod cursor;
 pub mod expr;
 pub mod func;
 pub mod index;
+pub mod maintenance;
+#[cfg(feature = "parser")]
+pub mod parser;
+pub mod policy;
+pub mod prepare;
+pub mod privilege;
 pub mod query;
+pub mod role;
+pub mod routine;
 pub mod schema;
+pub mod sequence;
+pub mod session;
 pub mod table;
+pub mod trigger;
 pub mod types;
+pub mod user_type;
 pub mod value;
+pub mod view;
 
 mod writer;
 pub use self::writer::SqlWriterValues;
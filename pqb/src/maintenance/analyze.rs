@@ -0,0 +1,142 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::maintenance::vacuum::MaintenanceRelation;
+use crate::maintenance::vacuum::write_maintenance_relations;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::TableName;
+use crate::value::write_string_value;
+use crate::writer::SqlWriter;
+use crate::writer::write_false;
+
+/// ANALYZE statement builder.
+///
+/// Without tables, every table of the current database that the user can analyze is processed.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Analyze {
+    relations: Vec<MaintenanceRelation>,
+    verbose: Option<bool>,
+    skip_locked: Option<bool>,
+    buffer_usage_limit: Option<String>,
+}
+
+impl Analyze {
+    /// Create a new ANALYZE statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_analyze(&mut w, self);
+        w
+    }
+
+    /// Convert the ANALYZE statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_analyze(&mut sql, self);
+        sql
+    }
+
+    /// Add a table to analyze.
+    pub fn table<T>(mut self, table: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.relations
+            .push(MaintenanceRelation::new(table, Vec::<Iden>::new()));
+        self
+    }
+
+    /// Add a table to analyze, collecting statistics only for the given columns.
+    pub fn table_columns<T, C, I>(mut self, table: T, columns: I) -> Self
+    where
+        T: Into<TableName>,
+        C: IntoIden,
+        I: IntoIterator<Item = C>,
+    {
+        self.relations
+            .push(MaintenanceRelation::new(table, columns));
+        self
+    }
+
+    /// Set `VERBOSE`.
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = Some(verbose);
+        self
+    }
+
+    /// Set `SKIP_LOCKED`.
+    pub fn skip_locked(mut self, skip_locked: bool) -> Self {
+        self.skip_locked = Some(skip_locked);
+        self
+    }
+
+    /// Set `BUFFER_USAGE_LIMIT`, e.g. `"256MB"`.
+    pub fn buffer_usage_limit<S>(mut self, size: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.buffer_usage_limit = Some(size.into());
+        self
+    }
+}
+
+fn write_analyze<W: SqlWriter>(w: &mut W, analyze: &Analyze) {
+    w.push_str("ANALYZE");
+
+    let has_options = analyze.verbose.is_some()
+        || analyze.skip_locked.is_some()
+        || analyze.buffer_usage_limit.is_some();
+
+    if has_options {
+        w.push_str(" (");
+
+        let mut is_first = true;
+        macro_rules! write_comma_if_not_first {
+            () => {
+                if is_first {
+                    is_first = false
+                } else {
+                    w.push_str(", ");
+                }
+            };
+        }
+        if let Some(verbose) = analyze.verbose {
+            write_comma_if_not_first!();
+            w.push_str("VERBOSE");
+            write_false(w, verbose);
+        }
+        if let Some(skip_locked) = analyze.skip_locked {
+            write_comma_if_not_first!();
+            w.push_str("SKIP_LOCKED");
+            write_false(w, skip_locked);
+        }
+        if let Some(size) = &analyze.buffer_usage_limit {
+            write_comma_if_not_first!();
+            w.push_str("BUFFER_USAGE_LIMIT ");
+            write_string_value(w, size);
+        }
+        let _ = is_first;
+
+        w.push_str(")");
+    }
+
+    write_maintenance_relations(w, &analyze.relations);
+}
@@ -0,0 +1,46 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::writer::SqlWriter;
+
+/// CHECKPOINT statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Checkpoint;
+
+impl Checkpoint {
+    /// Create a new CHECKPOINT statement.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_checkpoint(&mut w, self);
+        w
+    }
+
+    /// Convert the CHECKPOINT statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_checkpoint(&mut sql, self);
+        sql
+    }
+}
+
+fn write_checkpoint<W: SqlWriter>(w: &mut W, _: &Checkpoint) {
+    w.push_str("CHECKPOINT");
+}
@@ -0,0 +1,98 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::TableName;
+use crate::types::write_iden;
+use crate::types::write_table_name;
+use crate::writer::SqlWriter;
+use crate::writer::write_false;
+
+/// CLUSTER statement builder.
+///
+/// Without a table, every table of the current database that was previously clustered is
+/// reclustered.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cluster {
+    table: Option<TableName>,
+    index: Option<Iden>,
+    verbose: Option<bool>,
+}
+
+impl Cluster {
+    /// Create a new CLUSTER statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_cluster(&mut w, self);
+        w
+    }
+
+    /// Convert the CLUSTER statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_cluster(&mut sql, self);
+        sql
+    }
+
+    /// Set the table to cluster.
+    pub fn table<T>(mut self, table: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.table = Some(table.into());
+        self
+    }
+
+    /// Set the index to order the table by.
+    ///
+    /// Without an index, the index the table was previously clustered on is used.
+    pub fn using_index<N>(mut self, index: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.index = Some(index.into_iden());
+        self
+    }
+
+    /// Set `VERBOSE`.
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = Some(verbose);
+        self
+    }
+}
+
+fn write_cluster<W: SqlWriter>(w: &mut W, cluster: &Cluster) {
+    w.push_str("CLUSTER");
+    if let Some(verbose) = cluster.verbose {
+        w.push_str(" (VERBOSE");
+        write_false(w, verbose);
+        w.push_char(')');
+    }
+    if let Some(table) = &cluster.table {
+        w.push_char(' ');
+        write_table_name(w, table);
+        if let Some(index) = &cluster.index {
+            w.push_str(" USING ");
+            write_iden(w, index);
+        }
+    }
+}
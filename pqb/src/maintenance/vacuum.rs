@@ -0,0 +1,297 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::TableName;
+use crate::types::write_iden;
+use crate::types::write_table_name;
+use crate::value::write_string_value;
+use crate::writer::SqlWriter;
+use crate::writer::write_false;
+
+/// VACUUM statement builder.
+///
+/// Without tables, every table of the current database that the user can vacuum is processed.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vacuum {
+    relations: Vec<MaintenanceRelation>,
+    full: Option<bool>,
+    freeze: Option<bool>,
+    verbose: Option<bool>,
+    analyze: Option<bool>,
+    disable_page_skipping: Option<bool>,
+    skip_locked: Option<bool>,
+    index_cleanup: Option<IndexCleanup>,
+    process_main: Option<bool>,
+    process_toast: Option<bool>,
+    truncate: Option<bool>,
+    parallel: Option<u32>,
+    buffer_usage_limit: Option<String>,
+}
+
+/// Values of the VACUUM `INDEX_CLEANUP` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+#[expect(missing_docs)]
+pub enum IndexCleanup {
+    Auto,
+    On,
+    Off,
+}
+
+/// A table to vacuum or analyze, with an optional list of columns.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct MaintenanceRelation {
+    table: TableName,
+    columns: Vec<Iden>,
+}
+
+impl MaintenanceRelation {
+    pub(crate) fn new<T, C, I>(table: T, columns: I) -> Self
+    where
+        T: Into<TableName>,
+        C: IntoIden,
+        I: IntoIterator<Item = C>,
+    {
+        Self {
+            table: table.into(),
+            columns: columns.into_iter().map(|c| c.into_iden()).collect(),
+        }
+    }
+}
+
+impl Vacuum {
+    /// Create a new VACUUM statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_vacuum(&mut w, self);
+        w
+    }
+
+    /// Convert the VACUUM statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_vacuum(&mut sql, self);
+        sql
+    }
+
+    /// Add a table to vacuum.
+    pub fn table<T>(mut self, table: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.relations
+            .push(MaintenanceRelation::new(table, Vec::<Iden>::new()));
+        self
+    }
+
+    /// Add a table to vacuum, analyzing only the given columns.
+    pub fn table_columns<T, C, I>(mut self, table: T, columns: I) -> Self
+    where
+        T: Into<TableName>,
+        C: IntoIden,
+        I: IntoIterator<Item = C>,
+    {
+        self.relations
+            .push(MaintenanceRelation::new(table, columns));
+        self
+    }
+
+    /// Set `FULL`.
+    pub fn full(mut self, full: bool) -> Self {
+        self.full = Some(full);
+        self
+    }
+
+    /// Set `FREEZE`.
+    pub fn freeze(mut self, freeze: bool) -> Self {
+        self.freeze = Some(freeze);
+        self
+    }
+
+    /// Set `VERBOSE`.
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = Some(verbose);
+        self
+    }
+
+    /// Set `ANALYZE`.
+    pub fn analyze(mut self, analyze: bool) -> Self {
+        self.analyze = Some(analyze);
+        self
+    }
+
+    /// Set `DISABLE_PAGE_SKIPPING`.
+    pub fn disable_page_skipping(mut self, disable_page_skipping: bool) -> Self {
+        self.disable_page_skipping = Some(disable_page_skipping);
+        self
+    }
+
+    /// Set `SKIP_LOCKED`.
+    pub fn skip_locked(mut self, skip_locked: bool) -> Self {
+        self.skip_locked = Some(skip_locked);
+        self
+    }
+
+    /// Set `INDEX_CLEANUP`.
+    pub fn index_cleanup(mut self, index_cleanup: IndexCleanup) -> Self {
+        self.index_cleanup = Some(index_cleanup);
+        self
+    }
+
+    /// Set `PROCESS_MAIN`.
+    pub fn process_main(mut self, process_main: bool) -> Self {
+        self.process_main = Some(process_main);
+        self
+    }
+
+    /// Set `PROCESS_TOAST`.
+    pub fn process_toast(mut self, process_toast: bool) -> Self {
+        self.process_toast = Some(process_toast);
+        self
+    }
+
+    /// Set `TRUNCATE`.
+    pub fn truncate(mut self, truncate: bool) -> Self {
+        self.truncate = Some(truncate);
+        self
+    }
+
+    /// Set `PARALLEL` to the number of parallel workers for index vacuuming.
+    pub fn parallel(mut self, workers: u32) -> Self {
+        self.parallel = Some(workers);
+        self
+    }
+
+    /// Set `BUFFER_USAGE_LIMIT`, e.g. `"256MB"`.
+    pub fn buffer_usage_limit<S>(mut self, size: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.buffer_usage_limit = Some(size.into());
+        self
+    }
+}
+
+fn write_vacuum<W: SqlWriter>(w: &mut W, vacuum: &Vacuum) {
+    w.push_str("VACUUM");
+
+    let has_options = vacuum.full.is_some()
+        || vacuum.freeze.is_some()
+        || vacuum.verbose.is_some()
+        || vacuum.analyze.is_some()
+        || vacuum.disable_page_skipping.is_some()
+        || vacuum.skip_locked.is_some()
+        || vacuum.index_cleanup.is_some()
+        || vacuum.process_main.is_some()
+        || vacuum.process_toast.is_some()
+        || vacuum.truncate.is_some()
+        || vacuum.parallel.is_some()
+        || vacuum.buffer_usage_limit.is_some();
+
+    if has_options {
+        w.push_str(" (");
+
+        let mut is_first = true;
+        macro_rules! write_comma_if_not_first {
+            () => {
+                if is_first {
+                    is_first = false
+                } else {
+                    w.push_str(", ");
+                }
+            };
+        }
+        let options = [
+            ("FULL", vacuum.full),
+            ("FREEZE", vacuum.freeze),
+            ("VERBOSE", vacuum.verbose),
+            ("ANALYZE", vacuum.analyze),
+            ("DISABLE_PAGE_SKIPPING", vacuum.disable_page_skipping),
+            ("SKIP_LOCKED", vacuum.skip_locked),
+        ];
+        for (name, value) in options {
+            if let Some(value) = value {
+                write_comma_if_not_first!();
+                w.push_str(name);
+                write_false(w, value);
+            }
+        }
+        if let Some(index_cleanup) = vacuum.index_cleanup {
+            write_comma_if_not_first!();
+            w.push_str("INDEX_CLEANUP ");
+            w.push_str(match index_cleanup {
+                IndexCleanup::Auto => "AUTO",
+                IndexCleanup::On => "ON",
+                IndexCleanup::Off => "OFF",
+            });
+        }
+        let options = [
+            ("PROCESS_MAIN", vacuum.process_main),
+            ("PROCESS_TOAST", vacuum.process_toast),
+            ("TRUNCATE", vacuum.truncate),
+        ];
+        for (name, value) in options {
+            if let Some(value) = value {
+                write_comma_if_not_first!();
+                w.push_str(name);
+                write_false(w, value);
+            }
+        }
+        if let Some(workers) = vacuum.parallel {
+            write_comma_if_not_first!();
+            w.push_fmt(format_args!("PARALLEL {workers}"));
+        }
+        if let Some(size) = &vacuum.buffer_usage_limit {
+            write_comma_if_not_first!();
+            w.push_str("BUFFER_USAGE_LIMIT ");
+            write_string_value(w, size);
+        }
+        let _ = is_first;
+
+        w.push_str(")");
+    }
+
+    write_maintenance_relations(w, &vacuum.relations);
+}
+
+pub(crate) fn write_maintenance_relations<W: SqlWriter>(
+    w: &mut W,
+    relations: &[MaintenanceRelation],
+) {
+    for (i, relation) in relations.iter().enumerate() {
+        w.push_str(if i == 0 { " " } else { ", " });
+        write_table_name(w, &relation.table);
+        if !relation.columns.is_empty() {
+            w.push_str(" (");
+            for (j, column) in relation.columns.iter().enumerate() {
+                if j > 0 {
+                    w.push_str(", ");
+                }
+                write_iden(w, column);
+            }
+            w.push_char(')');
+        }
+    }
+}
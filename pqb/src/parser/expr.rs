@@ -0,0 +1,307 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use sqlparser::ast;
+
+use crate::expr::BinaryOp;
+use crate::expr::Expr;
+use crate::expr::Keyword;
+use crate::expr::SubQueryOp;
+use crate::func::FunctionCall;
+use crate::parser::ParseError;
+use crate::parser::query::convert_query;
+use crate::parser::unsupported;
+use crate::types::ColumnName;
+use crate::types::ColumnRef;
+use crate::types::DatabaseName;
+use crate::types::Iden;
+use crate::types::SchemaName;
+use crate::types::TableName;
+use crate::value::Value;
+
+/// Convert an expression, falling back to [`Expr::custom`] for unsupported nodes.
+pub(super) fn convert_expr(expr: &ast::Expr) -> Expr {
+    try_convert_expr(expr).unwrap_or_else(|| Expr::custom(expr.to_string()))
+}
+
+fn try_convert_expr(expr: &ast::Expr) -> Option<Expr> {
+    let expr = match expr {
+        ast::Expr::Identifier(ident) => {
+            if ident.quote_style.is_none() && ident.value.eq_ignore_ascii_case("default") {
+                return None;
+            }
+            Expr::column(convert_ident(ident))
+        }
+        ast::Expr::CompoundIdentifier(idents) => {
+            Expr::Column(ColumnRef::Column(convert_column_name(idents).ok()?))
+        }
+        ast::Expr::Wildcard(_) => Expr::asterisk(),
+        ast::Expr::QualifiedWildcard(name, _) => {
+            Expr::Column(ColumnRef::Asterisk(Some(convert_table_name(name).ok()?)))
+        }
+        ast::Expr::Value(value) => convert_value(&value.value)?,
+        ast::Expr::Nested(expr) => convert_expr(expr),
+        ast::Expr::Tuple(exprs) => Expr::tuple(exprs.iter().map(convert_expr)),
+        ast::Expr::IsNull(expr) => convert_expr(expr).is_null(),
+        ast::Expr::IsNotNull(expr) => convert_expr(expr).is_not_null(),
+        ast::Expr::IsTrue(expr) => convert_expr(expr).is(true),
+        ast::Expr::IsNotTrue(expr) => convert_expr(expr).is_not(true),
+        ast::Expr::IsFalse(expr) => convert_expr(expr).is(false),
+        ast::Expr::IsNotFalse(expr) => convert_expr(expr).is_not(false),
+        ast::Expr::InList {
+            expr,
+            list,
+            negated,
+        } => {
+            let list = list.iter().map(convert_expr);
+            if *negated {
+                convert_expr(expr).is_not_in(list)
+            } else {
+                convert_expr(expr).is_in(list)
+            }
+        }
+        ast::Expr::InSubquery {
+            expr,
+            subquery,
+            negated,
+        } => {
+            let op = if *negated {
+                BinaryOp::NotIn
+            } else {
+                BinaryOp::In
+            };
+            let subquery = convert_query(subquery).ok()?;
+            convert_expr(expr).binary(op, Expr::SubQuery(None, Box::new(subquery)))
+        }
+        ast::Expr::Between {
+            expr,
+            negated,
+            low,
+            high,
+        } => {
+            let (low, high) = (convert_expr(low), convert_expr(high));
+            if *negated {
+                convert_expr(expr).not_between(low, high)
+            } else {
+                convert_expr(expr).between(low, high)
+            }
+        }
+        ast::Expr::BinaryOp { left, op, right } => {
+            convert_expr(left).binary(convert_binary_op(op)?, convert_expr(right))
+        }
+        ast::Expr::Like {
+            negated,
+            any: false,
+            expr,
+            pattern,
+            escape_char: None,
+        } => {
+            let op = if *negated {
+                BinaryOp::NotLike
+            } else {
+                BinaryOp::Like
+            };
+            convert_expr(expr).binary(op, convert_expr(pattern))
+        }
+        ast::Expr::UnaryOp { op, expr } => match op {
+            ast::UnaryOperator::Not => convert_expr(expr).not(),
+            ast::UnaryOperator::Minus => match &**expr {
+                ast::Expr::Value(value) => match &value.value {
+                    ast::Value::Number(n, false) => convert_number(&format!("-{n}"))?,
+                    _ => return None,
+                },
+                _ => return None,
+            },
+            _ => return None,
+        },
+        ast::Expr::Cast {
+            kind: ast::CastKind::Cast | ast::CastKind::DoubleColon,
+            expr,
+            data_type,
+            format: None,
+        } => Expr::FunctionCall(FunctionCall::cast_as(
+            convert_expr(expr),
+            data_type.to_string(),
+        )),
+        ast::Expr::Exists { subquery, negated } => {
+            let subquery = convert_query(subquery).ok()?;
+            let exists = Expr::SubQuery(Some(SubQueryOp::Exists), Box::new(subquery));
+            if *negated { exists.not() } else { exists }
+        }
+        ast::Expr::Subquery(subquery) => {
+            Expr::SubQuery(None, Box::new(convert_query(subquery).ok()?))
+        }
+        ast::Expr::Function(function) => convert_function(function)?,
+        _ => return None,
+    };
+    Some(expr)
+}
+
+fn convert_function(function: &ast::Function) -> Option<Expr> {
+    let ast::Function {
+        name,
+        uses_odbc_syntax: false,
+        parameters: ast::FunctionArguments::None,
+        args,
+        within_group,
+        filter: None,
+        null_treatment: None,
+        over: None,
+    } = function
+    else {
+        return None;
+    };
+    if !within_group.is_empty() {
+        return None;
+    }
+
+    match args {
+        ast::FunctionArguments::None => {
+            if name.to_string().eq_ignore_ascii_case("current_timestamp") {
+                Some(Expr::current_timestamp())
+            } else {
+                None
+            }
+        }
+        ast::FunctionArguments::List(list) => {
+            if list.duplicate_treatment.is_some() || !list.clauses.is_empty() {
+                return None;
+            }
+            let args = list
+                .args
+                .iter()
+                .map(|arg| match arg {
+                    ast::FunctionArg::Unnamed(ast::FunctionArgExpr::Expr(expr)) => {
+                        Some(convert_expr(expr))
+                    }
+                    ast::FunctionArg::Unnamed(ast::FunctionArgExpr::Wildcard) => {
+                        Some(Expr::asterisk())
+                    }
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some(Expr::function(name.to_string(), args))
+        }
+        ast::FunctionArguments::Subquery(_) => None,
+    }
+}
+
+fn convert_binary_op(op: &ast::BinaryOperator) -> Option<BinaryOp> {
+    let op = match op {
+        ast::BinaryOperator::And => BinaryOp::And,
+        ast::BinaryOperator::Or => BinaryOp::Or,
+        ast::BinaryOperator::Eq => BinaryOp::Equal,
+        ast::BinaryOperator::NotEq => BinaryOp::NotEqual,
+        ast::BinaryOperator::Lt => BinaryOp::LessThan,
+        ast::BinaryOperator::LtEq => BinaryOp::LessThanOrEqual,
+        ast::BinaryOperator::Gt => BinaryOp::GreaterThan,
+        ast::BinaryOperator::GtEq => BinaryOp::GreaterThanOrEqual,
+        ast::BinaryOperator::Plus => BinaryOp::Add,
+        ast::BinaryOperator::Minus => BinaryOp::Sub,
+        ast::BinaryOperator::Multiply => BinaryOp::Mul,
+        ast::BinaryOperator::Divide => BinaryOp::Div,
+        ast::BinaryOperator::Modulo => BinaryOp::Mod,
+        ast::BinaryOperator::PGBitwiseShiftLeft => BinaryOp::LShift,
+        ast::BinaryOperator::PGBitwiseShiftRight => BinaryOp::RShift,
+        ast::BinaryOperator::AtArrow => BinaryOp::Contains,
+        ast::BinaryOperator::ArrowAt => BinaryOp::ContainedBy,
+        ast::BinaryOperator::PGOverlap => BinaryOp::Overlaps,
+        _ => return None,
+    };
+    Some(op)
+}
+
+fn convert_value(value: &ast::Value) -> Option<Expr> {
+    let value = match value {
+        ast::Value::Number(n, false) => return convert_number(n),
+        ast::Value::SingleQuotedString(s) | ast::Value::EscapedStringLiteral(s) => {
+            Value::from(s.as_str())
+        }
+        ast::Value::Boolean(b) => Value::from(*b),
+        ast::Value::Null => return Some(Expr::Keyword(Keyword::Null)),
+        _ => return None,
+    };
+    Some(Expr::Value(value))
+}
+
+/// Convert a numeric literal, keeping it verbatim if it cannot be represented without loss.
+fn convert_number(n: &str) -> Option<Expr> {
+    if let Ok(i) = n.parse::<i32>() {
+        return Some(Expr::value(i));
+    }
+    if let Ok(i) = n.parse::<i64>() {
+        return Some(Expr::value(i));
+    }
+    if let Ok(f) = n.parse::<f64>()
+        && f.to_string() == n
+    {
+        return Some(Expr::value(f));
+    }
+    Some(Expr::custom(n.to_owned()))
+}
+
+/// Convert an identifier, folding unquoted names to lower case as PostgreSQL does.
+pub(super) fn convert_ident(ident: &ast::Ident) -> Iden {
+    match ident.quote_style {
+        Some(_) => Iden::new(ident.value.clone()),
+        None => Iden::new(ident.value.to_lowercase()),
+    }
+}
+
+fn convert_object_name_parts(name: &ast::ObjectName) -> Result<Vec<Iden>, ParseError> {
+    name.0
+        .iter()
+        .map(|part| match part {
+            ast::ObjectNamePart::Identifier(ident) => Ok(convert_ident(ident)),
+            ast::ObjectNamePart::Function(_) => Err(unsupported(name)),
+        })
+        .collect()
+}
+
+pub(super) fn convert_table_name(name: &ast::ObjectName) -> Result<TableName, ParseError> {
+    let mut parts = convert_object_name_parts(name)?;
+    let table = parts.pop().ok_or_else(|| unsupported(name))?;
+    let schema = match parts.len() {
+        0 => None,
+        1 => Some(SchemaName(None, parts.remove(0))),
+        2 => {
+            let schema = parts.remove(1);
+            Some(SchemaName(Some(DatabaseName(parts.remove(0))), schema))
+        }
+        _ => return Err(unsupported(name)),
+    };
+    Ok(TableName(schema, table))
+}
+
+fn convert_column_name(idents: &[ast::Ident]) -> Result<ColumnName, ParseError> {
+    let (column, table) = idents
+        .split_last()
+        .ok_or_else(|| ParseError::Unsupported("empty compound identifier".to_owned()))?;
+    let table = if table.is_empty() {
+        None
+    } else {
+        let table = ast::ObjectName::from(table.to_vec());
+        Some(convert_table_name(&table)?)
+    };
+    Ok(ColumnName(table, convert_ident(column)))
+}
+
+/// Convert a single-part object name such as a column in an INSERT column list.
+pub(super) fn convert_single_name(name: &ast::ObjectName) -> Result<Iden, ParseError> {
+    let mut parts = convert_object_name_parts(name)?;
+    if parts.len() != 1 {
+        return Err(unsupported(name));
+    }
+    Ok(parts.remove(0))
+}
@@ -0,0 +1,171 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Parse PostgreSQL statements into pqb builders.
+//!
+//! This module is available with the `parser` feature. It turns SQL text into the same builder
+//! values you would construct by hand, so that existing queries can be migrated incrementally:
+//!
+//! ```
+//! use pqb::parser::Statement;
+//! use pqb::parser::parse_statement;
+//!
+//! let stmt = parse_statement("SELECT id FROM users WHERE age > 18").unwrap();
+//! let Statement::Select(select) = stmt else {
+//!     unreachable!()
+//! };
+//! assert_eq!(
+//!     select.to_sql(),
+//!     r#"SELECT "id" FROM "users" WHERE "age" > 18"#
+//! );
+//! ```
+//!
+//! Expressions that have no dedicated representation in [`Expr`] are preserved verbatim with
+//! [`Expr::custom`]. Statement-level constructs that the builders cannot express (for example,
+//! `DISTINCT` or `FULL JOIN`) are rejected with [`ParseError::Unsupported`] instead of being
+//! silently dropped.
+//!
+//! Unquoted identifiers are folded to lower case as PostgreSQL does, so `SELECT Id FROM Users`
+//! renders as `SELECT "id" FROM "users"`.
+
+use std::fmt;
+
+use sqlparser::ast;
+use sqlparser::dialect::PostgreSqlDialect;
+use sqlparser::parser::Parser;
+use sqlparser::parser::ParserError;
+
+use crate::SqlWriterValues;
+use crate::expr::Expr;
+use crate::index::CreateIndex;
+use crate::query::Delete;
+use crate::query::Insert;
+use crate::query::Select;
+use crate::query::Update;
+use crate::table::CreateTable;
+
+mod expr;
+mod query;
+mod table;
+
+/// A statement parsed from SQL text.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+#[expect(missing_docs)]
+pub enum Statement {
+    Select(Select),
+    Insert(Insert),
+    Update(Update),
+    Delete(Delete),
+    CreateTable(CreateTable),
+    CreateIndex(CreateIndex),
+}
+
+impl Statement {
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        match self {
+            Statement::Select(s) => s.to_values(),
+            Statement::Insert(i) => i.to_values(),
+            Statement::Update(u) => u.to_values(),
+            Statement::Delete(d) => d.to_values(),
+            Statement::CreateTable(t) => t.to_values(),
+            Statement::CreateIndex(i) => i.to_values(),
+        }
+    }
+
+    /// Convert the statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        match self {
+            Statement::Select(s) => s.to_sql(),
+            Statement::Insert(i) => i.to_sql(),
+            Statement::Update(u) => u.to_sql(),
+            Statement::Delete(d) => d.to_sql(),
+            Statement::CreateTable(t) => t.to_sql(),
+            Statement::CreateIndex(i) => i.to_sql(),
+        }
+    }
+}
+
+/// Errors returned when parsing SQL text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The input is not valid PostgreSQL syntax.
+    Syntax(String),
+    /// The input is valid, but uses a construct that the builders cannot represent.
+    Unsupported(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Syntax(msg) => write!(f, "syntax error: {msg}"),
+            ParseError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ParserError> for ParseError {
+    fn from(err: ParserError) -> Self {
+        ParseError::Syntax(err.to_string())
+    }
+}
+
+/// Parse a semicolon-separated list of statements.
+pub fn parse(sql: &str) -> Result<Vec<Statement>, ParseError> {
+    let statements = Parser::parse_sql(&PostgreSqlDialect {}, sql)?;
+    statements.iter().map(convert_statement).collect()
+}
+
+/// Parse exactly one statement.
+pub fn parse_statement(sql: &str) -> Result<Statement, ParseError> {
+    let mut statements = parse(sql)?;
+    if statements.len() != 1 {
+        return Err(ParseError::Syntax(format!(
+            "expected exactly one statement, found {}",
+            statements.len()
+        )));
+    }
+    Ok(statements.remove(0))
+}
+
+/// Parse a standalone expression, e.g. a WHERE condition.
+pub fn parse_expr(sql: &str) -> Result<Expr, ParseError> {
+    let dialect = PostgreSqlDialect {};
+    let expr = Parser::new(&dialect).try_with_sql(sql)?.parse_expr()?;
+    Ok(expr::convert_expr(&expr))
+}
+
+fn convert_statement(statement: &ast::Statement) -> Result<Statement, ParseError> {
+    match statement {
+        ast::Statement::Query(query) => query::convert_query_statement(query),
+        ast::Statement::Insert(insert) => query::convert_insert(insert).map(Statement::Insert),
+        ast::Statement::Update(update) => query::convert_update(update).map(Statement::Update),
+        ast::Statement::Delete(delete) => query::convert_delete(delete).map(Statement::Delete),
+        ast::Statement::CreateTable(create) => {
+            table::convert_create_table(create).map(Statement::CreateTable)
+        }
+        ast::Statement::CreateIndex(create) => {
+            table::convert_create_index(create).map(Statement::CreateIndex)
+        }
+        _ => Err(unsupported(statement)),
+    }
+}
+
+fn unsupported(node: impl fmt::Display) -> ParseError {
+    ParseError::Unsupported(node.to_string())
+}
@@ -0,0 +1,601 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use sqlparser::ast;
+
+use crate::expr::Expr;
+use crate::parser::ParseError;
+use crate::parser::Statement;
+use crate::parser::expr::convert_expr;
+use crate::parser::expr::convert_ident;
+use crate::parser::expr::convert_single_name;
+use crate::parser::expr::convert_table_name;
+use crate::parser::unsupported;
+use crate::query::CommonTableExpression;
+use crate::query::Delete;
+use crate::query::Insert;
+use crate::query::OnConflict;
+use crate::query::Order;
+use crate::query::Returning;
+use crate::query::RowLevelLock;
+use crate::query::Select;
+use crate::query::Update;
+use crate::query::With;
+use crate::types::ColumnName;
+use crate::types::ColumnRef;
+use crate::types::Iden;
+use crate::types::TableName;
+use crate::types::TableRef;
+use crate::value::Value;
+
+/// Convert a top-level query, which may also be a data-modifying statement with a WITH clause.
+pub(super) fn convert_query_statement(query: &ast::Query) -> Result<Statement, ParseError> {
+    let with = match &query.with {
+        Some(with) => Some(convert_with(with)?),
+        None => None,
+    };
+
+    let is_plain = query.order_by.is_none()
+        && query.limit_clause.is_none()
+        && query.fetch.is_none()
+        && query.locks.is_empty();
+    let statement = match &*query.body {
+        ast::SetExpr::Insert(ast::Statement::Insert(insert)) if is_plain => {
+            let insert = convert_insert(insert)?;
+            Statement::Insert(match with {
+                Some(with) => insert.with(with),
+                None => insert,
+            })
+        }
+        ast::SetExpr::Update(ast::Statement::Update(update)) if is_plain => {
+            let update = convert_update(update)?;
+            Statement::Update(match with {
+                Some(with) => update.with(with),
+                None => update,
+            })
+        }
+        ast::SetExpr::Delete(ast::Statement::Delete(delete)) if is_plain => {
+            let delete = convert_delete(delete)?;
+            Statement::Delete(match with {
+                Some(with) => delete.with(with),
+                None => delete,
+            })
+        }
+        _ => Statement::Select(convert_query(query)?),
+    };
+    Ok(statement)
+}
+
+pub(super) fn convert_query(query: &ast::Query) -> Result<Select, ParseError> {
+    let ast::Query {
+        with,
+        body,
+        order_by,
+        limit_clause,
+        fetch,
+        locks,
+        for_clause,
+        settings,
+        format_clause,
+        pipe_operators,
+    } = query;
+    if fetch.is_some()
+        || for_clause.is_some()
+        || settings.is_some()
+        || format_clause.is_some()
+        || !pipe_operators.is_empty()
+    {
+        return Err(unsupported(query));
+    }
+
+    let mut select = match &**body {
+        ast::SetExpr::Select(select) => convert_select(select)?,
+        ast::SetExpr::Query(inner) => {
+            // the outer clauses would be merged into the inner ones, e.g. a LIMIT replaced
+            if has_query_clauses(inner) && has_query_clauses(query) {
+                return Err(unsupported(query));
+            }
+            convert_query(inner)?
+        }
+        _ => return Err(unsupported(body)),
+    };
+
+    if let Some(with) = with {
+        select = select.with(convert_with(with)?);
+    }
+
+    if let Some(order_by) = order_by {
+        let ast::OrderByKind::Expressions(exprs) = &order_by.kind else {
+            return Err(unsupported(order_by));
+        };
+        if order_by.interpolate.is_some() {
+            return Err(unsupported(order_by));
+        }
+        let orders = exprs
+            .iter()
+            .map(convert_order)
+            .collect::<Result<Vec<_>, _>>()?;
+        select = select.order_by(orders);
+    }
+
+    if let Some(limit_clause) = limit_clause {
+        let ast::LimitClause::LimitOffset {
+            limit,
+            offset,
+            limit_by,
+        } = limit_clause
+        else {
+            return Err(unsupported(limit_clause));
+        };
+        if !limit_by.is_empty() {
+            return Err(unsupported(limit_clause));
+        }
+        if let Some(limit) = limit {
+            select = select.limit(convert_count(limit)?);
+        }
+        if let Some(offset) = offset {
+            select = select.offset(convert_count(&offset.value)?);
+        }
+    }
+
+    match locks.as_slice() {
+        [] => {}
+        [lock] => select = select.lock(convert_lock(lock)?),
+        _ => return Err(unsupported(query)),
+    }
+
+    Ok(select)
+}
+
+fn has_query_clauses(query: &ast::Query) -> bool {
+    query.with.is_some()
+        || query.order_by.is_some()
+        || query.limit_clause.is_some()
+        || !query.locks.is_empty()
+}
+
+fn convert_select(select: &ast::Select) -> Result<Select, ParseError> {
+    if select.distinct.is_some()
+        || select.top.is_some()
+        || select.into.is_some()
+        || select.exclude.is_some()
+        || select.prewhere.is_some()
+        || select.qualify.is_some()
+        || select.value_table_mode.is_some()
+        || select.select_modifiers.is_some()
+        || !select.optimizer_hints.is_empty()
+        || !select.lateral_views.is_empty()
+        || !select.connect_by.is_empty()
+        || !select.cluster_by.is_empty()
+        || !select.distribute_by.is_empty()
+        || !select.sort_by.is_empty()
+        || !select.named_window.is_empty()
+        || select.flavor != ast::SelectFlavor::Standard
+    {
+        return Err(unsupported(select));
+    }
+
+    let mut result = Select::new();
+
+    for item in &select.projection {
+        result = match item {
+            ast::SelectItem::UnnamedExpr(expr) => result.expr(convert_expr(expr)),
+            ast::SelectItem::ExprWithAlias { expr, alias } => {
+                result.expr_as(convert_expr(expr), convert_ident(alias))
+            }
+            ast::SelectItem::Wildcard(options) if is_plain_wildcard(options) => {
+                result.expr(Expr::asterisk())
+            }
+            ast::SelectItem::QualifiedWildcard(
+                ast::SelectItemQualifiedWildcardKind::ObjectName(name),
+                options,
+            ) if is_plain_wildcard(options) => result.expr(Expr::Column(ColumnRef::Asterisk(
+                Some(convert_table_name(name)?),
+            ))),
+            _ => return Err(unsupported(item)),
+        };
+    }
+
+    for (i, from) in select.from.iter().enumerate() {
+        // joins are rendered after all FROM items, so they may only follow the last one
+        if !from.joins.is_empty() && i + 1 != select.from.len() {
+            return Err(unsupported(from));
+        }
+        result = match convert_table_factor(&from.relation)? {
+            TableRef::SubQuery(query, alias) => result.from_subquery(*query, alias),
+            table => result.from(table),
+        };
+        for join in &from.joins {
+            let table = convert_table_factor(&join.relation)?;
+            result = match &join.join_operator {
+                ast::JoinOperator::Join(ast::JoinConstraint::On(on))
+                | ast::JoinOperator::Inner(ast::JoinConstraint::On(on)) => {
+                    result.inner_join(table, convert_expr(on))
+                }
+                ast::JoinOperator::Left(ast::JoinConstraint::On(on))
+                | ast::JoinOperator::LeftOuter(ast::JoinConstraint::On(on)) => {
+                    result.left_join(table, convert_expr(on))
+                }
+                _ => return Err(unsupported(join)),
+            };
+        }
+    }
+
+    if let Some(selection) = &select.selection {
+        result = result.and_where(convert_expr(selection));
+    }
+
+    match &select.group_by {
+        ast::GroupByExpr::Expressions(exprs, modifiers) if modifiers.is_empty() => {
+            result = result.group_by_exprs(exprs.iter().map(convert_expr));
+        }
+        group_by => return Err(unsupported(group_by)),
+    }
+
+    if let Some(having) = &select.having {
+        result = result.and_having(convert_expr(having));
+    }
+
+    Ok(result)
+}
+
+fn is_plain_wildcard(options: &ast::WildcardAdditionalOptions) -> bool {
+    options.opt_ilike.is_none()
+        && options.opt_exclude.is_none()
+        && options.opt_except.is_none()
+        && options.opt_replace.is_none()
+        && options.opt_rename.is_none()
+        && options.opt_alias.is_none()
+}
+
+fn convert_table_factor(factor: &ast::TableFactor) -> Result<TableRef, ParseError> {
+    match factor {
+        ast::TableFactor::Table {
+            name,
+            alias,
+            args: None,
+            with_hints,
+            version: None,
+            with_ordinality: false,
+            partitions,
+            json_path: None,
+            sample: None,
+            index_hints,
+        } if with_hints.is_empty() && partitions.is_empty() && index_hints.is_empty() => {
+            let table = TableRef::Table(convert_table_name(name)?, None);
+            match alias {
+                None => Ok(table),
+                Some(alias) => Ok(table.alias(convert_table_alias(alias)?)),
+            }
+        }
+        ast::TableFactor::Derived {
+            lateral: false,
+            subquery,
+            alias: Some(alias),
+            sample: None,
+        } => {
+            let subquery = convert_query(subquery)?;
+            Ok(TableRef::SubQuery(
+                Box::new(subquery),
+                convert_table_alias(alias)?,
+            ))
+        }
+        _ => Err(unsupported(factor)),
+    }
+}
+
+fn convert_table_alias(alias: &ast::TableAlias) -> Result<Iden, ParseError> {
+    if !alias.columns.is_empty() || alias.at.is_some() {
+        return Err(unsupported(alias));
+    }
+    Ok(convert_ident(&alias.name))
+}
+
+fn convert_with(with: &ast::With) -> Result<With, ParseError> {
+    if with.recursive {
+        return Err(unsupported(with));
+    }
+
+    let mut result = With::new();
+    for cte in &with.cte_tables {
+        if cte.from.is_some() || cte.alias.at.is_some() {
+            return Err(unsupported(cte));
+        }
+        let mut table = CommonTableExpression::new(convert_ident(&cte.alias.name));
+        for column in &cte.alias.columns {
+            if column.data_type.is_some() {
+                return Err(unsupported(cte));
+            }
+            table = table.column(convert_ident(&column.name));
+        }
+        table = match &*cte.query.body {
+            ast::SetExpr::Values(values) if is_plain_query(&cte.query) => {
+                table.values(convert_literal_rows(values)?)
+            }
+            _ => table.select(convert_query(&cte.query)?),
+        };
+        table = match &cte.materialized {
+            Some(ast::CteAsMaterialized::Materialized) => table.materialized(true),
+            Some(ast::CteAsMaterialized::NotMaterialized) => table.materialized(false),
+            None => table,
+        };
+        result = result.cte(table);
+    }
+    Ok(result)
+}
+
+fn is_plain_query(query: &ast::Query) -> bool {
+    query.with.is_none()
+        && query.order_by.is_none()
+        && query.limit_clause.is_none()
+        && query.fetch.is_none()
+        && query.locks.is_empty()
+}
+
+/// Convert VALUES rows made of literals only, as used by a CTE.
+fn convert_literal_rows(values: &ast::Values) -> Result<Vec<Vec<Value>>, ParseError> {
+    values
+        .rows
+        .iter()
+        .map(|row| {
+            row.content
+                .iter()
+                .map(|expr| match convert_expr(expr) {
+                    Expr::Value(value) => Ok(value),
+                    _ => Err(unsupported(expr)),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn convert_order(order: &ast::OrderByExpr) -> Result<Order, ParseError> {
+    if order.with_fill.is_some() {
+        return Err(unsupported(order));
+    }
+    let mut result = Order::expr(convert_expr(&order.expr));
+    result = match &order.options.sort {
+        None | Some(ast::OrderBySort::Asc) => result.asc(),
+        Some(ast::OrderBySort::Desc) => result.desc(),
+        Some(_) => return Err(unsupported(order)),
+    };
+    result = match order.options.nulls_first {
+        None => result,
+        Some(true) => result.nulls_first(),
+        Some(false) => result.nulls_last(),
+    };
+    Ok(result)
+}
+
+fn convert_count(expr: &ast::Expr) -> Result<u64, ParseError> {
+    match expr {
+        ast::Expr::Value(value) => match &value.value {
+            ast::Value::Number(n, false) => n.parse().map_err(|_| unsupported(expr)),
+            _ => Err(unsupported(expr)),
+        },
+        _ => Err(unsupported(expr)),
+    }
+}
+
+fn convert_lock(lock: &ast::LockClause) -> Result<RowLevelLock, ParseError> {
+    let mut result = match lock.lock_type {
+        ast::LockType::Update => RowLevelLock::for_update(),
+        ast::LockType::Share => RowLevelLock::for_share(),
+    };
+    if let Some(of) = &lock.of {
+        result = result.tables([convert_single_name(of)?]);
+    }
+    result = match lock.nonblock {
+        None => result,
+        Some(ast::NonBlock::Nowait) => result.no_wait(),
+        Some(ast::NonBlock::SkipLocked) => result.skip_locked(),
+    };
+    Ok(result)
+}
+
+fn convert_returning(items: &[ast::SelectItem]) -> Result<Returning, ParseError> {
+    if let [ast::SelectItem::Wildcard(options)] = items
+        && is_plain_wildcard(options)
+    {
+        return Ok(Returning::all());
+    }
+    let exprs = items
+        .iter()
+        .map(|item| match item {
+            ast::SelectItem::UnnamedExpr(expr) => Ok(convert_expr(expr)),
+            _ => Err(unsupported(item)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Returning::exprs(exprs))
+}
+
+fn convert_target_table(table: &ast::TableWithJoins) -> Result<TableRef, ParseError> {
+    if !table.joins.is_empty() {
+        return Err(unsupported(table));
+    }
+    match convert_table_factor(&table.relation)? {
+        table @ TableRef::Table(..) => Ok(table),
+        _ => Err(unsupported(&table.relation)),
+    }
+}
+
+fn convert_assignments(assignments: &[ast::Assignment]) -> Result<Vec<(Iden, Expr)>, ParseError> {
+    assignments
+        .iter()
+        .map(|assignment| match &assignment.target {
+            ast::AssignmentTarget::ColumnName(name) => {
+                Ok((convert_single_name(name)?, convert_expr(&assignment.value)))
+            }
+            ast::AssignmentTarget::Tuple(_) => Err(unsupported(assignment)),
+        })
+        .collect()
+}
+
+pub(super) fn convert_insert(insert: &ast::Insert) -> Result<Insert, ParseError> {
+    let ast::TableObject::TableName(table) = &insert.table else {
+        return Err(unsupported(insert));
+    };
+    if insert.or.is_some()
+        || insert.ignore
+        || insert.overwrite
+        || insert.replace_into
+        || insert.partitioned.is_some()
+        || insert.output.is_some()
+        || insert.priority.is_some()
+        || insert.insert_alias.is_some()
+        || insert.settings.is_some()
+        || insert.format_clause.is_some()
+        || insert.multi_table_insert_type.is_some()
+        || !insert.optimizer_hints.is_empty()
+        || !insert.assignments.is_empty()
+        || !insert.after_columns.is_empty()
+    {
+        return Err(unsupported(insert));
+    }
+
+    let mut table = TableRef::Table(convert_table_name(table)?, None);
+    if let Some(alias) = &insert.table_alias {
+        table = table.alias(convert_ident(&alias.alias));
+    }
+    let columns = insert
+        .columns
+        .iter()
+        .map(convert_single_name)
+        .collect::<Result<Vec<_>, _>>()?;
+    let num_columns = columns.len();
+    let mut result = Insert::new().into_table(table).columns(columns);
+
+    match &insert.source {
+        None => result = result.or_default_values(1),
+        Some(source) => match &*source.body {
+            ast::SetExpr::Values(values) if is_plain_query(source) => {
+                for row in &values.rows {
+                    if row.content.len() != num_columns {
+                        return Err(unsupported(source));
+                    }
+                    result = result.values(row.content.iter().map(convert_expr));
+                }
+            }
+            _ => {
+                let select = convert_query(source)?;
+                if select.columns_len() != num_columns {
+                    return Err(unsupported(source));
+                }
+                result = result.select_from(select);
+            }
+        },
+    }
+
+    match &insert.on {
+        None => {}
+        Some(ast::OnInsert::OnConflict(on_conflict)) => {
+            result = result.on_conflict(convert_on_conflict(on_conflict)?);
+        }
+        Some(on) => return Err(unsupported(on)),
+    }
+
+    if let Some(returning) = &insert.returning {
+        result = result.returning(convert_returning(returning)?);
+    }
+
+    Ok(result)
+}
+
+fn convert_on_conflict(on_conflict: &ast::OnConflict) -> Result<OnConflict, ParseError> {
+    let mut result = match &on_conflict.conflict_target {
+        None => OnConflict::new(),
+        Some(ast::ConflictTarget::Columns(columns)) => {
+            OnConflict::columns(columns.iter().map(convert_ident))
+        }
+        Some(ast::ConflictTarget::OnConstraint(name)) => {
+            OnConflict::constraint(convert_single_name(name)?.into_inner())
+        }
+    };
+
+    match &on_conflict.action {
+        ast::OnConflictAction::DoNothing => result = result.do_nothing(),
+        ast::OnConflictAction::DoUpdate(update) => {
+            for (column, value) in convert_assignments(&update.assignments)? {
+                result = if is_excluded_column(&value, &column) {
+                    result.update_column(column)
+                } else {
+                    result.value(column, value)
+                };
+            }
+            if let Some(selection) = &update.selection {
+                result = result.action_and_where(convert_expr(selection));
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Whether the expression is `excluded.<column>`, i.e., the value proposed for insertion.
+fn is_excluded_column(expr: &Expr, column: &Iden) -> bool {
+    matches!(
+        expr,
+        Expr::Column(ColumnRef::Column(ColumnName(Some(TableName(None, table)), col)))
+            if *table == Iden::new_static("excluded") && col == column
+    )
+}
+
+pub(super) fn convert_update(update: &ast::Update) -> Result<Update, ParseError> {
+    if update.from.is_some()
+        || update.output.is_some()
+        || update.or.is_some()
+        || update.limit.is_some()
+        || !update.order_by.is_empty()
+        || !update.optimizer_hints.is_empty()
+    {
+        return Err(unsupported(update));
+    }
+
+    let mut result = Update::new()
+        .table(convert_target_table(&update.table)?)
+        .values(convert_assignments(&update.assignments)?);
+    if let Some(selection) = &update.selection {
+        result = result.and_where(convert_expr(selection));
+    }
+    if let Some(returning) = &update.returning {
+        result = result.returning(convert_returning(returning)?);
+    }
+    Ok(result)
+}
+
+pub(super) fn convert_delete(delete: &ast::Delete) -> Result<Delete, ParseError> {
+    let (ast::FromTable::WithFromKeyword(tables) | ast::FromTable::WithoutKeyword(tables)) =
+        &delete.from;
+    let [table] = tables.as_slice() else {
+        return Err(unsupported(delete));
+    };
+    if !delete.tables.is_empty()
+        || delete.using.is_some()
+        || delete.output.is_some()
+        || delete.limit.is_some()
+        || !delete.order_by.is_empty()
+        || !delete.optimizer_hints.is_empty()
+    {
+        return Err(unsupported(delete));
+    }
+
+    let mut result = Delete::new().from_table(convert_target_table(table)?);
+    if let Some(selection) = &delete.selection {
+        result = result.and_where(convert_expr(selection));
+    }
+    if let Some(returning) = &delete.returning {
+        result = result.returning(convert_returning(returning)?);
+    }
+    Ok(result)
+}
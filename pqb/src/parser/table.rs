@@ -0,0 +1,351 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use sqlparser::ast;
+
+use crate::index::CreateIndex;
+use crate::index::IndexElement;
+use crate::index::IndexMethod;
+use crate::parser::ParseError;
+use crate::parser::expr::convert_expr;
+use crate::parser::expr::convert_ident;
+use crate::parser::expr::convert_single_name;
+use crate::parser::expr::convert_table_name;
+use crate::parser::unsupported;
+use crate::table::ColumnDef;
+use crate::table::ColumnType;
+use crate::table::CreateTable;
+use crate::table::GeneratedColumn;
+use crate::table::GeneratedColumnKind;
+use crate::table::PrimaryKey;
+use crate::table::TableConstraint;
+use crate::types::TableName;
+use crate::types::TableRef;
+use crate::types::TypeName;
+
+pub(super) fn convert_create_table(create: &ast::CreateTable) -> Result<CreateTable, ParseError> {
+    if create.or_replace
+        || create.unlogged
+        || create.external
+        || create.global.is_some()
+        || create.query.is_some()
+        || create.like.is_some()
+        || create.inherits.is_some()
+        || create.partition_of.is_some()
+        || create.for_values.is_some()
+        || create.partition_by.is_some()
+        || create.on_commit.is_some()
+        || create.with_data.is_some()
+        || create.table_options != ast::CreateTableOptions::None
+    {
+        return Err(unsupported(create));
+    }
+
+    let mut result = CreateTable::new().table(convert_table_name(&create.name)?);
+    if create.temporary {
+        result = result.temporary();
+    }
+    if create.if_not_exists {
+        result = result.if_not_exists();
+    }
+
+    for column in &create.columns {
+        result = result.column(convert_column_def(column)?);
+    }
+
+    for constraint in &create.constraints {
+        match constraint {
+            ast::TableConstraint::PrimaryKey(pk)
+                if pk.index_name.is_none()
+                    && pk.index_type.is_none()
+                    && pk.index_options.is_empty()
+                    && pk.characteristics.is_none() =>
+            {
+                let columns = pk
+                    .columns
+                    .iter()
+                    .map(convert_index_column_name)
+                    .collect::<Result<Vec<_>, _>>()?;
+                let primary_key = PrimaryKey::columns(columns)
+                    .include_columns(pk.include.iter().map(convert_ident));
+                let mut constraint = TableConstraint::primary_key(primary_key);
+                if let Some(name) = &pk.name {
+                    constraint = constraint.name(convert_ident(name));
+                }
+                result = result.constraint(constraint);
+            }
+            _ => return Err(unsupported(constraint)),
+        }
+    }
+
+    Ok(result)
+}
+
+fn convert_column_def(column: &ast::ColumnDef) -> Result<ColumnDef, ParseError> {
+    let mut result = ColumnDef::new(convert_ident(&column.name));
+    result.ty = Some(convert_data_type(&column.data_type)?);
+
+    for option in &column.options {
+        if option.name.is_some() {
+            return Err(unsupported(option));
+        }
+        match &option.option {
+            ast::ColumnOption::Null => result.spec.nullable = Some(true),
+            ast::ColumnOption::NotNull => result.spec.nullable = Some(false),
+            ast::ColumnOption::Default(expr) => result.spec.default = Some(convert_expr(expr)),
+            ast::ColumnOption::PrimaryKey(pk) if pk.characteristics.is_none() => {
+                result.spec.primary_key = true;
+            }
+            ast::ColumnOption::Unique(unique)
+                if unique.characteristics.is_none()
+                    && unique.nulls_distinct == ast::NullsDistinctOption::None =>
+            {
+                result.spec.unique = true;
+            }
+            ast::ColumnOption::Generated {
+                generated_as: ast::GeneratedAs::Always | ast::GeneratedAs::ExpStored,
+                sequence_options: None,
+                generation_expr: Some(expr),
+                generation_expr_mode,
+                ..
+            } => {
+                let kind = match generation_expr_mode {
+                    Some(ast::GeneratedExpressionMode::Virtual) => GeneratedColumnKind::Virtual,
+                    Some(ast::GeneratedExpressionMode::Stored) | None => {
+                        GeneratedColumnKind::Stored
+                    }
+                };
+                result.spec.generated = Some(GeneratedColumn {
+                    expr: convert_expr(expr),
+                    kind,
+                });
+            }
+            _ => return Err(unsupported(option)),
+        }
+    }
+
+    if result.spec.default.is_some() && result.spec.generated.is_some() {
+        return Err(unsupported(column));
+    }
+
+    Ok(result)
+}
+
+fn convert_data_type(data_type: &ast::DataType) -> Result<ColumnType, ParseError> {
+    let ty = match data_type {
+        ast::DataType::Char(len) | ast::DataType::Character(len) => {
+            ColumnType::Char(convert_char_length(data_type, len)?.unwrap_or(1))
+        }
+        ast::DataType::Varchar(len) | ast::DataType::CharacterVarying(len) => {
+            match convert_char_length(data_type, len)? {
+                Some(len) => ColumnType::Varchar(len),
+                None => return Err(unsupported(data_type)),
+            }
+        }
+        ast::DataType::Text => ColumnType::Text,
+        ast::DataType::Bytea => ColumnType::Bytea,
+        ast::DataType::SmallInt(None) | ast::DataType::Int2(None) => ColumnType::SmallInt,
+        ast::DataType::Int(None) | ast::DataType::Integer(None) | ast::DataType::Int4(None) => {
+            ColumnType::Int
+        }
+        ast::DataType::BigInt(None) | ast::DataType::Int8(None) => ColumnType::BigInt,
+        ast::DataType::Real | ast::DataType::Float4 => ColumnType::Float,
+        ast::DataType::DoublePrecision | ast::DataType::Float8 => ColumnType::Double,
+        ast::DataType::Numeric(info) | ast::DataType::Decimal(info) => match info {
+            ast::ExactNumberInfo::None => ColumnType::Numeric(None),
+            ast::ExactNumberInfo::Precision(p) => {
+                ColumnType::Numeric(Some((convert_precision(data_type, *p)?, 0)))
+            }
+            ast::ExactNumberInfo::PrecisionAndScale(p, s) => ColumnType::Numeric(Some((
+                convert_precision(data_type, *p)?,
+                i32::try_from(*s).map_err(|_| unsupported(data_type))?,
+            ))),
+        },
+        ast::DataType::Timestamp(None, tz) => match tz {
+            ast::TimezoneInfo::None => ColumnType::Timestamp,
+            ast::TimezoneInfo::WithoutTimeZone => ColumnType::DateTime,
+            ast::TimezoneInfo::WithTimeZone | ast::TimezoneInfo::Tz => {
+                ColumnType::TimestampWithTimeZone
+            }
+        },
+        ast::DataType::Time(None, ast::TimezoneInfo::None) => ColumnType::Time,
+        ast::DataType::Date => ColumnType::Date,
+        ast::DataType::Bool | ast::DataType::Boolean => ColumnType::Boolean,
+        ast::DataType::JSON => ColumnType::Json,
+        ast::DataType::JSONB => ColumnType::JsonBinary,
+        ast::DataType::Uuid => ColumnType::Uuid,
+        ast::DataType::Array(ast::ArrayElemTypeDef::SquareBracket(ty, None)) => {
+            ColumnType::Array(Arc::new(convert_data_type(ty)?))
+        }
+        ast::DataType::Custom(name, modifiers) if modifiers.is_empty() => {
+            let TableName(schema, ty) = convert_table_name(name)?;
+            let builtin = match (&schema, ty.clone().into_inner().as_ref()) {
+                (None, "smallserial" | "serial2") => Some(ColumnType::SmallSerial),
+                (None, "serial" | "serial4") => Some(ColumnType::Serial),
+                (None, "bigserial" | "serial8") => Some(ColumnType::BigSerial),
+                (None, "int4range") => Some(ColumnType::Int4Range),
+                (None, "int8range") => Some(ColumnType::Int8Range),
+                (None, "numrange") => Some(ColumnType::NumRange),
+                (None, "tsrange") => Some(ColumnType::TsRange),
+                (None, "tstzrange") => Some(ColumnType::TsTzRange),
+                (None, "daterange") => Some(ColumnType::DateRange),
+                _ => None,
+            };
+            builtin.unwrap_or(ColumnType::Custom(TypeName(schema, ty)))
+        }
+        _ => return Err(unsupported(data_type)),
+    };
+    Ok(ty)
+}
+
+fn convert_char_length(
+    data_type: &ast::DataType,
+    len: &Option<ast::CharacterLength>,
+) -> Result<Option<u32>, ParseError> {
+    match len {
+        None => Ok(None),
+        Some(ast::CharacterLength::IntegerLength { length, unit: None }) if *length > 0 => {
+            u32::try_from(*length)
+                .map(Some)
+                .map_err(|_| unsupported(data_type))
+        }
+        Some(_) => Err(unsupported(data_type)),
+    }
+}
+
+fn convert_precision(data_type: &ast::DataType, precision: u64) -> Result<i32, ParseError> {
+    match i32::try_from(precision) {
+        Ok(p) if (1..=1000).contains(&p) => Ok(p),
+        _ => Err(unsupported(data_type)),
+    }
+}
+
+fn convert_index_column_name(column: &ast::IndexColumn) -> Result<crate::types::Iden, ParseError> {
+    match column {
+        ast::IndexColumn {
+            column:
+                ast::OrderByExpr {
+                    expr: ast::Expr::Identifier(ident),
+                    options:
+                        ast::OrderByOptions {
+                            sort: None,
+                            nulls_first: None,
+                        },
+                    with_fill: None,
+                },
+            operator_class: None,
+        } => Ok(convert_ident(ident)),
+        _ => Err(unsupported(&column.column)),
+    }
+}
+
+pub(super) fn convert_create_index(create: &ast::CreateIndex) -> Result<CreateIndex, ParseError> {
+    if create.r#async
+        || create.nulls_distinct == Some(true)
+        || (create.nulls_distinct == Some(false) && !create.unique)
+        || !create.index_options.is_empty()
+        || !create.alter_options.is_empty()
+    {
+        return Err(unsupported(create));
+    }
+
+    let mut result = CreateIndex::new().table(TableRef::Table(
+        convert_table_name(&create.table_name)?,
+        None,
+    ));
+    if let Some(name) = &create.name {
+        result = result.name(convert_single_name(name)?);
+    }
+    if create.unique {
+        result = result.unique();
+    }
+    if create.concurrently {
+        result = result.concurrently();
+    }
+    if create.if_not_exists {
+        result = result.if_not_exists();
+    }
+    if create.nulls_distinct == Some(false) {
+        result = result.nulls_not_distinct();
+    }
+    if let Some(using) = &create.using {
+        result = result.using(match using {
+            ast::IndexType::BTree => IndexMethod::Btree,
+            ast::IndexType::Hash => IndexMethod::Hash,
+            ast::IndexType::GiST => IndexMethod::Gist,
+            ast::IndexType::BRIN => IndexMethod::Brin,
+            ast::IndexType::Custom(name) => IndexMethod::custom(convert_ident(name).into_inner()),
+            other => IndexMethod::custom(other.to_string().to_lowercase()),
+        });
+    }
+
+    for column in &create.columns {
+        let ast::IndexColumn {
+            column:
+                ast::OrderByExpr {
+                    expr,
+                    options: ast::OrderByOptions { sort, nulls_first },
+                    with_fill: None,
+                },
+            operator_class,
+        } = column
+        else {
+            return Err(unsupported(&column.column));
+        };
+        let mut element = match expr {
+            ast::Expr::Identifier(ident) => IndexElement::column(convert_ident(ident)),
+            expr => IndexElement::expr(convert_expr(expr)),
+        };
+        if let Some(opclass) = operator_class {
+            element = element.opclass(convert_single_name(opclass)?);
+        }
+        match sort {
+            Some(ast::OrderBySort::Asc) => element = element.asc(),
+            Some(ast::OrderBySort::Desc) => element = element.desc(),
+            Some(ast::OrderBySort::Using(_)) => return Err(unsupported(&column.column)),
+            None => {}
+        }
+        match nulls_first {
+            Some(true) => element = element.nulls_first(),
+            Some(false) => element = element.nulls_last(),
+            None => {}
+        }
+        result = result.element(element);
+    }
+
+    result = result.include_columns(create.include.iter().map(convert_ident));
+
+    for option in &create.with {
+        let ast::Expr::BinaryOp {
+            left,
+            op: ast::BinaryOperator::Eq,
+            right,
+        } = option
+        else {
+            return Err(unsupported(option));
+        };
+        let ast::Expr::Identifier(name) = &**left else {
+            return Err(unsupported(option));
+        };
+        result = result.with_option(convert_ident(name), convert_expr(right));
+    }
+
+    if let Some(predicate) = &create.predicate {
+        result = result.index_where(convert_expr(predicate));
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,141 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::expr::Expr;
+use crate::policy::write_policy_clauses;
+use crate::privilege::Grantee;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::TableName;
+use crate::types::write_iden;
+use crate::types::write_table_name;
+use crate::writer::SqlWriter;
+
+/// ALTER POLICY statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlterPolicy {
+    name: Option<Iden>,
+    table: Option<TableName>,
+    rename_to: Option<Iden>,
+    roles: Vec<Grantee>,
+    using: Option<Expr>,
+    with_check: Option<Expr>,
+}
+
+impl AlterPolicy {
+    /// Create a new ALTER POLICY statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_alter_policy(&mut w, self);
+        w
+    }
+
+    /// Convert the ALTER POLICY statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_alter_policy(&mut sql, self);
+        sql
+    }
+
+    /// Set the policy name.
+    pub fn name<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Set the table the policy applies to.
+    pub fn table<T>(mut self, table: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.table = Some(table.into());
+        self
+    }
+
+    /// Rename the policy.
+    ///
+    /// ## Panics
+    /// Rendering the statement will panic if the roles or conditions are changed as well, since
+    /// PostgreSQL cannot rename a policy and change its definition in one statement.
+    pub fn rename_to<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.rename_to = Some(name.into_iden());
+        self
+    }
+
+    /// Add a role the policy applies to, replacing the current roles.
+    pub fn to<N>(mut self, role: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.roles.push(Grantee::Role(role.into_iden()));
+        self
+    }
+
+    /// Apply the policy to all roles, i.e., PUBLIC.
+    pub fn to_public(mut self) -> Self {
+        self.roles.push(Grantee::Public);
+        self
+    }
+
+    /// Replace the USING condition.
+    pub fn using<E>(mut self, expr: E) -> Self
+    where
+        E: Into<Expr>,
+    {
+        self.using = Some(expr.into());
+        self
+    }
+
+    /// Replace the WITH CHECK condition.
+    pub fn with_check<E>(mut self, expr: E) -> Self
+    where
+        E: Into<Expr>,
+    {
+        self.with_check = Some(expr.into());
+        self
+    }
+}
+
+fn write_alter_policy<W: SqlWriter>(w: &mut W, alter: &AlterPolicy) {
+    w.push_str("ALTER POLICY ");
+    if let Some(name) = &alter.name {
+        write_iden(w, name);
+    }
+    w.push_str(" ON ");
+    if let Some(table) = &alter.table {
+        write_table_name(w, table);
+    }
+    if let Some(new_name) = &alter.rename_to {
+        if !alter.roles.is_empty() || alter.using.is_some() || alter.with_check.is_some() {
+            panic!("RENAME TO cannot be combined with other changes of a policy.");
+        }
+        w.push_str(" RENAME TO ");
+        write_iden(w, new_name);
+    } else {
+        write_policy_clauses(w, &alter.roles, &alter.using, &alter.with_check);
+    }
+}
@@ -0,0 +1,191 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::expr::Expr;
+use crate::expr::write_expr;
+use crate::privilege::Grantee;
+use crate::privilege::write_grantees;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::TableName;
+use crate::types::write_iden;
+use crate::types::write_table_name;
+use crate::writer::SqlWriter;
+
+/// CREATE POLICY statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreatePolicy {
+    name: Option<Iden>,
+    table: Option<TableName>,
+    permissive: Option<bool>,
+    command: Option<PolicyCommand>,
+    roles: Vec<Grantee>,
+    using: Option<Expr>,
+    with_check: Option<Expr>,
+}
+
+/// Commands a policy applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+#[expect(missing_docs)]
+pub enum PolicyCommand {
+    All,
+    Select,
+    Insert,
+    Update,
+    Delete,
+}
+
+impl CreatePolicy {
+    /// Create a new CREATE POLICY statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_create_policy(&mut w, self);
+        w
+    }
+
+    /// Convert the CREATE POLICY statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_create_policy(&mut sql, self);
+        sql
+    }
+
+    /// Set the policy name.
+    pub fn name<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Set the table the policy applies to.
+    pub fn table<T>(mut self, table: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.table = Some(table.into());
+        self
+    }
+
+    /// Combine the policy with other permissive policies using OR.
+    pub fn permissive(mut self) -> Self {
+        self.permissive = Some(true);
+        self
+    }
+
+    /// Combine the policy with other policies using AND.
+    pub fn restrictive(mut self) -> Self {
+        self.permissive = Some(false);
+        self
+    }
+
+    /// Set the command the policy applies to.
+    pub fn command(mut self, command: PolicyCommand) -> Self {
+        self.command = Some(command);
+        self
+    }
+
+    /// Add a role the policy applies to.
+    pub fn to<N>(mut self, role: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.roles.push(Grantee::Role(role.into_iden()));
+        self
+    }
+
+    /// Apply the policy to all roles, i.e., PUBLIC.
+    pub fn to_public(mut self) -> Self {
+        self.roles.push(Grantee::Public);
+        self
+    }
+
+    /// Set the condition existing rows must satisfy to be visible or modifiable.
+    pub fn using<E>(mut self, expr: E) -> Self
+    where
+        E: Into<Expr>,
+    {
+        self.using = Some(expr.into());
+        self
+    }
+
+    /// Set the condition new or updated rows must satisfy.
+    pub fn with_check<E>(mut self, expr: E) -> Self
+    where
+        E: Into<Expr>,
+    {
+        self.with_check = Some(expr.into());
+        self
+    }
+}
+
+fn write_create_policy<W: SqlWriter>(w: &mut W, create: &CreatePolicy) {
+    w.push_str("CREATE POLICY ");
+    if let Some(name) = &create.name {
+        write_iden(w, name);
+    }
+    w.push_str(" ON ");
+    if let Some(table) = &create.table {
+        write_table_name(w, table);
+    }
+    if let Some(permissive) = create.permissive {
+        w.push_str(if permissive {
+            " AS PERMISSIVE"
+        } else {
+            " AS RESTRICTIVE"
+        });
+    }
+    if let Some(command) = create.command {
+        w.push_str(match command {
+            PolicyCommand::All => " FOR ALL",
+            PolicyCommand::Select => " FOR SELECT",
+            PolicyCommand::Insert => " FOR INSERT",
+            PolicyCommand::Update => " FOR UPDATE",
+            PolicyCommand::Delete => " FOR DELETE",
+        });
+    }
+    write_policy_clauses(w, &create.roles, &create.using, &create.with_check);
+}
+
+pub(crate) fn write_policy_clauses<W: SqlWriter>(
+    w: &mut W,
+    roles: &[Grantee],
+    using: &Option<Expr>,
+    with_check: &Option<Expr>,
+) {
+    if !roles.is_empty() {
+        w.push_str(" TO ");
+        write_grantees(w, roles);
+    }
+    if let Some(using) = using {
+        w.push_str(" USING (");
+        write_expr(w, using);
+        w.push_char(')');
+    }
+    if let Some(with_check) = with_check {
+        w.push_str(" WITH CHECK (");
+        write_expr(w, with_check);
+        w.push_char(')');
+    }
+}
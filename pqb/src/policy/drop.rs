@@ -0,0 +1,110 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::types::DropBehavior;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::TableName;
+use crate::types::write_iden;
+use crate::types::write_table_name;
+use crate::writer::SqlWriter;
+
+/// DROP POLICY statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DropPolicy {
+    name: Option<Iden>,
+    table: Option<TableName>,
+    if_exists: bool,
+    behavior: Option<DropBehavior>,
+}
+
+impl DropPolicy {
+    /// Create a new DROP POLICY statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_drop_policy(&mut w, self);
+        w
+    }
+
+    /// Convert the DROP POLICY statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_drop_policy(&mut sql, self);
+        sql
+    }
+
+    /// Set the policy name.
+    pub fn name<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Set the table the policy is defined on.
+    pub fn table<T>(mut self, table: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.table = Some(table.into());
+        self
+    }
+
+    /// Drop the policy if it exists.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Add CASCADE to drop dependent objects.
+    pub fn cascade(mut self) -> Self {
+        self.behavior = Some(DropBehavior::Cascade);
+        self
+    }
+
+    /// Add RESTRICT to drop (explicitly).
+    pub fn restrict(mut self) -> Self {
+        self.behavior = Some(DropBehavior::Restrict);
+        self
+    }
+}
+
+fn write_drop_policy<W: SqlWriter>(w: &mut W, drop_policy: &DropPolicy) {
+    w.push_str("DROP POLICY ");
+    if drop_policy.if_exists {
+        w.push_str("IF EXISTS ");
+    }
+    if let Some(name) = &drop_policy.name {
+        write_iden(w, name);
+    }
+    w.push_str(" ON ");
+    if let Some(table) = &drop_policy.table {
+        write_table_name(w, table);
+    }
+    if let Some(behavior) = drop_policy.behavior {
+        w.push_char(' ');
+        match behavior {
+            DropBehavior::Cascade => w.push_str("CASCADE"),
+            DropBehavior::Restrict => w.push_str("RESTRICT"),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::write_iden;
+use crate::writer::SqlWriter;
+
+/// DEALLOCATE statement builder.
+///
+/// Without a name, all prepared statements of the session are deallocated.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Deallocate {
+    name: Option<Iden>,
+}
+
+impl Deallocate {
+    /// Create a new DEALLOCATE statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_deallocate(&mut w, self);
+        w
+    }
+
+    /// Convert the DEALLOCATE statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_deallocate(&mut sql, self);
+        sql
+    }
+
+    /// Set the name of the prepared statement to deallocate.
+    pub fn name<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+}
+
+fn write_deallocate<W: SqlWriter>(w: &mut W, deallocate: &Deallocate) {
+    w.push_str("DEALLOCATE ");
+    match &deallocate.name {
+        Some(name) => write_iden(w, name),
+        None => w.push_str("ALL"),
+    }
+}
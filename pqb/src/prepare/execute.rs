@@ -0,0 +1,97 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::write_iden;
+use crate::value::Value;
+use crate::value::write_value;
+use crate::writer::SqlWriter;
+
+/// EXECUTE statement builder.
+///
+/// Arguments are always written as literals, since EXECUTE does not accept bind parameters.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Execute {
+    name: Option<Iden>,
+    args: Vec<Value>,
+}
+
+impl Execute {
+    /// Create a new EXECUTE statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_execute(&mut w, self);
+        w
+    }
+
+    /// Convert the EXECUTE statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_execute(&mut sql, self);
+        sql
+    }
+
+    /// Set the name of the prepared statement to execute.
+    pub fn name<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Add an argument.
+    pub fn arg<V>(mut self, arg: V) -> Self
+    where
+        V: Into<Value>,
+    {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// Add multiple arguments.
+    pub fn args<V, I>(mut self, args: I) -> Self
+    where
+        V: Into<Value>,
+        I: IntoIterator<Item = V>,
+    {
+        self.args.extend(args.into_iter().map(|a| a.into()));
+        self
+    }
+}
+
+fn write_execute<W: SqlWriter>(w: &mut W, execute: &Execute) {
+    w.push_str("EXECUTE ");
+    if let Some(name) = &execute.name {
+        write_iden(w, name);
+    }
+    if !execute.args.is_empty() {
+        w.push_str(" (");
+        for (i, arg) in execute.args.iter().enumerate() {
+            if i > 0 {
+                w.push_str(", ");
+            }
+            write_value(w, arg);
+        }
+        w.push_char(')');
+    }
+}
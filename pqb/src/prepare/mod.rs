@@ -0,0 +1,57 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Prepared statement commands.
+//!
+//! The body of a [`Prepare`] refers to its parameters with [`Expr::param`], and EXECUTE passes
+//! the arguments for them:
+//!
+//! ```
+//! use pqb::expr::Expr;
+//! use pqb::prepare::Execute;
+//! use pqb::prepare::Prepare;
+//! use pqb::query::Select;
+//! use pqb::table::ColumnType;
+//!
+//! let prepare = Prepare::new()
+//!     .name("user_by_id")
+//!     .param_type(ColumnType::BigInt)
+//!     .statement(
+//!         Select::new()
+//!             .column("name")
+//!             .from("users")
+//!             .and_where(Expr::column("id").eq(Expr::param(1))),
+//!     );
+//! assert_eq!(
+//!     prepare.to_sql(),
+//!     r#"PREPARE "user_by_id" (bigint) AS SELECT "name" FROM "users" WHERE "id" = $1"#
+//! );
+//!
+//! let execute = Execute::new().name("user_by_id").arg(42);
+//! assert_eq!(execute.to_sql(), r#"EXECUTE "user_by_id" (42)"#);
+//! ```
+//!
+//! PREPARE and EXECUTE do not accept bind parameters themselves, so they must be sent with the
+//! SQL string alone: values in the body of PREPARE and the arguments of EXECUTE are always written
+//! as literals.
+//!
+//! [`Expr::param`]: crate::expr::Expr::param
+
+mod deallocate;
+mod execute;
+mod statement;
+
+pub use self::deallocate::*;
+pub use self::execute::*;
+pub use self::statement::*;
@@ -0,0 +1,157 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::query::Delete;
+use crate::query::Insert;
+use crate::query::Select;
+use crate::query::Update;
+use crate::query::write_delete;
+use crate::query::write_insert;
+use crate::query::write_select;
+use crate::query::write_update;
+use crate::table::ColumnType;
+use crate::table::write_column_type;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::write_iden;
+use crate::writer::SqlWriter;
+
+/// PREPARE statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Prepare {
+    name: Option<Iden>,
+    param_types: Vec<ColumnType>,
+    statement: Option<PreparableStatement>,
+}
+
+/// Statements that can be prepared.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+#[expect(missing_docs)]
+pub enum PreparableStatement {
+    Select(Select),
+    Insert(Insert),
+    Update(Update),
+    Delete(Delete),
+}
+
+impl From<Select> for PreparableStatement {
+    fn from(s: Select) -> Self {
+        PreparableStatement::Select(s)
+    }
+}
+
+impl From<Insert> for PreparableStatement {
+    fn from(i: Insert) -> Self {
+        PreparableStatement::Insert(i)
+    }
+}
+
+impl From<Update> for PreparableStatement {
+    fn from(u: Update) -> Self {
+        PreparableStatement::Update(u)
+    }
+}
+
+impl From<Delete> for PreparableStatement {
+    fn from(d: Delete) -> Self {
+        PreparableStatement::Delete(d)
+    }
+}
+
+impl Prepare {
+    /// Create a new PREPARE statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string and return no values.
+    ///
+    /// PREPARE does not accept bind parameters, so values in the statement body are written as
+    /// literals, the same as [`Self::to_sql`]. Use [`Expr::param`] for the parameters of the
+    /// prepared statement.
+    ///
+    /// [`Expr::param`]: crate::expr::Expr::param
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        w.push_str(&self.to_sql());
+        w
+    }
+
+    /// Convert the PREPARE statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_prepare(&mut sql, self);
+        sql
+    }
+
+    /// Set the prepared statement name.
+    pub fn name<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Add the type of the next parameter.
+    pub fn param_type(mut self, ty: ColumnType) -> Self {
+        self.param_types.push(ty);
+        self
+    }
+
+    /// Add the types of the next parameters.
+    pub fn param_types<I>(mut self, types: I) -> Self
+    where
+        I: IntoIterator<Item = ColumnType>,
+    {
+        self.param_types.extend(types);
+        self
+    }
+
+    /// Set the statement to prepare.
+    pub fn statement(mut self, statement: impl Into<PreparableStatement>) -> Self {
+        self.statement = Some(statement.into());
+        self
+    }
+}
+
+fn write_prepare<W: SqlWriter>(w: &mut W, prepare: &Prepare) {
+    w.push_str("PREPARE ");
+    if let Some(name) = &prepare.name {
+        write_iden(w, name);
+    }
+    if !prepare.param_types.is_empty() {
+        w.push_str(" (");
+        for (i, ty) in prepare.param_types.iter().enumerate() {
+            if i > 0 {
+                w.push_str(", ");
+            }
+            write_column_type(w, ty);
+        }
+        w.push_char(')');
+    }
+    if let Some(statement) = &prepare.statement {
+        w.push_str(" AS ");
+        match statement {
+            PreparableStatement::Select(s) => write_select(w, s),
+            PreparableStatement::Insert(i) => write_insert(w, i),
+            PreparableStatement::Update(u) => write_update(w, u),
+            PreparableStatement::Delete(d) => write_delete(w, d),
+        }
+    }
+}
@@ -0,0 +1,227 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::privilege::Grantee;
+use crate::privilege::Privilege;
+use crate::privilege::write_grantees;
+use crate::privilege::write_privilege;
+use crate::types::DropBehavior;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::SchemaName;
+use crate::types::write_iden;
+use crate::types::write_schema_name;
+use crate::writer::SqlWriter;
+
+/// ALTER DEFAULT PRIVILEGES statement builder.
+///
+/// Grants privileges by default; call [`AlterDefaultPrivileges::revoke`] to revoke them instead.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlterDefaultPrivileges {
+    for_roles: Vec<Iden>,
+    schemas: Vec<SchemaName>,
+    revoke: bool,
+    grant_option: bool,
+    privileges: Vec<Privilege>,
+    object: Option<DefaultPrivilegeObject>,
+    grantees: Vec<Grantee>,
+    behavior: Option<DropBehavior>,
+}
+
+/// Object types whose default privileges can be altered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+#[expect(missing_docs)]
+pub enum DefaultPrivilegeObject {
+    Tables,
+    Sequences,
+    Functions,
+    Routines,
+    Types,
+    Schemas,
+}
+
+impl AlterDefaultPrivileges {
+    /// Create a new ALTER DEFAULT PRIVILEGES statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_alter_default_privileges(&mut w, self);
+        w
+    }
+
+    /// Convert the ALTER DEFAULT PRIVILEGES statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_alter_default_privileges(&mut sql, self);
+        sql
+    }
+
+    /// Only affect objects created by the given role.
+    pub fn for_role<N>(mut self, role: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.for_roles.push(role.into_iden());
+        self
+    }
+
+    /// Only affect objects created in the given schema.
+    pub fn in_schema<S>(mut self, schema: S) -> Self
+    where
+        S: Into<SchemaName>,
+    {
+        self.schemas.push(schema.into());
+        self
+    }
+
+    /// Grant the privileges (the default).
+    pub fn grant(mut self) -> Self {
+        self.revoke = false;
+        self
+    }
+
+    /// Revoke the privileges.
+    pub fn revoke(mut self) -> Self {
+        self.revoke = true;
+        self
+    }
+
+    /// Grant WITH GRANT OPTION, or revoke only the GRANT OPTION FOR the privileges.
+    pub fn grant_option(mut self) -> Self {
+        self.grant_option = true;
+        self
+    }
+
+    /// Add a privilege.
+    pub fn privilege(mut self, privilege: Privilege) -> Self {
+        self.privileges.push(privilege);
+        self
+    }
+
+    /// Add privileges.
+    pub fn privileges<I>(mut self, privileges: I) -> Self
+    where
+        I: IntoIterator<Item = Privilege>,
+    {
+        self.privileges.extend(privileges);
+        self
+    }
+
+    /// Use all privileges available for the object type.
+    pub fn all_privileges(self) -> Self {
+        self.privilege(Privilege::All)
+    }
+
+    /// Set the type of objects affected.
+    pub fn on(mut self, object: DefaultPrivilegeObject) -> Self {
+        self.object = Some(object);
+        self
+    }
+
+    /// Add a role the privileges are granted to or revoked from.
+    pub fn grantee<N>(mut self, role: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.grantees.push(Grantee::Role(role.into_iden()));
+        self
+    }
+
+    /// Grant the privileges to, or revoke them from, all roles, i.e., PUBLIC.
+    pub fn grantee_public(mut self) -> Self {
+        self.grantees.push(Grantee::Public);
+        self
+    }
+
+    /// Add CASCADE when revoking.
+    pub fn cascade(mut self) -> Self {
+        self.behavior = Some(DropBehavior::Cascade);
+        self
+    }
+
+    /// Add RESTRICT when revoking (explicitly).
+    pub fn restrict(mut self) -> Self {
+        self.behavior = Some(DropBehavior::Restrict);
+        self
+    }
+}
+
+fn write_alter_default_privileges<W: SqlWriter>(w: &mut W, alter: &AlterDefaultPrivileges) {
+    w.push_str("ALTER DEFAULT PRIVILEGES");
+    if !alter.for_roles.is_empty() {
+        w.push_str(" FOR ROLE ");
+        for (i, role) in alter.for_roles.iter().enumerate() {
+            if i > 0 {
+                w.push_str(", ");
+            }
+            write_iden(w, role);
+        }
+    }
+    if !alter.schemas.is_empty() {
+        w.push_str(" IN SCHEMA ");
+        for (i, schema) in alter.schemas.iter().enumerate() {
+            if i > 0 {
+                w.push_str(", ");
+            }
+            write_schema_name(w, schema);
+        }
+    }
+
+    if alter.revoke {
+        w.push_str(" REVOKE ");
+        if alter.grant_option {
+            w.push_str("GRANT OPTION FOR ");
+        }
+    } else {
+        w.push_str(" GRANT ");
+    }
+    for (i, privilege) in alter.privileges.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        write_privilege(w, *privilege);
+    }
+    if let Some(object) = alter.object {
+        w.push_str(match object {
+            DefaultPrivilegeObject::Tables => " ON TABLES",
+            DefaultPrivilegeObject::Sequences => " ON SEQUENCES",
+            DefaultPrivilegeObject::Functions => " ON FUNCTIONS",
+            DefaultPrivilegeObject::Routines => " ON ROUTINES",
+            DefaultPrivilegeObject::Types => " ON TYPES",
+            DefaultPrivilegeObject::Schemas => " ON SCHEMAS",
+        });
+    }
+    w.push_str(if alter.revoke { " FROM " } else { " TO " });
+    write_grantees(w, &alter.grantees);
+
+    if alter.revoke {
+        if let Some(behavior) = alter.behavior {
+            w.push_char(' ');
+            match behavior {
+                DropBehavior::Cascade => w.push_str("CASCADE"),
+                DropBehavior::Restrict => w.push_str("RESTRICT"),
+            }
+        }
+    } else if alter.grant_option {
+        w.push_str(" WITH GRANT OPTION");
+    }
+}
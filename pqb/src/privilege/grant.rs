@@ -0,0 +1,150 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::privilege::Grantee;
+use crate::privilege::Privilege;
+use crate::privilege::PrivilegeObject;
+use crate::privilege::PrivilegeSpec;
+use crate::privilege::write_grantees;
+use crate::privilege::write_privilege_object;
+use crate::privilege::write_privilege_specs;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::write_iden;
+use crate::writer::SqlWriter;
+
+/// GRANT statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Grant {
+    privileges: Vec<PrivilegeSpec>,
+    object: Option<PrivilegeObject>,
+    grantees: Vec<Grantee>,
+    with_grant_option: bool,
+    granted_by: Option<Iden>,
+}
+
+impl Grant {
+    /// Create a new GRANT statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_grant(&mut w, self);
+        w
+    }
+
+    /// Convert the GRANT statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_grant(&mut sql, self);
+        sql
+    }
+
+    /// Add a privilege to grant.
+    pub fn privilege(mut self, privilege: Privilege) -> Self {
+        self.privileges.push(PrivilegeSpec {
+            privilege,
+            columns: vec![],
+        });
+        self
+    }
+
+    /// Add privileges to grant.
+    pub fn privileges<I>(mut self, privileges: I) -> Self
+    where
+        I: IntoIterator<Item = Privilege>,
+    {
+        for privilege in privileges {
+            self = self.privilege(privilege);
+        }
+        self
+    }
+
+    /// Add a privilege to grant on the given columns of a table.
+    pub fn column_privilege<T, I>(mut self, privilege: Privilege, columns: I) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.privileges.push(PrivilegeSpec {
+            privilege,
+            columns: columns.into_iter().map(|c| c.into_iden()).collect(),
+        });
+        self
+    }
+
+    /// Grant all privileges available for the object type.
+    pub fn all_privileges(self) -> Self {
+        self.privilege(Privilege::All)
+    }
+
+    /// Set the objects to grant privileges on.
+    pub fn on(mut self, object: PrivilegeObject) -> Self {
+        self.object = Some(object);
+        self
+    }
+
+    /// Add a role to grant privileges to.
+    pub fn to<N>(mut self, role: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.grantees.push(Grantee::Role(role.into_iden()));
+        self
+    }
+
+    /// Grant privileges to all roles, i.e., PUBLIC.
+    pub fn to_public(mut self) -> Self {
+        self.grantees.push(Grantee::Public);
+        self
+    }
+
+    /// Allow the recipients to grant the privileges to others.
+    pub fn with_grant_option(mut self) -> Self {
+        self.with_grant_option = true;
+        self
+    }
+
+    /// Set the role recorded as having granted the privileges.
+    pub fn granted_by<N>(mut self, role: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.granted_by = Some(role.into_iden());
+        self
+    }
+}
+
+fn write_grant<W: SqlWriter>(w: &mut W, grant: &Grant) {
+    w.push_str("GRANT ");
+    write_privilege_specs(w, &grant.privileges);
+    if let Some(object) = &grant.object {
+        w.push_str(" ON ");
+        write_privilege_object(w, object);
+    }
+    w.push_str(" TO ");
+    write_grantees(w, &grant.grantees);
+    if grant.with_grant_option {
+        w.push_str(" WITH GRANT OPTION");
+    }
+    if let Some(role) = &grant.granted_by {
+        w.push_str(" GRANTED BY ");
+        write_iden(w, role);
+    }
+}
@@ -0,0 +1,285 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::SchemaName;
+use crate::types::TableName;
+use crate::types::write_iden;
+use crate::types::write_schema_name;
+use crate::types::write_table_name;
+use crate::writer::SqlWriter;
+
+/// Privileges that can be granted on database objects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+#[expect(missing_docs)]
+pub enum Privilege {
+    Select,
+    Insert,
+    Update,
+    Delete,
+    Truncate,
+    References,
+    Trigger,
+    Usage,
+    Create,
+    Connect,
+    Temporary,
+    Execute,
+    All,
+}
+
+/// The objects a privilege is granted on or revoked from.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrivilegeObject {
+    kind: PrivilegeObjectKind,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum PrivilegeObjectKind {
+    Tables(Vec<TableName>),
+    AllTablesInSchema(SchemaName),
+    Sequences(Vec<TableName>),
+    AllSequencesInSchema(SchemaName),
+    Functions(Vec<TableName>),
+    AllFunctionsInSchema(SchemaName),
+    Schemas(Vec<SchemaName>),
+    Databases(Vec<Iden>),
+}
+
+impl PrivilegeObject {
+    /// A single table, view or materialized view.
+    pub fn table<T>(table: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        Self::tables([table])
+    }
+
+    /// Multiple tables, views or materialized views.
+    pub fn tables<T, I>(tables: I) -> Self
+    where
+        T: Into<TableName>,
+        I: IntoIterator<Item = T>,
+    {
+        let tables = tables.into_iter().map(Into::into).collect();
+        Self {
+            kind: PrivilegeObjectKind::Tables(tables),
+        }
+    }
+
+    /// All tables in the given schema.
+    pub fn all_tables_in_schema<S>(schema: S) -> Self
+    where
+        S: Into<SchemaName>,
+    {
+        Self {
+            kind: PrivilegeObjectKind::AllTablesInSchema(schema.into()),
+        }
+    }
+
+    /// A single sequence.
+    pub fn sequence<T>(sequence: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        Self {
+            kind: PrivilegeObjectKind::Sequences(vec![sequence.into()]),
+        }
+    }
+
+    /// All sequences in the given schema.
+    pub fn all_sequences_in_schema<S>(schema: S) -> Self
+    where
+        S: Into<SchemaName>,
+    {
+        Self {
+            kind: PrivilegeObjectKind::AllSequencesInSchema(schema.into()),
+        }
+    }
+
+    /// A single function, identified by name only.
+    pub fn function<T>(function: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        Self {
+            kind: PrivilegeObjectKind::Functions(vec![function.into()]),
+        }
+    }
+
+    /// All functions in the given schema.
+    pub fn all_functions_in_schema<S>(schema: S) -> Self
+    where
+        S: Into<SchemaName>,
+    {
+        Self {
+            kind: PrivilegeObjectKind::AllFunctionsInSchema(schema.into()),
+        }
+    }
+
+    /// A single schema.
+    pub fn schema<S>(schema: S) -> Self
+    where
+        S: Into<SchemaName>,
+    {
+        Self::schemas([schema])
+    }
+
+    /// Multiple schemas.
+    pub fn schemas<S, I>(schemas: I) -> Self
+    where
+        S: Into<SchemaName>,
+        I: IntoIterator<Item = S>,
+    {
+        let schemas = schemas.into_iter().map(Into::into).collect();
+        Self {
+            kind: PrivilegeObjectKind::Schemas(schemas),
+        }
+    }
+
+    /// A single database.
+    pub fn database<N>(database: N) -> Self
+    where
+        N: IntoIden,
+    {
+        Self {
+            kind: PrivilegeObjectKind::Databases(vec![database.into_iden()]),
+        }
+    }
+}
+
+/// A privilege, optionally restricted to some columns of a table.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct PrivilegeSpec {
+    pub(crate) privilege: Privilege,
+    pub(crate) columns: Vec<Iden>,
+}
+
+/// A role receiving or losing privileges.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum Grantee {
+    Role(Iden),
+    Public,
+}
+
+pub(crate) fn write_privilege<W: SqlWriter>(w: &mut W, privilege: Privilege) {
+    w.push_str(match privilege {
+        Privilege::Select => "SELECT",
+        Privilege::Insert => "INSERT",
+        Privilege::Update => "UPDATE",
+        Privilege::Delete => "DELETE",
+        Privilege::Truncate => "TRUNCATE",
+        Privilege::References => "REFERENCES",
+        Privilege::Trigger => "TRIGGER",
+        Privilege::Usage => "USAGE",
+        Privilege::Create => "CREATE",
+        Privilege::Connect => "CONNECT",
+        Privilege::Temporary => "TEMPORARY",
+        Privilege::Execute => "EXECUTE",
+        Privilege::All => "ALL PRIVILEGES",
+    });
+}
+
+pub(crate) fn write_privilege_specs<W: SqlWriter>(w: &mut W, specs: &[PrivilegeSpec]) {
+    for (i, spec) in specs.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        write_privilege(w, spec.privilege);
+        if !spec.columns.is_empty() {
+            w.push_str(" (");
+            write_iden_list(w, &spec.columns);
+            w.push_char(')');
+        }
+    }
+}
+
+pub(crate) fn write_privilege_object<W: SqlWriter>(w: &mut W, object: &PrivilegeObject) {
+    match &object.kind {
+        PrivilegeObjectKind::Tables(tables) => {
+            w.push_str("TABLE ");
+            write_table_name_list(w, tables);
+        }
+        PrivilegeObjectKind::AllTablesInSchema(schema) => {
+            w.push_str("ALL TABLES IN SCHEMA ");
+            write_schema_name(w, schema);
+        }
+        PrivilegeObjectKind::Sequences(sequences) => {
+            w.push_str("SEQUENCE ");
+            write_table_name_list(w, sequences);
+        }
+        PrivilegeObjectKind::AllSequencesInSchema(schema) => {
+            w.push_str("ALL SEQUENCES IN SCHEMA ");
+            write_schema_name(w, schema);
+        }
+        PrivilegeObjectKind::Functions(functions) => {
+            w.push_str("FUNCTION ");
+            write_table_name_list(w, functions);
+        }
+        PrivilegeObjectKind::AllFunctionsInSchema(schema) => {
+            w.push_str("ALL FUNCTIONS IN SCHEMA ");
+            write_schema_name(w, schema);
+        }
+        PrivilegeObjectKind::Schemas(schemas) => {
+            w.push_str("SCHEMA ");
+            for (i, schema) in schemas.iter().enumerate() {
+                if i > 0 {
+                    w.push_str(", ");
+                }
+                write_schema_name(w, schema);
+            }
+        }
+        PrivilegeObjectKind::Databases(databases) => {
+            w.push_str("DATABASE ");
+            write_iden_list(w, databases);
+        }
+    }
+}
+
+pub(crate) fn write_grantees<W: SqlWriter>(w: &mut W, grantees: &[Grantee]) {
+    for (i, grantee) in grantees.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        match grantee {
+            Grantee::Role(role) => write_iden(w, role),
+            Grantee::Public => w.push_str("PUBLIC"),
+        }
+    }
+}
+
+fn write_table_name_list<W: SqlWriter>(w: &mut W, names: &[TableName]) {
+    for (i, name) in names.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        write_table_name(w, name);
+    }
+}
+
+fn write_iden_list<W: SqlWriter>(w: &mut W, idens: &[Iden]) {
+    for (i, iden) in idens.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        write_iden(w, iden);
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::privilege::Grantee;
+use crate::privilege::Privilege;
+use crate::privilege::PrivilegeObject;
+use crate::privilege::PrivilegeSpec;
+use crate::privilege::write_grantees;
+use crate::privilege::write_privilege_object;
+use crate::privilege::write_privilege_specs;
+use crate::types::DropBehavior;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::write_iden;
+use crate::writer::SqlWriter;
+
+/// REVOKE statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Revoke {
+    grant_option_for: bool,
+    privileges: Vec<PrivilegeSpec>,
+    object: Option<PrivilegeObject>,
+    grantees: Vec<Grantee>,
+    granted_by: Option<Iden>,
+    behavior: Option<DropBehavior>,
+}
+
+impl Revoke {
+    /// Create a new REVOKE statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_revoke(&mut w, self);
+        w
+    }
+
+    /// Convert the REVOKE statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_revoke(&mut sql, self);
+        sql
+    }
+
+    /// Only revoke the grant option, keeping the privileges themselves.
+    pub fn grant_option_for(mut self) -> Self {
+        self.grant_option_for = true;
+        self
+    }
+
+    /// Add a privilege to revoke.
+    pub fn privilege(mut self, privilege: Privilege) -> Self {
+        self.privileges.push(PrivilegeSpec {
+            privilege,
+            columns: vec![],
+        });
+        self
+    }
+
+    /// Add privileges to revoke.
+    pub fn privileges<I>(mut self, privileges: I) -> Self
+    where
+        I: IntoIterator<Item = Privilege>,
+    {
+        for privilege in privileges {
+            self = self.privilege(privilege);
+        }
+        self
+    }
+
+    /// Add a privilege to revoke on the given columns of a table.
+    pub fn column_privilege<T, I>(mut self, privilege: Privilege, columns: I) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.privileges.push(PrivilegeSpec {
+            privilege,
+            columns: columns.into_iter().map(|c| c.into_iden()).collect(),
+        });
+        self
+    }
+
+    /// Revoke all privileges available for the object type.
+    pub fn all_privileges(self) -> Self {
+        self.privilege(Privilege::All)
+    }
+
+    /// Set the objects to revoke privileges on.
+    pub fn on(mut self, object: PrivilegeObject) -> Self {
+        self.object = Some(object);
+        self
+    }
+
+    /// Add a role to revoke privileges from.
+    pub fn from<N>(mut self, role: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.grantees.push(Grantee::Role(role.into_iden()));
+        self
+    }
+
+    /// Revoke privileges granted to all roles, i.e., PUBLIC.
+    pub fn from_public(mut self) -> Self {
+        self.grantees.push(Grantee::Public);
+        self
+    }
+
+    /// Only revoke privileges granted by the given role.
+    pub fn granted_by<N>(mut self, role: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.granted_by = Some(role.into_iden());
+        self
+    }
+
+    /// Add CASCADE to also revoke privileges granted onwards.
+    pub fn cascade(mut self) -> Self {
+        self.behavior = Some(DropBehavior::Cascade);
+        self
+    }
+
+    /// Add RESTRICT to fail if privileges were granted onwards (explicitly).
+    pub fn restrict(mut self) -> Self {
+        self.behavior = Some(DropBehavior::Restrict);
+        self
+    }
+}
+
+fn write_revoke<W: SqlWriter>(w: &mut W, revoke: &Revoke) {
+    w.push_str("REVOKE ");
+    if revoke.grant_option_for {
+        w.push_str("GRANT OPTION FOR ");
+    }
+    write_privilege_specs(w, &revoke.privileges);
+    if let Some(object) = &revoke.object {
+        w.push_str(" ON ");
+        write_privilege_object(w, object);
+    }
+    w.push_str(" FROM ");
+    write_grantees(w, &revoke.grantees);
+    if let Some(role) = &revoke.granted_by {
+        w.push_str(" GRANTED BY ");
+        write_iden(w, role);
+    }
+    if let Some(behavior) = revoke.behavior {
+        w.push_char(' ');
+        match behavior {
+            DropBehavior::Cascade => w.push_str("CASCADE"),
+            DropBehavior::Restrict => w.push_str("RESTRICT"),
+        }
+    }
+}
@@ -20,7 +20,8 @@ use crate::types::write_iden;
 use crate::writer::SqlWriter;
 
 /// ON CONFLICT clause for INSERT statements.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OnConflict {
     targets: OnConflictTarget,
     target_conditions: Vec<Expr>,
@@ -184,21 +185,24 @@ impl OnConflict {
 ///
 /// Targets can be a list of columns or expressions, even mixed, or just a
 /// single constraint name.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum OnConflictTarget {
     Exprs(Vec<Expr>),
     Constraint(String),
 }
 
 /// Represents ON CONFLICT (upsert) actions
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum OnConflictAction {
     DoNothing,
     Update(Vec<OnConflictUpdate>),
 }
 
 /// Represents strategies to update column in ON CONFLICT (upsert) actions
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum OnConflictUpdate {
     /// Update column value of existing row with inserting value
     Column(Iden),
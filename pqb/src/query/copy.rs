@@ -0,0 +1,523 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::DefaultHasher;
+use std::hash::Hasher;
+
+use crate::SqlWriterValues;
+use crate::expr::Expr;
+use crate::expr::write_expr;
+use crate::query::Select;
+use crate::query::write_select;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::TableName;
+use crate::types::write_iden;
+use crate::types::write_table_name;
+use crate::value::write_string_value;
+use crate::writer::SqlWriter;
+use crate::writer::SqlWriterHasher;
+use crate::writer::write_false;
+
+/// Copy data between a table and the client, i.e., COPY.
+///
+/// PostgreSQL does not accept bind parameters in COPY, so values are always written as literals.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CopyStatement {
+    table: Option<TableName>,
+    columns: Vec<Iden>,
+    query: Option<Box<Select>>,
+    target: Option<CopyTarget>,
+    format: Option<CopyFormat>,
+    freeze: Option<bool>,
+    delimiter: Option<char>,
+    null: Option<String>,
+    header: Option<CopyHeader>,
+    quote: Option<char>,
+    escape: Option<char>,
+    force_quote: Option<ForceQuote>,
+    force_not_null: Vec<Iden>,
+    force_null: Vec<Iden>,
+    on_error: Option<OnError>,
+    encoding: Option<String>,
+    conditions: Vec<Expr>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum CopyTarget {
+    FromStdin,
+    ToStdout,
+    FromFile(String),
+    ToFile(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum CopyFormat {
+    Text,
+    Csv,
+    Binary,
+}
+
+impl CopyFormat {
+    const fn as_str(&self) -> &'static str {
+        match self {
+            CopyFormat::Text => "text",
+            CopyFormat::Csv => "csv",
+            CopyFormat::Binary => "binary",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum CopyHeader {
+    Enabled(bool),
+    Match,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum ForceQuote {
+    All,
+    Columns(Vec<Iden>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum OnError {
+    Stop,
+    Ignore,
+}
+
+impl CopyStatement {
+    /// Create a new COPY statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string and return no values.
+    ///
+    /// COPY does not accept bind parameters, so values in the query and the WHERE clause are
+    /// written as literals, the same as [`Self::to_sql`].
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        w.push_str(&self.to_sql());
+        w
+    }
+
+    /// Convert the COPY statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_copy(&mut sql, self);
+        sql
+    }
+
+    /// Feed the shape of the statement into the given hasher.
+    ///
+    /// The shape is the SQL string with values written as placeholders, so statements that only
+    /// differ in their values hash the same.
+    pub fn shape_hash<H: Hasher>(&self, state: &mut H) {
+        let mut w = SqlWriterHasher::new(state);
+        write_copy(&mut w, self);
+    }
+
+    /// Return a fingerprint of the statement shape, see [`Self::shape_hash`].
+    ///
+    /// The fingerprint is only stable within the same build of the program.
+    pub fn fingerprint(&self) -> u64 {
+        let mut state = DefaultHasher::new();
+        self.shape_hash(&mut state);
+        state.finish()
+    }
+
+    /// Copy from or to the given table.
+    pub fn table<T>(mut self, table: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.table = Some(table.into());
+        self
+    }
+
+    /// Add a column to copy.
+    pub fn column<C>(mut self, column: C) -> Self
+    where
+        C: IntoIden,
+    {
+        self.columns.push(column.into_iden());
+        self
+    }
+
+    /// Add columns to copy.
+    pub fn columns<T, I>(mut self, columns: I) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.columns
+            .extend(columns.into_iter().map(|c| c.into_iden()));
+        self
+    }
+
+    /// Copy the results of a query, i.e., `COPY (SELECT ...) TO`.
+    ///
+    /// ## Panics
+    /// Rendering the statement will panic if it reads the data with `FROM`.
+    pub fn query(mut self, query: Select) -> Self {
+        self.query = Some(Box::new(query));
+        self
+    }
+
+    /// Read the data from the client, i.e., `FROM STDIN`.
+    pub fn from_stdin(mut self) -> Self {
+        self.target = Some(CopyTarget::FromStdin);
+        self
+    }
+
+    /// Send the data to the client, i.e., `TO STDOUT`.
+    pub fn to_stdout(mut self) -> Self {
+        self.target = Some(CopyTarget::ToStdout);
+        self
+    }
+
+    /// Read the data from a file on the server.
+    pub fn from_file<P>(mut self, path: P) -> Self
+    where
+        P: Into<String>,
+    {
+        self.target = Some(CopyTarget::FromFile(path.into()));
+        self
+    }
+
+    /// Write the data to a file on the server.
+    pub fn to_file<P>(mut self, path: P) -> Self
+    where
+        P: Into<String>,
+    {
+        self.target = Some(CopyTarget::ToFile(path.into()));
+        self
+    }
+
+    /// Set `FORMAT text`.
+    pub fn format_text(mut self) -> Self {
+        self.format = Some(CopyFormat::Text);
+        self
+    }
+
+    /// Set `FORMAT csv`.
+    pub fn format_csv(mut self) -> Self {
+        self.format = Some(CopyFormat::Csv);
+        self
+    }
+
+    /// Set `FORMAT binary`.
+    pub fn format_binary(mut self) -> Self {
+        self.format = Some(CopyFormat::Binary);
+        self
+    }
+
+    /// Set `FREEZE`.
+    pub fn freeze(mut self, freeze: bool) -> Self {
+        self.freeze = Some(freeze);
+        self
+    }
+
+    /// Set `DELIMITER`.
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = Some(delimiter);
+        self
+    }
+
+    /// Set `NULL`, the string that represents a null value.
+    pub fn null<S>(mut self, null: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.null = Some(null.into());
+        self
+    }
+
+    /// Set `HEADER`.
+    pub fn header(mut self, header: bool) -> Self {
+        self.header = Some(CopyHeader::Enabled(header));
+        self
+    }
+
+    /// Set `HEADER MATCH`, i.e., check the header against the column names.
+    pub fn header_match(mut self) -> Self {
+        self.header = Some(CopyHeader::Match);
+        self
+    }
+
+    /// Set `QUOTE`.
+    pub fn quote(mut self, quote: char) -> Self {
+        self.quote = Some(quote);
+        self
+    }
+
+    /// Set `ESCAPE`.
+    pub fn escape(mut self, escape: char) -> Self {
+        self.escape = Some(escape);
+        self
+    }
+
+    /// Set `FORCE_QUOTE` for the given columns.
+    pub fn force_quote<T, I>(mut self, columns: I) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        let columns = columns.into_iter().map(|c| c.into_iden()).collect();
+        self.force_quote = Some(ForceQuote::Columns(columns));
+        self
+    }
+
+    /// Set `FORCE_QUOTE *`.
+    pub fn force_quote_all(mut self) -> Self {
+        self.force_quote = Some(ForceQuote::All);
+        self
+    }
+
+    /// Set `FORCE_NOT_NULL` for the given columns.
+    pub fn force_not_null<T, I>(mut self, columns: I) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.force_not_null
+            .extend(columns.into_iter().map(|c| c.into_iden()));
+        self
+    }
+
+    /// Set `FORCE_NULL` for the given columns.
+    pub fn force_null<T, I>(mut self, columns: I) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.force_null
+            .extend(columns.into_iter().map(|c| c.into_iden()));
+        self
+    }
+
+    /// Set `ON_ERROR stop`.
+    pub fn on_error_stop(mut self) -> Self {
+        self.on_error = Some(OnError::Stop);
+        self
+    }
+
+    /// Set `ON_ERROR ignore`, i.e., skip rows that fail to convert.
+    pub fn on_error_ignore(mut self) -> Self {
+        self.on_error = Some(OnError::Ignore);
+        self
+    }
+
+    /// Set `ENCODING`.
+    pub fn encoding<S>(mut self, encoding: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.encoding = Some(encoding.into());
+        self
+    }
+
+    /// Only load the rows matching the condition, for `COPY FROM`.
+    ///
+    /// ## Panics
+    /// Rendering the statement will panic if it copies a query or writes the data with `TO`.
+    pub fn and_where<T>(mut self, expr: T) -> Self
+    where
+        T: Into<Expr>,
+    {
+        self.conditions.push(expr.into());
+        self
+    }
+}
+
+fn write_copy<W: SqlWriter>(w: &mut W, copy: &CopyStatement) {
+    let copy_to = copy.query.is_some()
+        || matches!(
+            copy.target,
+            Some(CopyTarget::ToStdout | CopyTarget::ToFile(_))
+        );
+    if copy.query.is_some()
+        && matches!(
+            copy.target,
+            Some(CopyTarget::FromStdin | CopyTarget::FromFile(_))
+        )
+    {
+        panic!("COPY of a query can only write the data with TO.");
+    }
+    if copy_to && !copy.conditions.is_empty() {
+        panic!("COPY WHERE is only allowed with FROM.");
+    }
+    w.push_str("COPY ");
+    if let Some(query) = &copy.query {
+        w.push_char('(');
+        write_select(w, query);
+        w.push_char(')');
+    } else if let Some(table) = &copy.table {
+        write_table_name(w, table);
+        if !copy.columns.is_empty() {
+            w.push_str(" (");
+            write_iden_list(w, &copy.columns);
+            w.push_char(')');
+        }
+    }
+
+    match &copy.target {
+        Some(CopyTarget::FromStdin) => w.push_str(" FROM STDIN"),
+        Some(CopyTarget::ToStdout) => w.push_str(" TO STDOUT"),
+        Some(CopyTarget::FromFile(path)) => {
+            w.push_str(" FROM ");
+            write_string_value(w, path);
+        }
+        Some(CopyTarget::ToFile(path)) => {
+            w.push_str(" TO ");
+            write_string_value(w, path);
+        }
+        None => {}
+    }
+
+    write_copy_options(w, copy);
+
+    if let Some(condition) = Expr::from_conditions(copy.conditions.clone()) {
+        w.push_str(" WHERE ");
+        write_expr(w, &condition);
+    }
+}
+
+fn write_copy_options<W: SqlWriter>(w: &mut W, copy: &CopyStatement) {
+    let has_options = copy.format.is_some()
+        || copy.freeze.is_some()
+        || copy.delimiter.is_some()
+        || copy.null.is_some()
+        || copy.header.is_some()
+        || copy.quote.is_some()
+        || copy.escape.is_some()
+        || copy.force_quote.is_some()
+        || !copy.force_not_null.is_empty()
+        || !copy.force_null.is_empty()
+        || copy.on_error.is_some()
+        || copy.encoding.is_some();
+    if !has_options {
+        return;
+    }
+
+    w.push_str(" (");
+
+    fn write_char<W: SqlWriter>(w: &mut W, c: char) {
+        write_string_value(w, c.encode_utf8(&mut [0; 4]));
+    }
+
+    let mut is_first = true;
+    macro_rules! write_comma_if_not_first {
+        () => {
+            if is_first {
+                is_first = false
+            } else {
+                w.push_str(", ");
+            }
+        };
+    }
+    if let Some(format) = copy.format {
+        write_comma_if_not_first!();
+        w.push_str("FORMAT ");
+        w.push_str(format.as_str());
+    }
+    if let Some(freeze) = copy.freeze {
+        write_comma_if_not_first!();
+        w.push_str("FREEZE");
+        write_false(w, freeze);
+    }
+    if let Some(delimiter) = copy.delimiter {
+        write_comma_if_not_first!();
+        w.push_str("DELIMITER ");
+        write_char(w, delimiter);
+    }
+    if let Some(null) = &copy.null {
+        write_comma_if_not_first!();
+        w.push_str("NULL ");
+        write_string_value(w, null);
+    }
+    if let Some(header) = copy.header {
+        write_comma_if_not_first!();
+        w.push_str("HEADER");
+        match header {
+            CopyHeader::Enabled(header) => write_false(w, header),
+            CopyHeader::Match => w.push_str(" MATCH"),
+        }
+    }
+    if let Some(quote) = copy.quote {
+        write_comma_if_not_first!();
+        w.push_str("QUOTE ");
+        write_char(w, quote);
+    }
+    if let Some(escape) = copy.escape {
+        write_comma_if_not_first!();
+        w.push_str("ESCAPE ");
+        write_char(w, escape);
+    }
+    if let Some(force_quote) = &copy.force_quote {
+        write_comma_if_not_first!();
+        w.push_str("FORCE_QUOTE ");
+        match force_quote {
+            ForceQuote::All => w.push_char('*'),
+            ForceQuote::Columns(columns) => {
+                w.push_char('(');
+                write_iden_list(w, columns);
+                w.push_char(')');
+            }
+        }
+    }
+    if !copy.force_not_null.is_empty() {
+        write_comma_if_not_first!();
+        w.push_str("FORCE_NOT_NULL (");
+        write_iden_list(w, &copy.force_not_null);
+        w.push_char(')');
+    }
+    if !copy.force_null.is_empty() {
+        write_comma_if_not_first!();
+        w.push_str("FORCE_NULL (");
+        write_iden_list(w, &copy.force_null);
+        w.push_char(')');
+    }
+    if let Some(on_error) = copy.on_error {
+        write_comma_if_not_first!();
+        w.push_str(match on_error {
+            OnError::Stop => "ON_ERROR stop",
+            OnError::Ignore => "ON_ERROR ignore",
+        });
+    }
+    if let Some(encoding) = &copy.encoding {
+        write_comma_if_not_first!();
+        w.push_str("ENCODING ");
+        write_string_value(w, encoding);
+    }
+    let _ = is_first;
+
+    w.push_char(')');
+}
+
+fn write_iden_list<W: SqlWriter>(w: &mut W, idens: &[Iden]) {
+    for (i, iden) in idens.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        write_iden(w, iden);
+    }
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::hash::DefaultHasher;
+use std::hash::Hasher;
+
 use crate::SqlWriterValues;
 use crate::expr::Expr;
 use crate::expr::write_expr;
@@ -23,9 +26,11 @@ use crate::types::IntoTableRef;
 use crate::types::TableRef;
 use crate::types::write_table_ref;
 use crate::writer::SqlWriter;
+use crate::writer::SqlWriterHasher;
 
 /// Delete existing rows from the table.
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Delete {
     table: Option<TableRef>,
     conditions: Vec<Expr>,
@@ -53,6 +58,24 @@ impl Delete {
         sql
     }
 
+    /// Feed the shape of the statement into the given hasher.
+    ///
+    /// The shape is the SQL string with placeholders, as built by [`Self::to_values`], so
+    /// statements that only differ in their bound values hash the same.
+    pub fn shape_hash<H: Hasher>(&self, state: &mut H) {
+        let mut w = SqlWriterHasher::new(state);
+        write_delete(&mut w, self);
+    }
+
+    /// Return a fingerprint of the statement shape, see [`Self::shape_hash`].
+    ///
+    /// The fingerprint is only stable within the same build of the program.
+    pub fn fingerprint(&self) -> u64 {
+        let mut state = DefaultHasher::new();
+        self.shape_hash(&mut state);
+        state.finish()
+    }
+
     /// Specify which table to delete from.
     pub fn from_table<T>(mut self, table: T) -> Self
     where
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::hash::DefaultHasher;
+use std::hash::Hasher;
+
 use crate::SqlWriterValues;
 use crate::query::Delete;
 use crate::query::Insert;
@@ -22,9 +25,12 @@ use crate::query::write_insert;
 use crate::query::write_select;
 use crate::query::write_update;
 use crate::writer::SqlWriter;
+use crate::writer::SqlWriterHasher;
+use crate::writer::write_false;
 
 /// Explain a SQL statement.
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Explain {
     statement: Option<ExplainableStatement>,
     analyze: Option<bool>,
@@ -61,6 +67,24 @@ impl Explain {
         sql
     }
 
+    /// Feed the shape of the statement into the given hasher.
+    ///
+    /// The shape is the SQL string with placeholders, as built by [`Self::to_values`], so
+    /// statements that only differ in their bound values hash the same.
+    pub fn shape_hash<H: Hasher>(&self, state: &mut H) {
+        let mut w = SqlWriterHasher::new(state);
+        write_explain(&mut w, self);
+    }
+
+    /// Return a fingerprint of the statement shape, see [`Self::shape_hash`].
+    ///
+    /// The fingerprint is only stable within the same build of the program.
+    pub fn fingerprint(&self) -> u64 {
+        let mut state = DefaultHasher::new();
+        self.shape_hash(&mut state);
+        state.finish()
+    }
+
     /// Set the statement to be explained.
     pub fn statement(mut self, statement: impl Into<ExplainableStatement>) -> Self {
         self.statement = Some(statement.into());
@@ -170,7 +194,8 @@ impl Explain {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 #[expect(missing_docs)]
 pub enum ExplainableStatement {
@@ -204,7 +229,8 @@ impl From<Delete> for ExplainableStatement {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum Format {
     Text,
     Xml,
@@ -223,7 +249,8 @@ impl Format {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum Serialize {
     None,
     Text,
@@ -259,17 +286,6 @@ fn write_explain<W: SqlWriter>(w: &mut W, explain: &Explain) {
     if has_options {
         w.push_str(" (");
 
-        // Specifies whether the selected option should be turned on or off. You can write TRUE, ON,
-        // or 1 to enable the option, and FALSE, OFF, or 0 to disable it. The boolean value can also
-        // be omitted, in which case TRUE is assumed.
-        //
-        // @see https://www.postgresql.org/docs/18/sql-explain.html
-        fn write_false<W: SqlWriter>(w: &mut W, value: bool) {
-            if !value {
-                w.push_str(" 0");
-            }
-        }
-
         let mut is_first = true;
         macro_rules! write_comma_if_not_first {
             () => {
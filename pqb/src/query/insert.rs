@@ -12,9 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt::Arguments;
+use std::hash::DefaultHasher;
+use std::hash::Hasher;
+use std::sync::Arc;
+
 use crate::SqlWriterValues;
+use crate::copy::is_null;
+use crate::copy::write_array_literal;
 use crate::expr::Expr;
 use crate::expr::write_expr;
+use crate::func::FunctionCall;
 use crate::query::OnConflict;
 use crate::query::Returning;
 use crate::query::Select;
@@ -23,16 +31,20 @@ use crate::query::write_on_conflict;
 use crate::query::write_returning;
 use crate::query::write_select;
 use crate::query::write_with;
+use crate::table::ColumnType;
 use crate::types::Iden;
 use crate::types::IntoIden;
 use crate::types::IntoTableRef;
 use crate::types::TableRef;
 use crate::types::write_iden;
 use crate::types::write_table_ref;
+use crate::value::Value;
 use crate::writer::SqlWriter;
+use crate::writer::SqlWriterHasher;
 
 /// Insert any new rows into an existing table.
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Insert {
     table: Option<TableRef>,
     columns: Vec<Iden>,
@@ -63,6 +75,24 @@ impl Insert {
         sql
     }
 
+    /// Feed the shape of the statement into the given hasher.
+    ///
+    /// The shape is the SQL string with placeholders, as built by [`Self::to_values`], so
+    /// statements that only differ in their bound values hash the same.
+    pub fn shape_hash<H: Hasher>(&self, state: &mut H) {
+        let mut w = SqlWriterHasher::new(state);
+        write_insert(&mut w, self);
+    }
+
+    /// Return a fingerprint of the statement shape, see [`Self::shape_hash`].
+    ///
+    /// The fingerprint is only stable within the same build of the program.
+    pub fn fingerprint(&self) -> u64 {
+        let mut state = DefaultHasher::new();
+        self.shape_hash(&mut state);
+        state.finish()
+    }
+
     /// Specify which table to insert into.
     pub fn into_table<T>(mut self, table: T) -> Self
     where
@@ -138,17 +168,315 @@ impl Insert {
         self
     }
 
+    /// Insert rows given as one array of values per column, with `SELECT * FROM UNNEST(...)`.
+    ///
+    /// Each array is bound as a single parameter cast to an array of the column type, so the
+    /// statement has one parameter per column and stays the same regardless of the number of rows.
+    ///
+    /// ```
+    /// use pqb::query::Insert;
+    /// use pqb::table::ColumnType;
+    /// use pqb::value::Value;
+    ///
+    /// let insert = Insert::new()
+    ///     .into_table("users")
+    ///     .columns(["id", "name"])
+    ///     .unnest_columns([
+    ///         (ColumnType::Int, vec![Value::from(1), Value::from(2)]),
+    ///         (ColumnType::Text, vec![Value::from("alice"), Value::from("bob")]),
+    ///     ]);
+    /// assert_eq!(
+    ///     insert.to_values().into_parts().0,
+    ///     r#"INSERT INTO "users" ("id", "name") SELECT * FROM UNNEST(CAST($1 AS integer[]), CAST($2 AS text[]))"#
+    /// );
+    /// ```
+    ///
+    /// UNNEST flattens nested arrays, so the values of an array column are bound as a `text[]` of
+    /// array literals instead, and each literal is cast back to the column type in the select list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of arrays does not match the number of columns specified, if the
+    /// arrays have different lengths, or if a value of an array column is not an array.
+    pub fn unnest_columns<I>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = (ColumnType, Vec<Value>)>,
+    {
+        let columns = columns.into_iter().collect::<Vec<_>>();
+        assert_eq!(columns.len(), self.columns.len());
+        let rows = columns.first().map_or(0, |(_, values)| values.len());
+        let mut has_array = false;
+        let mut select = Vec::with_capacity(columns.len());
+        let mut arrays = Vec::with_capacity(columns.len());
+        for ((ty, values), column) in columns.into_iter().zip(&self.columns) {
+            assert_eq!(
+                values.len(),
+                rows,
+                "UNNEST arrays must have the same length"
+            );
+            let column = Expr::column(column.clone());
+            let (ty, values, column) = match ty {
+                ColumnType::Array(_) => {
+                    has_array = true;
+                    let literals = values.iter().map(array_literal).collect();
+                    let column = Expr::FunctionCall(FunctionCall::cast_as_type(column, ty));
+                    (ColumnType::Text, literals, column)
+                }
+                // serial types only exist as column types, cast to their integer types
+                ColumnType::SmallSerial => (ColumnType::SmallInt, values, column),
+                ColumnType::Serial => (ColumnType::Int, values, column),
+                ColumnType::BigSerial => (ColumnType::BigInt, values, column),
+                ty => (ty, values, column),
+            };
+            select.push(column);
+            let array = Expr::value(Value::Array(Some(values)));
+            arrays.push(Expr::FunctionCall(FunctionCall::cast_as_type(
+                array,
+                ColumnType::Array(Arc::new(ty)),
+            )));
+        }
+        let select = has_array.then_some(select);
+        self.source = Some(InsertValueSource::Unnest(arrays, select));
+        self
+    }
+
+    /// Insert rows of values with `SELECT * FROM UNNEST(...)`, given the type of each column.
+    ///
+    /// The rows are transposed into one array per column, see [`Self::unnest_columns`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of types or the number of values in a row does not match the number
+    /// of columns specified, or if a value of an array column is not an array.
+    pub fn unnest_rows<T, R, V>(self, types: T, rows: R) -> Self
+    where
+        T: IntoIterator<Item = ColumnType>,
+        R: IntoIterator<Item = V>,
+        V: IntoIterator<Item = Value>,
+    {
+        let mut columns = types.into_iter().map(|ty| (ty, vec![])).collect::<Vec<_>>();
+        for row in rows {
+            let row = row.into_iter().collect::<Vec<_>>();
+            assert_eq!(row.len(), self.columns.len());
+            for ((_, values), value) in columns.iter_mut().zip(row) {
+                values.push(value);
+            }
+        }
+        self.unnest_columns(columns)
+    }
+
     /// Insert `n` rows with default values if columns and values are not supplied.
     pub fn or_default_values(mut self, n: u32) -> Self {
         self.defaults = Some(n);
         self
     }
+
+    /// Split the rows into statements with at most 65535 parameters each, the most a single
+    /// statement can bind.
+    ///
+    /// See [`Self::batches_with`] for details.
+    pub fn batches(&self) -> InsertBatches<'_> {
+        self.batches_with(InsertBatchLimits::new())
+    }
+
+    /// Split the rows into statements that respect the given limits.
+    ///
+    /// Each statement is returned with its placeholders and collected values, as built by
+    /// [`Self::to_values`], and keeps the WITH, ON CONFLICT and RETURNING clauses, so that running
+    /// all of them is equivalent to running the whole statement. A row that exceeds the limits on
+    /// its own is still returned as a statement of one row.
+    ///
+    /// Statements that do not insert a list of rows, e.g. INSERT ... SELECT or UNNEST, are
+    /// returned as a single statement.
+    ///
+    /// ```
+    /// use pqb::expr::Expr;
+    /// use pqb::query::Insert;
+    /// use pqb::query::InsertBatchLimits;
+    ///
+    /// let mut insert = Insert::new().into_table("users").columns(["id", "name"]);
+    /// for id in 0..5 {
+    ///     insert = insert.values([Expr::value(id), Expr::value(format!("user{id}"))]);
+    /// }
+    /// let batches = insert
+    ///     .batches_with(InsertBatchLimits::new().max_params(4))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(batches.len(), 3);
+    /// assert_eq!(
+    ///     batches[2].0,
+    ///     r#"INSERT INTO "users" ("id", "name") VALUES ($1, $2)"#
+    /// );
+    /// ```
+    pub fn batches_with(&self, limits: InsertBatchLimits) -> InsertBatches<'_> {
+        InsertBatches {
+            insert: self,
+            limits,
+            overhead: None,
+            next_row: 0,
+            done: false,
+        }
+    }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+/// Limits for splitting a multi-row INSERT into several statements, see [`Insert::batches_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InsertBatchLimits {
+    max_params: usize,
+    max_bytes: Option<usize>,
+}
+
+impl Default for InsertBatchLimits {
+    fn default() -> Self {
+        Self {
+            max_params: 65535,
+            max_bytes: None,
+        }
+    }
+}
+
+impl InsertBatchLimits {
+    /// Create limits of 65535 parameters per statement and no limit on the SQL length.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of parameters per statement.
+    pub fn max_params(mut self, n: usize) -> Self {
+        self.max_params = n;
+        self
+    }
+
+    /// Set the maximum length in bytes of the SQL string of each statement.
+    ///
+    /// Placeholders are counted with the width of the largest parameter number allowed by the
+    /// parameter limit, so statements may be split slightly earlier than needed.
+    pub fn max_bytes(mut self, n: usize) -> Self {
+        self.max_bytes = Some(n);
+        self
+    }
+
+    fn placeholder_len(&self) -> usize {
+        1 + self.max_params.max(1).ilog10() as usize + 1
+    }
+}
+
+/// An iterator over the statements of a split INSERT, see [`Insert::batches_with`].
+#[derive(Debug, Clone)]
+pub struct InsertBatches<'a> {
+    insert: &'a Insert,
+    limits: InsertBatchLimits,
+    // parameters and bytes of the statement without its rows
+    overhead: Option<WriteSize>,
+    next_row: usize,
+    done: bool,
+}
+
+impl Iterator for InsertBatches<'_> {
+    type Item = (String, Vec<Value>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let rows = match &self.insert.source {
+            Some(InsertValueSource::Values(rows)) if !rows.is_empty() => rows,
+            _ => {
+                self.done = true;
+                return Some(self.insert.to_values().into_parts());
+            }
+        };
+
+        let placeholder_len = self.limits.placeholder_len();
+        let overhead = *self.overhead.get_or_insert_with(|| {
+            let mut size = WriteSize::new(placeholder_len);
+            let insert = Insert {
+                source: None,
+                ..self.insert.clone()
+            };
+            write_insert(&mut size, &insert);
+            size.bytes += " VALUES ".len();
+            size
+        });
+
+        let start = self.next_row;
+        let mut params = overhead.params;
+        let mut bytes = overhead.bytes;
+        let mut end = start;
+        while end < rows.len() {
+            let mut size = WriteSize::new(placeholder_len);
+            if end > start {
+                size.bytes += ", ".len();
+            }
+            write_values_row(&mut size, &rows[end]);
+            let fits = params + size.params <= self.limits.max_params
+                && self
+                    .limits
+                    .max_bytes
+                    .is_none_or(|max_bytes| bytes + size.bytes <= max_bytes);
+            if end > start && !fits {
+                break;
+            }
+            params += size.params;
+            bytes += size.bytes;
+            end += 1;
+        }
+
+        self.next_row = end;
+        self.done = end == rows.len();
+        let insert = Insert {
+            source: Some(InsertValueSource::Values(rows[start..end].to_vec())),
+            ..self.insert.clone()
+        };
+        Some(insert.to_values().into_parts())
+    }
+}
+
+/// SQL writer that only measures the statement.
+#[derive(Debug, Clone, Copy)]
+struct WriteSize {
+    params: usize,
+    bytes: usize,
+    placeholder_len: usize,
+}
+
+impl WriteSize {
+    fn new(placeholder_len: usize) -> Self {
+        Self {
+            params: 0,
+            bytes: 0,
+            placeholder_len,
+        }
+    }
+}
+
+impl SqlWriter for WriteSize {
+    fn push_param(&mut self, _: Value) {
+        self.params += 1;
+        self.bytes += self.placeholder_len;
+    }
+
+    fn push_str(&mut self, value: &str) {
+        self.bytes += value.len();
+    }
+
+    fn push_char(&mut self, value: char) {
+        self.bytes += value.len_utf8();
+    }
+
+    fn push_fmt(&mut self, args: Arguments) {
+        self.bytes += args.to_string().len();
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum InsertValueSource {
     Values(Vec<Vec<Expr>>),
     Select(Box<Select>),
+    /// The arrays passed to UNNEST, and the select list when some columns are cast back from
+    /// array literals.
+    Unnest(Vec<Expr>, Option<Vec<Expr>>),
 }
 
 pub(crate) fn write_insert<W: SqlWriter>(w: &mut W, insert: &Insert) {
@@ -195,17 +523,43 @@ pub(crate) fn write_insert<W: SqlWriter>(w: &mut W, insert: &Insert) {
                         if i > 0 {
                             w.push_str(", ");
                         }
-                        w.push_char('(');
-                        for (j, expr) in row.iter().enumerate() {
-                            if j > 0 {
+                        write_values_row(w, row);
+                    }
+                }
+                InsertValueSource::Select(select) => write_select(w, select),
+                InsertValueSource::Unnest(arrays, select) => {
+                    w.push_str("SELECT ");
+                    match select {
+                        Some(select) => {
+                            for (i, expr) in select.iter().enumerate() {
+                                if i > 0 {
+                                    w.push_str(", ");
+                                }
+                                write_expr(w, expr);
+                            }
+                        }
+                        None => w.push_char('*'),
+                    }
+                    w.push_str(" FROM UNNEST(");
+                    for (i, array) in arrays.iter().enumerate() {
+                        if i > 0 {
+                            w.push_str(", ");
+                        }
+                        write_expr(w, array);
+                    }
+                    w.push_char(')');
+                    if select.is_some() {
+                        // name the columns after the insert columns for the select list
+                        w.push_str(" AS \"unnest\" (");
+                        for (i, column) in insert.columns.iter().enumerate() {
+                            if i > 0 {
                                 w.push_str(", ");
                             }
-                            write_expr(w, expr);
+                            write_iden(w, column);
                         }
                         w.push_char(')');
                     }
                 }
-                InsertValueSource::Select(select) => write_select(w, select),
             }
         }
 
@@ -218,3 +572,26 @@ pub(crate) fn write_insert<W: SqlWriter>(w: &mut W, insert: &Insert) {
         }
     }
 }
+
+fn array_literal(value: &Value) -> Value {
+    match value {
+        Value::Array(Some(values)) => {
+            let mut literal = String::new();
+            write_array_literal(&mut literal, values);
+            Value::String(Some(literal))
+        }
+        value if is_null(value) => Value::String(None),
+        _ => panic!("UNNEST array columns require array values"),
+    }
+}
+
+fn write_values_row<W: SqlWriter>(w: &mut W, row: &[Expr]) {
+    w.push_char('(');
+    for (i, expr) in row.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        write_expr(w, expr);
+    }
+    w.push_char(')');
+}
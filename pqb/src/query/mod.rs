@@ -15,6 +15,7 @@
 //! SQL query statements.
 
 mod conflict;
+mod copy;
 mod delete;
 mod explain;
 mod insert;
@@ -25,6 +26,7 @@ mod update;
 mod with;
 
 pub use self::conflict::*;
+pub use self::copy::*;
 pub use self::delete::*;
 pub use self::explain::*;
 pub use self::insert::*;
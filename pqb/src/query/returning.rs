@@ -18,7 +18,8 @@ use crate::types::IntoColumnRef;
 use crate::writer::SqlWriter;
 
 /// RETURNING clause.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 #[expect(missing_docs)]
 pub enum Returning {
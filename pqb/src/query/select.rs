@@ -12,6 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::hash::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
 use crate::expr::Expr;
 use crate::expr::write_expr;
 use crate::query::With;
@@ -27,10 +31,12 @@ use crate::types::TableRef;
 use crate::types::write_iden;
 use crate::types::write_table_ref;
 use crate::writer::SqlWriter;
+use crate::writer::SqlWriterHasher;
 use crate::writer::SqlWriterValues;
 
 /// Select rows from an existing table.
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Select {
     selects: Vec<SelectExpr>,
     from: Vec<TableRef>,
@@ -47,7 +53,8 @@ pub struct Select {
 }
 
 /// Join expression.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct JoinExpr {
     join_type: JoinType,
     table: TableRef,
@@ -74,6 +81,24 @@ impl Select {
         sql
     }
 
+    /// Feed the shape of the statement into the given hasher.
+    ///
+    /// The shape is the SQL string with placeholders, as built by [`Self::to_values`], so
+    /// statements that only differ in their bound values hash the same.
+    pub fn shape_hash<H: Hasher>(&self, state: &mut H) {
+        let mut w = SqlWriterHasher::new(state);
+        write_select(&mut w, self);
+    }
+
+    /// Return a fingerprint of the statement shape, see [`Self::shape_hash`].
+    ///
+    /// The fingerprint is only stable within the same build of the program.
+    pub fn fingerprint(&self) -> u64 {
+        let mut state = DefaultHasher::new();
+        self.shape_hash(&mut state);
+        state.finish()
+    }
+
     /// From table.
     pub fn from<R>(mut self, table: R) -> Self
     where
@@ -285,7 +310,8 @@ impl Select {
 }
 
 /// Select expression used in select statement.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SelectExpr {
     expr: Expr,
     alias: Option<Iden>,
@@ -304,7 +330,8 @@ where
 }
 
 /// Row-level lock clause for select statements.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RowLevelLock {
     ty: RowLevelLockType,
     tables: Vec<Iden>,
@@ -372,7 +399,8 @@ impl RowLevelLock {
 }
 
 /// Types of row-level locks.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum RowLevelLockType {
     Update,
     NoKeyUpdate,
@@ -381,14 +409,16 @@ enum RowLevelLockType {
 }
 
 /// Behavior of row-level locks.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum RowLevelLockBehavior {
     Nowait,
     SkipLocked,
 }
 
 /// TABLESAMPLE clause.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableSample {
     method: SampleMethod,
     percentage: f64,
@@ -429,7 +459,27 @@ impl TableSample {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+// Floats are compared bitwise so that sampling clauses can be used as hash keys.
+impl PartialEq for TableSample {
+    fn eq(&self, other: &Self) -> bool {
+        self.method == other.method
+            && self.percentage.to_bits() == other.percentage.to_bits()
+            && self.repeatable.map(f64::to_bits) == other.repeatable.map(f64::to_bits)
+    }
+}
+
+impl Eq for TableSample {}
+
+impl Hash for TableSample {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.method.hash(state);
+        self.percentage.to_bits().hash(state);
+        self.repeatable.map(f64::to_bits).hash(state);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[expect(clippy::upper_case_acronyms)]
 enum SampleMethod {
     BERNOULLI,
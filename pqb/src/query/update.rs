@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::hash::DefaultHasher;
+use std::hash::Hasher;
+
 use crate::SqlWriterValues;
 use crate::expr::Expr;
 use crate::expr::write_expr;
@@ -26,9 +29,11 @@ use crate::types::TableRef;
 use crate::types::write_iden;
 use crate::types::write_table_ref;
 use crate::writer::SqlWriter;
+use crate::writer::SqlWriterHasher;
 
 /// Update existing rows in the table.
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Update {
     table: Option<TableRef>,
     values: Vec<(Iden, Expr)>,
@@ -57,6 +62,24 @@ impl Update {
         sql
     }
 
+    /// Feed the shape of the statement into the given hasher.
+    ///
+    /// The shape is the SQL string with placeholders, as built by [`Self::to_values`], so
+    /// statements that only differ in their bound values hash the same.
+    pub fn shape_hash<H: Hasher>(&self, state: &mut H) {
+        let mut w = SqlWriterHasher::new(state);
+        write_update(&mut w, self);
+    }
+
+    /// Return a fingerprint of the statement shape, see [`Self::shape_hash`].
+    ///
+    /// The fingerprint is only stable within the same build of the program.
+    pub fn fingerprint(&self) -> u64 {
+        let mut state = DefaultHasher::new();
+        self.shape_hash(&mut state);
+        state.finish()
+    }
+
     /// Specify which table to update.
     pub fn table<T>(mut self, table: T) -> Self
     where
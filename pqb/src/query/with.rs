@@ -21,7 +21,8 @@ use crate::value::Value;
 use crate::value::write_value;
 
 /// A WITH clause can contain one or multiple common table expressions.
-#[derive(Debug, Clone, Default, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct With {
     ctes: Vec<CommonTableExpression>,
 }
@@ -40,7 +41,8 @@ impl With {
 }
 
 /// A table definition inside a WITH clause
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommonTableExpression {
     name: Iden,
     columns: Vec<Iden>,
@@ -101,7 +103,8 @@ impl CommonTableExpression {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum Query {
     Select(Box<Select>),
     Values(Vec<Vec<Value>>),
@@ -0,0 +1,99 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::role::RoleOptions;
+use crate::role::write_role_options;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::write_iden;
+use crate::writer::SqlWriter;
+
+/// ALTER ROLE statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlterRole {
+    role: Option<Iden>,
+    action: Option<RoleAlterAction>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum RoleAlterAction {
+    Options(RoleOptions),
+    RenameTo(Iden),
+}
+
+impl AlterRole {
+    /// Create a new ALTER ROLE statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_alter_role(&mut w, self);
+        w
+    }
+
+    /// Convert the ALTER ROLE statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_alter_role(&mut sql, self);
+        sql
+    }
+
+    /// Set the role name.
+    pub fn role<N>(mut self, role: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.role = Some(role.into_iden());
+        self
+    }
+
+    /// Change the role attributes.
+    pub fn options(mut self, options: RoleOptions) -> Self {
+        self.action = Some(RoleAlterAction::Options(options));
+        self
+    }
+
+    /// Rename the role.
+    pub fn rename_to<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.action = Some(RoleAlterAction::RenameTo(name.into_iden()));
+        self
+    }
+}
+
+fn write_alter_role<W: SqlWriter>(w: &mut W, alter: &AlterRole) {
+    w.push_str("ALTER ROLE ");
+    if let Some(role) = &alter.role {
+        write_iden(w, role);
+    }
+    match &alter.action {
+        Some(RoleAlterAction::Options(options)) if !options.is_empty() => {
+            w.push_str(" WITH");
+            write_role_options(w, options);
+        }
+        Some(RoleAlterAction::RenameTo(name)) => {
+            w.push_str(" RENAME TO ");
+            write_iden(w, name);
+        }
+        _ => {}
+    }
+}
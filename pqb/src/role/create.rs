@@ -0,0 +1,97 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::role::RoleOptions;
+use crate::role::write_role_options;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::write_iden;
+use crate::writer::SqlWriter;
+
+/// CREATE ROLE statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateRole {
+    role: Option<Iden>,
+    options: RoleOptions,
+    in_roles: Vec<Iden>,
+}
+
+impl CreateRole {
+    /// Create a new CREATE ROLE statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_create_role(&mut w, self);
+        w
+    }
+
+    /// Convert the CREATE ROLE statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_create_role(&mut sql, self);
+        sql
+    }
+
+    /// Set the role name.
+    pub fn role<N>(mut self, role: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.role = Some(role.into_iden());
+        self
+    }
+
+    /// Set the role attributes.
+    pub fn options(mut self, options: RoleOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Add the new role as a member of the given roles.
+    pub fn in_roles<T, I>(mut self, roles: I) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.in_roles
+            .extend(roles.into_iter().map(|r| r.into_iden()));
+        self
+    }
+}
+
+fn write_create_role<W: SqlWriter>(w: &mut W, create: &CreateRole) {
+    w.push_str("CREATE ROLE ");
+    if let Some(role) = &create.role {
+        write_iden(w, role);
+    }
+    if !create.options.is_empty() {
+        w.push_str(" WITH");
+        write_role_options(w, &create.options);
+    }
+    if !create.in_roles.is_empty() {
+        w.push_str(" IN ROLE ");
+        for (i, role) in create.in_roles.iter().enumerate() {
+            if i > 0 {
+                w.push_str(", ");
+            }
+            write_iden(w, role);
+        }
+    }
+}
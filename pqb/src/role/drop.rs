@@ -0,0 +1,86 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::write_iden;
+use crate::writer::SqlWriter;
+
+/// DROP ROLE statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DropRole {
+    roles: Vec<Iden>,
+    if_exists: bool,
+}
+
+impl DropRole {
+    /// Create a new DROP ROLE statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_drop_role(&mut w, self);
+        w
+    }
+
+    /// Convert the DROP ROLE statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_drop_role(&mut sql, self);
+        sql
+    }
+
+    /// Add a role to drop.
+    pub fn role<N>(mut self, role: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.roles.push(role.into_iden());
+        self
+    }
+
+    /// Add multiple roles to drop.
+    pub fn roles<T, I>(mut self, roles: I) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.roles.extend(roles.into_iter().map(|r| r.into_iden()));
+        self
+    }
+
+    /// Drop the role if it exists.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+}
+
+fn write_drop_role<W: SqlWriter>(w: &mut W, drop_role: &DropRole) {
+    w.push_str("DROP ROLE ");
+    if drop_role.if_exists {
+        w.push_str("IF EXISTS ");
+    }
+    for (i, role) in drop_role.roles.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        write_iden(w, role);
+    }
+}
@@ -0,0 +1,202 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::value::write_string_value;
+use crate::writer::SqlWriter;
+
+/// Role attributes shared by CREATE ROLE and ALTER ROLE.
+///
+/// Attributes left unset are omitted, so the server default (or, in ALTER ROLE, the current
+/// setting) applies.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoleOptions {
+    superuser: Option<bool>,
+    create_db: Option<bool>,
+    create_role: Option<bool>,
+    inherit: Option<bool>,
+    login: Option<bool>,
+    replication: Option<bool>,
+    bypass_rls: Option<bool>,
+    connection_limit: Option<i32>,
+    password: Option<Option<String>>,
+    valid_until: Option<String>,
+}
+
+impl RoleOptions {
+    /// Create an empty set of role attributes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Make the role a superuser (SUPERUSER).
+    pub fn superuser(mut self) -> Self {
+        self.superuser = Some(true);
+        self
+    }
+
+    /// Make the role a regular user (NOSUPERUSER).
+    pub fn no_superuser(mut self) -> Self {
+        self.superuser = Some(false);
+        self
+    }
+
+    /// Allow the role to create databases (CREATEDB).
+    pub fn create_db(mut self) -> Self {
+        self.create_db = Some(true);
+        self
+    }
+
+    /// Forbid the role to create databases (NOCREATEDB).
+    pub fn no_create_db(mut self) -> Self {
+        self.create_db = Some(false);
+        self
+    }
+
+    /// Allow the role to create and manage roles (CREATEROLE).
+    pub fn create_role(mut self) -> Self {
+        self.create_role = Some(true);
+        self
+    }
+
+    /// Forbid the role to create and manage roles (NOCREATEROLE).
+    pub fn no_create_role(mut self) -> Self {
+        self.create_role = Some(false);
+        self
+    }
+
+    /// Inherit the privileges of roles it is a member of (INHERIT).
+    pub fn inherit(mut self) -> Self {
+        self.inherit = Some(true);
+        self
+    }
+
+    /// Do not inherit the privileges of roles it is a member of (NOINHERIT).
+    pub fn no_inherit(mut self) -> Self {
+        self.inherit = Some(false);
+        self
+    }
+
+    /// Allow the role to log in (LOGIN).
+    pub fn login(mut self) -> Self {
+        self.login = Some(true);
+        self
+    }
+
+    /// Forbid the role to log in (NOLOGIN).
+    pub fn no_login(mut self) -> Self {
+        self.login = Some(false);
+        self
+    }
+
+    /// Allow the role to initiate replication (REPLICATION).
+    pub fn replication(mut self) -> Self {
+        self.replication = Some(true);
+        self
+    }
+
+    /// Forbid the role to initiate replication (NOREPLICATION).
+    pub fn no_replication(mut self) -> Self {
+        self.replication = Some(false);
+        self
+    }
+
+    /// Bypass every row-level security policy (BYPASSRLS).
+    pub fn bypass_rls(mut self) -> Self {
+        self.bypass_rls = Some(true);
+        self
+    }
+
+    /// Apply row-level security policies to the role (NOBYPASSRLS).
+    pub fn no_bypass_rls(mut self) -> Self {
+        self.bypass_rls = Some(false);
+        self
+    }
+
+    /// Set the maximum number of concurrent connections, `-1` for no limit.
+    pub fn connection_limit(mut self, limit: i32) -> Self {
+        self.connection_limit = Some(limit);
+        self
+    }
+
+    /// Set the password of the role.
+    pub fn password<P>(mut self, password: P) -> Self
+    where
+        P: Into<String>,
+    {
+        self.password = Some(Some(password.into()));
+        self
+    }
+
+    /// Remove the password of the role (PASSWORD NULL).
+    pub fn password_null(mut self) -> Self {
+        self.password = Some(None);
+        self
+    }
+
+    /// Set the time after which the password is no longer valid, e.g. `2030-01-01`.
+    pub fn valid_until<T>(mut self, timestamp: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.valid_until = Some(timestamp.into());
+        self
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.superuser.is_none()
+            && self.create_db.is_none()
+            && self.create_role.is_none()
+            && self.inherit.is_none()
+            && self.login.is_none()
+            && self.replication.is_none()
+            && self.bypass_rls.is_none()
+            && self.connection_limit.is_none()
+            && self.password.is_none()
+            && self.valid_until.is_none()
+    }
+}
+
+/// Write the role attributes, each preceded by a space.
+pub(crate) fn write_role_options<W: SqlWriter>(w: &mut W, options: &RoleOptions) {
+    let flags = [
+        (options.superuser, "SUPERUSER"),
+        (options.create_db, "CREATEDB"),
+        (options.create_role, "CREATEROLE"),
+        (options.inherit, "INHERIT"),
+        (options.login, "LOGIN"),
+        (options.replication, "REPLICATION"),
+        (options.bypass_rls, "BYPASSRLS"),
+    ];
+    for (flag, keyword) in flags {
+        if let Some(enabled) = flag {
+            w.push_str(if enabled { " " } else { " NO" });
+            w.push_str(keyword);
+        }
+    }
+    if let Some(limit) = options.connection_limit {
+        w.push_fmt(format_args!(" CONNECTION LIMIT {limit}"));
+    }
+    if let Some(password) = &options.password {
+        w.push_str(" PASSWORD ");
+        match password {
+            Some(password) => write_string_value(w, password),
+            None => w.push_str("NULL"),
+        }
+    }
+    if let Some(valid_until) = &options.valid_until {
+        w.push_str(" VALID UNTIL ");
+        write_string_value(w, valid_until);
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::expr::Expr;
+use crate::expr::write_expr;
+use crate::types::TableName;
+use crate::types::write_table_name;
+use crate::writer::SqlWriter;
+
+/// CALL statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Call {
+    procedure: Option<TableName>,
+    args: Vec<Expr>,
+}
+
+impl Call {
+    /// Create a new CALL statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_call(&mut w, self);
+        w
+    }
+
+    /// Convert the CALL statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_call(&mut sql, self);
+        sql
+    }
+
+    /// Set the procedure to call.
+    pub fn procedure<T>(mut self, procedure: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.procedure = Some(procedure.into());
+        self
+    }
+
+    /// Add an argument.
+    pub fn arg<E>(mut self, arg: E) -> Self
+    where
+        E: Into<Expr>,
+    {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// Add arguments.
+    pub fn args<I, E>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = E>,
+        E: Into<Expr>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+}
+
+fn write_call<W: SqlWriter>(w: &mut W, call: &Call) {
+    w.push_str("CALL ");
+    if let Some(procedure) = &call.procedure {
+        write_table_name(w, procedure);
+    }
+    w.push_char('(');
+    for (i, arg) in call.args.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        write_expr(w, arg);
+    }
+    w.push_char(')');
+}
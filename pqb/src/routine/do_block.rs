@@ -0,0 +1,76 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::routine::Language;
+use crate::routine::write_language;
+use crate::value::write_dollar_quoted;
+use crate::writer::SqlWriter;
+
+/// DO statement builder, i.e., an anonymous code block.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Do {
+    language: Option<Language>,
+    body: String,
+}
+
+impl Do {
+    /// Create a new DO statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_do(&mut w, self);
+        w
+    }
+
+    /// Convert the DO statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_do(&mut sql, self);
+        sql
+    }
+
+    /// Set the language of the code block. PL/pgSQL is used if not set.
+    pub fn language<L>(mut self, language: L) -> Self
+    where
+        L: Into<Language>,
+    {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Set the code to execute, written as a dollar-quoted string.
+    pub fn body<B>(mut self, body: B) -> Self
+    where
+        B: Into<String>,
+    {
+        self.body = body.into();
+        self
+    }
+}
+
+fn write_do<W: SqlWriter>(w: &mut W, do_block: &Do) {
+    w.push_str("DO ");
+    if let Some(language) = &do_block.language {
+        w.push_str("LANGUAGE ");
+        write_language(w, language);
+        w.push_char(' ');
+    }
+    write_dollar_quoted(w, &do_block.body);
+}
@@ -0,0 +1,120 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::table::ColumnType;
+use crate::table::write_column_type;
+use crate::types::DropBehavior;
+use crate::types::TableName;
+use crate::types::write_table_name;
+use crate::writer::SqlWriter;
+
+/// DROP FUNCTION statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DropFunction {
+    functions: Vec<(TableName, Option<Vec<ColumnType>>)>,
+    if_exists: bool,
+    behavior: Option<DropBehavior>,
+}
+
+impl DropFunction {
+    /// Create a new DROP FUNCTION statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_drop_function(&mut w, self);
+        w
+    }
+
+    /// Convert the DROP FUNCTION statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_drop_function(&mut sql, self);
+        sql
+    }
+
+    /// Add a function to drop, identified by name only.
+    pub fn function<T>(mut self, name: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.functions.push((name.into(), None));
+        self
+    }
+
+    /// Add a function to drop, identified by name and argument types.
+    pub fn function_with_args<T, A, I>(mut self, name: T, arg_types: I) -> Self
+    where
+        T: Into<TableName>,
+        A: Into<ColumnType>,
+        I: IntoIterator<Item = A>,
+    {
+        let arg_types = arg_types.into_iter().map(Into::into).collect();
+        self.functions.push((name.into(), Some(arg_types)));
+        self
+    }
+
+    /// Drop the function if it exists.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Add CASCADE to drop dependent objects.
+    pub fn cascade(mut self) -> Self {
+        self.behavior = Some(DropBehavior::Cascade);
+        self
+    }
+
+    /// Add RESTRICT to drop (explicitly).
+    pub fn restrict(mut self) -> Self {
+        self.behavior = Some(DropBehavior::Restrict);
+        self
+    }
+}
+
+fn write_drop_function<W: SqlWriter>(w: &mut W, drop_function: &DropFunction) {
+    w.push_str("DROP FUNCTION ");
+    if drop_function.if_exists {
+        w.push_str("IF EXISTS ");
+    }
+    for (i, (name, arg_types)) in drop_function.functions.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        write_table_name(w, name);
+        if let Some(arg_types) = arg_types {
+            w.push_char('(');
+            for (j, ty) in arg_types.iter().enumerate() {
+                if j > 0 {
+                    w.push_str(", ");
+                }
+                write_column_type(w, ty);
+            }
+            w.push_char(')');
+        }
+    }
+    if let Some(behavior) = drop_function.behavior {
+        w.push_char(' ');
+        match behavior {
+            DropBehavior::Cascade => w.push_str("CASCADE"),
+            DropBehavior::Restrict => w.push_str("RESTRICT"),
+        }
+    }
+}
@@ -0,0 +1,540 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+
+use crate::SqlWriterValues;
+use crate::expr::Expr;
+use crate::expr::write_expr;
+use crate::table::ColumnType;
+use crate::table::write_column_type;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::TableName;
+use crate::types::write_iden;
+use crate::types::write_table_name;
+use crate::value::write_dollar_quoted;
+use crate::value::write_string_value;
+use crate::writer::SqlWriter;
+
+/// CREATE FUNCTION statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateFunction {
+    name: Option<TableName>,
+    or_replace: bool,
+    args: Vec<FunctionArg>,
+    returns: Option<FunctionReturns>,
+    language: Option<Language>,
+    volatility: Option<Volatility>,
+    strict: bool,
+    security_definer: Option<bool>,
+    parallel: Option<Parallel>,
+    settings: Vec<RoutineSetting>,
+    body: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum FunctionReturns {
+    Type(ColumnType),
+    SetOf(ColumnType),
+    Table(Vec<(Iden, ColumnType)>),
+    Void,
+    Trigger,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct RoutineSetting {
+    name: Iden,
+    value: RoutineSettingValue,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum RoutineSettingValue {
+    Literal(String),
+    Identifiers(Vec<Iden>),
+    FromCurrent,
+}
+
+impl RoutineSetting {
+    pub(crate) fn literal<N, V>(name: N, value: V) -> Self
+    where
+        N: IntoIden,
+        V: Into<String>,
+    {
+        Self {
+            name: name.into_iden(),
+            value: RoutineSettingValue::Literal(value.into()),
+        }
+    }
+
+    pub(crate) fn search_path<T, I>(schemas: I) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            name: "search_path".into_iden(),
+            value: RoutineSettingValue::Identifiers(
+                schemas.into_iter().map(|s| s.into_iden()).collect(),
+            ),
+        }
+    }
+
+    pub(crate) fn from_current<N>(name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        Self {
+            name: name.into_iden(),
+            value: RoutineSettingValue::FromCurrent,
+        }
+    }
+}
+
+impl CreateFunction {
+    /// Create a new CREATE FUNCTION statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_create_function(&mut w, self);
+        w
+    }
+
+    /// Convert the CREATE FUNCTION statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_create_function(&mut sql, self);
+        sql
+    }
+
+    /// Set the function name.
+    pub fn name<T>(mut self, name: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Replace the function if it already exists.
+    pub fn or_replace(mut self) -> Self {
+        self.or_replace = true;
+        self
+    }
+
+    /// Add a function argument.
+    pub fn arg(mut self, arg: FunctionArg) -> Self {
+        self.args.push(arg);
+        self
+    }
+
+    /// Add function arguments.
+    pub fn args<I>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = FunctionArg>,
+    {
+        self.args.extend(args);
+        self
+    }
+
+    /// Set the return type.
+    pub fn returns<T>(mut self, ty: T) -> Self
+    where
+        T: Into<ColumnType>,
+    {
+        self.returns = Some(FunctionReturns::Type(ty.into()));
+        self
+    }
+
+    /// Return a set of rows of the given type, i.e., RETURNS SETOF.
+    pub fn returns_setof<T>(mut self, ty: T) -> Self
+    where
+        T: Into<ColumnType>,
+    {
+        self.returns = Some(FunctionReturns::SetOf(ty.into()));
+        self
+    }
+
+    /// Return a set of rows with the given columns, i.e., RETURNS TABLE.
+    pub fn returns_table<N, T, I>(mut self, columns: I) -> Self
+    where
+        N: IntoIden,
+        T: Into<ColumnType>,
+        I: IntoIterator<Item = (N, T)>,
+    {
+        let columns = columns
+            .into_iter()
+            .map(|(name, ty)| (name.into_iden(), ty.into()))
+            .collect();
+        self.returns = Some(FunctionReturns::Table(columns));
+        self
+    }
+
+    /// Return no value, i.e., RETURNS void.
+    pub fn returns_void(mut self) -> Self {
+        self.returns = Some(FunctionReturns::Void);
+        self
+    }
+
+    /// Return a trigger, i.e., RETURNS trigger.
+    pub fn returns_trigger(mut self) -> Self {
+        self.returns = Some(FunctionReturns::Trigger);
+        self
+    }
+
+    /// Set the implementation language.
+    pub fn language<L>(mut self, language: L) -> Self
+    where
+        L: Into<Language>,
+    {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Set the volatility category.
+    pub fn volatility(mut self, volatility: Volatility) -> Self {
+        self.volatility = Some(volatility);
+        self
+    }
+
+    /// Return NULL whenever any argument is NULL, i.e., STRICT.
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    /// Execute with the privileges of the function owner.
+    pub fn security_definer(mut self) -> Self {
+        self.security_definer = Some(true);
+        self
+    }
+
+    /// Execute with the privileges of the calling user.
+    pub fn security_invoker(mut self) -> Self {
+        self.security_definer = Some(false);
+        self
+    }
+
+    /// Set the parallel safety of the function.
+    pub fn parallel(mut self, parallel: Parallel) -> Self {
+        self.parallel = Some(parallel);
+        self
+    }
+
+    /// Set a configuration parameter while the function runs.
+    pub fn set<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: IntoIden,
+        V: Into<String>,
+    {
+        self.settings.push(RoutineSetting::literal(name, value));
+        self
+    }
+
+    /// Set the search path while the function runs.
+    pub fn set_search_path<T, I>(mut self, schemas: I) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.settings.push(RoutineSetting::search_path(schemas));
+        self
+    }
+
+    /// Keep the session value of a configuration parameter while the function runs.
+    pub fn set_from_current<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.settings.push(RoutineSetting::from_current(name));
+        self
+    }
+
+    /// Set the function body, written as a dollar-quoted string.
+    pub fn body<B>(mut self, body: B) -> Self
+    where
+        B: Into<String>,
+    {
+        self.body = Some(body.into());
+        self
+    }
+}
+
+/// An argument of a function or procedure.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FunctionArg {
+    mode: Option<ArgMode>,
+    name: Option<Iden>,
+    ty: ColumnType,
+    default: Option<Expr>,
+}
+
+impl FunctionArg {
+    /// Create a named argument of the given type.
+    pub fn new<N, T>(name: N, ty: T) -> Self
+    where
+        N: IntoIden,
+        T: Into<ColumnType>,
+    {
+        Self {
+            mode: None,
+            name: Some(name.into_iden()),
+            ty: ty.into(),
+            default: None,
+        }
+    }
+
+    /// Create an unnamed argument of the given type.
+    pub fn unnamed<T>(ty: T) -> Self
+    where
+        T: Into<ColumnType>,
+    {
+        Self {
+            mode: None,
+            name: None,
+            ty: ty.into(),
+            default: None,
+        }
+    }
+
+    /// Set the argument mode.
+    pub fn mode(mut self, mode: ArgMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Set the default value of the argument.
+    pub fn default<E>(mut self, expr: E) -> Self
+    where
+        E: Into<Expr>,
+    {
+        self.default = Some(expr.into());
+        self
+    }
+}
+
+/// Argument modes of a function or procedure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+#[expect(missing_docs)]
+pub enum ArgMode {
+    In,
+    Out,
+    InOut,
+    Variadic,
+}
+
+/// Implementation language of a function, procedure or DO block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Language {
+    /// SQL language.
+    Sql,
+    /// PL/pgSQL language.
+    PlPgSql,
+    /// Custom language.
+    Custom(Cow<'static, str>),
+}
+
+impl Language {
+    /// Create a custom language.
+    pub fn custom<T>(language: T) -> Self
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        Self::Custom(language.into())
+    }
+}
+
+impl From<&'static str> for Language {
+    fn from(value: &'static str) -> Self {
+        Language::Custom(Cow::Borrowed(value))
+    }
+}
+
+impl From<String> for Language {
+    fn from(value: String) -> Self {
+        Language::Custom(Cow::Owned(value))
+    }
+}
+
+/// Function volatility categories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+#[expect(missing_docs)]
+pub enum Volatility {
+    Immutable,
+    Stable,
+    Volatile,
+}
+
+/// Function parallel safety levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+#[expect(missing_docs)]
+pub enum Parallel {
+    Unsafe,
+    Restricted,
+    Safe,
+}
+
+fn write_create_function<W: SqlWriter>(w: &mut W, create: &CreateFunction) {
+    w.push_str("CREATE ");
+    if create.or_replace {
+        w.push_str("OR REPLACE ");
+    }
+    w.push_str("FUNCTION ");
+    if let Some(name) = &create.name {
+        write_table_name(w, name);
+    }
+    write_function_args(w, &create.args);
+
+    if let Some(returns) = &create.returns {
+        w.push_str(" RETURNS ");
+        match returns {
+            FunctionReturns::Type(ty) => write_column_type(w, ty),
+            FunctionReturns::SetOf(ty) => {
+                w.push_str("SETOF ");
+                write_column_type(w, ty);
+            }
+            FunctionReturns::Table(columns) => {
+                w.push_str("TABLE (");
+                for (i, (name, ty)) in columns.iter().enumerate() {
+                    if i > 0 {
+                        w.push_str(", ");
+                    }
+                    write_iden(w, name);
+                    w.push_char(' ');
+                    write_column_type(w, ty);
+                }
+                w.push_char(')');
+            }
+            FunctionReturns::Void => w.push_str("void"),
+            FunctionReturns::Trigger => w.push_str("trigger"),
+        }
+    }
+
+    if let Some(language) = &create.language {
+        w.push_str(" LANGUAGE ");
+        write_language(w, language);
+    }
+    if let Some(volatility) = create.volatility {
+        w.push_str(match volatility {
+            Volatility::Immutable => " IMMUTABLE",
+            Volatility::Stable => " STABLE",
+            Volatility::Volatile => " VOLATILE",
+        });
+    }
+    if create.strict {
+        w.push_str(" STRICT");
+    }
+    write_security(w, create.security_definer);
+    if let Some(parallel) = create.parallel {
+        w.push_str(match parallel {
+            Parallel::Unsafe => " PARALLEL UNSAFE",
+            Parallel::Restricted => " PARALLEL RESTRICTED",
+            Parallel::Safe => " PARALLEL SAFE",
+        });
+    }
+    write_routine_settings(w, &create.settings);
+    write_routine_body(w, create.body.as_deref());
+}
+
+pub(crate) fn write_function_args<W: SqlWriter>(w: &mut W, args: &[FunctionArg]) {
+    w.push_char('(');
+    for (i, arg) in args.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        if let Some(mode) = arg.mode {
+            w.push_str(match mode {
+                ArgMode::In => "IN ",
+                ArgMode::Out => "OUT ",
+                ArgMode::InOut => "INOUT ",
+                ArgMode::Variadic => "VARIADIC ",
+            });
+        }
+        if let Some(name) = &arg.name {
+            write_iden(w, name);
+            w.push_char(' ');
+        }
+        write_column_type(w, &arg.ty);
+        if let Some(default) = &arg.default {
+            w.push_str(" DEFAULT ");
+            write_expr(w, default);
+        }
+    }
+    w.push_char(')');
+}
+
+pub(crate) fn write_language<W: SqlWriter>(w: &mut W, language: &Language) {
+    match language {
+        Language::Sql => w.push_str("sql"),
+        Language::PlPgSql => w.push_str("plpgsql"),
+        Language::Custom(name) => w.push_str(name),
+    }
+}
+
+pub(crate) fn write_security<W: SqlWriter>(w: &mut W, security_definer: Option<bool>) {
+    if let Some(definer) = security_definer {
+        w.push_str(if definer {
+            " SECURITY DEFINER"
+        } else {
+            " SECURITY INVOKER"
+        });
+    }
+}
+
+pub(crate) fn write_routine_settings<W: SqlWriter>(w: &mut W, settings: &[RoutineSetting]) {
+    for setting in settings {
+        w.push_str(" SET ");
+        write_iden(w, &setting.name);
+        match &setting.value {
+            RoutineSettingValue::Literal(value) => {
+                w.push_str(" = ");
+                write_string_value(w, value);
+            }
+            RoutineSettingValue::Identifiers(idens) => {
+                w.push_str(" = ");
+                for (i, iden) in idens.iter().enumerate() {
+                    if i > 0 {
+                        w.push_str(", ");
+                    }
+                    write_iden(w, iden);
+                }
+            }
+            RoutineSettingValue::FromCurrent => w.push_str(" FROM CURRENT"),
+        }
+    }
+}
+
+pub(crate) fn write_routine_body<W: SqlWriter>(w: &mut W, body: Option<&str>) {
+    if let Some(body) = body {
+        w.push_str(" AS ");
+        write_dollar_quoted(w, body);
+    }
+}
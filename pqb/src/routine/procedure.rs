@@ -0,0 +1,169 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::routine::FunctionArg;
+use crate::routine::Language;
+use crate::routine::RoutineSetting;
+use crate::routine::write_function_args;
+use crate::routine::write_language;
+use crate::routine::write_routine_body;
+use crate::routine::write_routine_settings;
+use crate::routine::write_security;
+use crate::types::IntoIden;
+use crate::types::TableName;
+use crate::types::write_table_name;
+use crate::writer::SqlWriter;
+
+/// CREATE PROCEDURE statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateProcedure {
+    name: Option<TableName>,
+    or_replace: bool,
+    args: Vec<FunctionArg>,
+    language: Option<Language>,
+    security_definer: Option<bool>,
+    settings: Vec<RoutineSetting>,
+    body: Option<String>,
+}
+
+impl CreateProcedure {
+    /// Create a new CREATE PROCEDURE statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_create_procedure(&mut w, self);
+        w
+    }
+
+    /// Convert the CREATE PROCEDURE statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_create_procedure(&mut sql, self);
+        sql
+    }
+
+    /// Set the procedure name.
+    pub fn name<T>(mut self, name: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Replace the procedure if it already exists.
+    pub fn or_replace(mut self) -> Self {
+        self.or_replace = true;
+        self
+    }
+
+    /// Add a procedure argument.
+    pub fn arg(mut self, arg: FunctionArg) -> Self {
+        self.args.push(arg);
+        self
+    }
+
+    /// Add procedure arguments.
+    pub fn args<I>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = FunctionArg>,
+    {
+        self.args.extend(args);
+        self
+    }
+
+    /// Set the implementation language.
+    pub fn language<L>(mut self, language: L) -> Self
+    where
+        L: Into<Language>,
+    {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Execute with the privileges of the procedure owner.
+    pub fn security_definer(mut self) -> Self {
+        self.security_definer = Some(true);
+        self
+    }
+
+    /// Execute with the privileges of the calling user.
+    pub fn security_invoker(mut self) -> Self {
+        self.security_definer = Some(false);
+        self
+    }
+
+    /// Set a configuration parameter while the procedure runs.
+    pub fn set<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: IntoIden,
+        V: Into<String>,
+    {
+        self.settings.push(RoutineSetting::literal(name, value));
+        self
+    }
+
+    /// Set the search path while the procedure runs.
+    pub fn set_search_path<T, I>(mut self, schemas: I) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.settings.push(RoutineSetting::search_path(schemas));
+        self
+    }
+
+    /// Keep the session value of a configuration parameter while the procedure runs.
+    pub fn set_from_current<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.settings.push(RoutineSetting::from_current(name));
+        self
+    }
+
+    /// Set the procedure body, written as a dollar-quoted string.
+    pub fn body<B>(mut self, body: B) -> Self
+    where
+        B: Into<String>,
+    {
+        self.body = Some(body.into());
+        self
+    }
+}
+
+fn write_create_procedure<W: SqlWriter>(w: &mut W, create: &CreateProcedure) {
+    w.push_str("CREATE ");
+    if create.or_replace {
+        w.push_str("OR REPLACE ");
+    }
+    w.push_str("PROCEDURE ");
+    if let Some(name) = &create.name {
+        write_table_name(w, name);
+    }
+    write_function_args(w, &create.args);
+    if let Some(language) = &create.language {
+        w.push_str(" LANGUAGE ");
+        write_language(w, language);
+    }
+    write_security(w, create.security_definer);
+    write_routine_settings(w, &create.settings);
+    write_routine_body(w, create.body.as_deref());
+}
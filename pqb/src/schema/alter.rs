@@ -0,0 +1,102 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::SchemaName;
+use crate::types::write_iden;
+use crate::types::write_schema_name;
+use crate::writer::SqlWriter;
+
+/// ALTER SCHEMA statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlterSchema {
+    schema: Option<SchemaName>,
+    action: Option<SchemaAlterAction>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum SchemaAlterAction {
+    RenameTo(Iden),
+    OwnerTo(Iden),
+}
+
+impl AlterSchema {
+    /// Create a new ALTER SCHEMA statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_alter_schema(&mut w, self);
+        w
+    }
+
+    /// Convert the ALTER SCHEMA statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_alter_schema(&mut sql, self);
+        sql
+    }
+
+    /// Set the schema name.
+    pub fn schema<S>(mut self, schema: S) -> Self
+    where
+        S: Into<SchemaName>,
+    {
+        self.schema = Some(schema.into());
+        self
+    }
+
+    /// Rename the schema.
+    pub fn rename_to<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.action = Some(SchemaAlterAction::RenameTo(name.into_iden()));
+        self
+    }
+
+    /// Change the owner of the schema.
+    pub fn owner_to<R>(mut self, role: R) -> Self
+    where
+        R: IntoIden,
+    {
+        self.action = Some(SchemaAlterAction::OwnerTo(role.into_iden()));
+        self
+    }
+}
+
+fn write_alter_schema<W: SqlWriter>(w: &mut W, alter: &AlterSchema) {
+    w.push_str("ALTER SCHEMA ");
+    if let Some(schema) = &alter.schema {
+        write_schema_name(w, schema);
+    }
+    match &alter.action {
+        Some(SchemaAlterAction::RenameTo(name)) => {
+            w.push_str(" RENAME TO ");
+            write_iden(w, name);
+        }
+        Some(SchemaAlterAction::OwnerTo(role)) => {
+            w.push_str(" OWNER TO ");
+            write_iden(w, role);
+        }
+        None => {}
+    }
+}
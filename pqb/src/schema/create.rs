@@ -0,0 +1,139 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::index::CreateIndex;
+use crate::index::write_create_index;
+use crate::table::CreateTable;
+use crate::table::write_create_table;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::SchemaName;
+use crate::types::write_iden;
+use crate::types::write_schema_name;
+use crate::writer::SqlWriter;
+
+/// CREATE SCHEMA statement builder.
+///
+/// PostgreSQL does not allow `IF NOT EXISTS` together with schema elements.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateSchema {
+    schema: Option<SchemaName>,
+    if_not_exists: bool,
+    authorization: Option<Iden>,
+    elements: Vec<SchemaElement>,
+}
+
+/// A statement embedded in a CREATE SCHEMA statement.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+#[expect(missing_docs)]
+pub enum SchemaElement {
+    CreateTable(CreateTable),
+    CreateIndex(CreateIndex),
+}
+
+impl From<CreateTable> for SchemaElement {
+    fn from(table: CreateTable) -> Self {
+        SchemaElement::CreateTable(table)
+    }
+}
+
+impl From<CreateIndex> for SchemaElement {
+    fn from(index: CreateIndex) -> Self {
+        SchemaElement::CreateIndex(index)
+    }
+}
+
+impl CreateSchema {
+    /// Create a new CREATE SCHEMA statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_create_schema(&mut w, self);
+        w
+    }
+
+    /// Convert the CREATE SCHEMA statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_create_schema(&mut sql, self);
+        sql
+    }
+
+    /// Set the schema name.
+    ///
+    /// If omitted, the schema is named after the [`authorization`](Self::authorization) role.
+    pub fn schema<S>(mut self, schema: S) -> Self
+    where
+        S: Into<SchemaName>,
+    {
+        self.schema = Some(schema.into());
+        self
+    }
+
+    /// Create the schema if it does not exist.
+    pub fn if_not_exists(mut self) -> Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Set the role that will own the schema.
+    pub fn authorization<R>(mut self, role: R) -> Self
+    where
+        R: IntoIden,
+    {
+        self.authorization = Some(role.into_iden());
+        self
+    }
+
+    /// Add a statement to be created within the schema.
+    pub fn element<E>(mut self, element: E) -> Self
+    where
+        E: Into<SchemaElement>,
+    {
+        self.elements.push(element.into());
+        self
+    }
+}
+
+fn write_create_schema<W: SqlWriter>(w: &mut W, create: &CreateSchema) {
+    w.push_str("CREATE SCHEMA ");
+    if create.if_not_exists {
+        w.push_str("IF NOT EXISTS ");
+    }
+    if let Some(schema) = &create.schema {
+        write_schema_name(w, schema);
+        if create.authorization.is_some() {
+            w.push_char(' ');
+        }
+    }
+    if let Some(role) = &create.authorization {
+        w.push_str("AUTHORIZATION ");
+        write_iden(w, role);
+    }
+    for element in &create.elements {
+        w.push_char(' ');
+        match element {
+            SchemaElement::CreateTable(table) => write_create_table(w, table),
+            SchemaElement::CreateIndex(index) => write_create_index(w, index),
+        }
+    }
+}
@@ -20,6 +20,7 @@ use crate::writer::SqlWriter;
 
 /// DROP SCHEMA statement builder.
 #[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DropSchema {
     schemas: Vec<SchemaName>,
     if_exists: bool,
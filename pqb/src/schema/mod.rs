@@ -14,6 +14,10 @@
 
 //! Schema definition statements.
 
+mod alter;
+mod create;
 mod drop;
 
+pub use self::alter::*;
+pub use self::create::*;
 pub use self::drop::*;
@@ -0,0 +1,103 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::SqlWriterValues;
+use crate::sequence::SequenceOptions;
+use crate::sequence::write_sequence_options;
+use crate::types::TableName;
+use crate::types::write_table_name;
+use crate::writer::SqlWriter;
+
+/// ALTER SEQUENCE statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlterSequence {
+    sequence: Option<TableName>,
+    if_exists: bool,
+    options: SequenceOptions,
+    restart: Option<Option<i64>>,
+}
+
+impl AlterSequence {
+    /// Create a new ALTER SEQUENCE statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_alter_sequence(&mut w, self);
+        w
+    }
+
+    /// Convert the ALTER SEQUENCE statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_alter_sequence(&mut sql, self);
+        sql
+    }
+
+    /// Set the sequence name.
+    pub fn sequence<T>(mut self, sequence: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.sequence = Some(sequence.into());
+        self
+    }
+
+    /// Do not throw an error if the sequence does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Set the sequence parameters to change.
+    pub fn options(mut self, options: SequenceOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Restart the sequence at its recorded start value.
+    pub fn restart(mut self) -> Self {
+        self.restart = Some(None);
+        self
+    }
+
+    /// Restart the sequence at the given value.
+    pub fn restart_with(mut self, value: i64) -> Self {
+        self.restart = Some(Some(value));
+        self
+    }
+}
+
+fn write_alter_sequence<W: SqlWriter>(w: &mut W, alter: &AlterSequence) {
+    w.push_str("ALTER SEQUENCE ");
+    if alter.if_exists {
+        w.push_str("IF EXISTS ");
+    }
+    if let Some(sequence) = &alter.sequence {
+        write_table_name(w, sequence);
+    }
+    if !alter.options.is_empty() {
+        w.push_char(' ');
+        write_sequence_options(w, &alter.options, "");
+    }
+    if let Some(restart) = alter.restart {
+        w.push_str(" RESTART");
+        if let Some(value) = restart {
+            w.push_fmt(format_args!(" WITH {value}"));
+        }
+    }
+}
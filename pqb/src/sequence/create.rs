@@ -0,0 +1,95 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::SqlWriterValues;
+use crate::sequence::SequenceOptions;
+use crate::sequence::write_sequence_options;
+use crate::types::TableName;
+use crate::types::write_table_name;
+use crate::writer::SqlWriter;
+
+/// CREATE SEQUENCE statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateSequence {
+    sequence: Option<TableName>,
+    temporary: bool,
+    if_not_exists: bool,
+    options: SequenceOptions,
+}
+
+impl CreateSequence {
+    /// Create a new CREATE SEQUENCE statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_create_sequence(&mut w, self);
+        w
+    }
+
+    /// Convert the CREATE SEQUENCE statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_create_sequence(&mut sql, self);
+        sql
+    }
+
+    /// Set the sequence name.
+    pub fn sequence<T>(mut self, sequence: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.sequence = Some(sequence.into());
+        self
+    }
+
+    /// Create a temporary sequence.
+    pub fn temporary(mut self) -> Self {
+        self.temporary = true;
+        self
+    }
+
+    /// Create the sequence if it does not exist.
+    pub fn if_not_exists(mut self) -> Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Set the sequence parameters.
+    pub fn options(mut self, options: SequenceOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+fn write_create_sequence<W: SqlWriter>(w: &mut W, create: &CreateSequence) {
+    w.push_str("CREATE ");
+    if create.temporary {
+        w.push_str("TEMPORARY ");
+    }
+    w.push_str("SEQUENCE ");
+    if create.if_not_exists {
+        w.push_str("IF NOT EXISTS ");
+    }
+    if let Some(sequence) = &create.sequence {
+        write_table_name(w, sequence);
+    }
+    if !create.options.is_empty() {
+        w.push_char(' ');
+        write_sequence_options(w, &create.options, "");
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::types::DropBehavior;
+use crate::types::TableName;
+use crate::types::write_table_name;
+use crate::writer::SqlWriter;
+
+/// DROP SEQUENCE statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DropSequence {
+    sequences: Vec<TableName>,
+    if_exists: bool,
+    behavior: Option<DropBehavior>,
+}
+
+impl DropSequence {
+    /// Create a new DROP SEQUENCE statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_drop_sequence(&mut w, self);
+        w
+    }
+
+    /// Convert the DROP SEQUENCE statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_drop_sequence(&mut sql, self);
+        sql
+    }
+
+    /// Add a sequence name to drop.
+    pub fn sequence<T>(mut self, sequence: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.sequences.push(sequence.into());
+        self
+    }
+
+    /// Add multiple sequence names to drop.
+    pub fn sequences<I, T>(mut self, sequences: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<TableName>,
+    {
+        self.sequences.extend(sequences.into_iter().map(Into::into));
+        self
+    }
+
+    /// Drop the sequence if it exists.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Add CASCADE to drop dependent objects.
+    pub fn cascade(mut self) -> Self {
+        self.behavior = Some(DropBehavior::Cascade);
+        self
+    }
+
+    /// Add RESTRICT to drop (explicitly).
+    pub fn restrict(mut self) -> Self {
+        self.behavior = Some(DropBehavior::Restrict);
+        self
+    }
+}
+
+fn write_drop_sequence<W: SqlWriter>(w: &mut W, drop_sequence: &DropSequence) {
+    w.push_str("DROP SEQUENCE ");
+    if drop_sequence.if_exists {
+        w.push_str("IF EXISTS ");
+    }
+    for (i, sequence) in drop_sequence.sequences.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        write_table_name(w, sequence);
+    }
+    if let Some(behavior) = drop_sequence.behavior {
+        w.push_char(' ');
+        match behavior {
+            DropBehavior::Cascade => w.push_str("CASCADE"),
+            DropBehavior::Restrict => w.push_str("RESTRICT"),
+        }
+    }
+}
@@ -0,0 +1,201 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::table::ColumnType;
+use crate::table::write_column_type;
+use crate::types::ColumnName;
+use crate::types::write_iden;
+use crate::types::write_table_name;
+use crate::writer::SqlWriter;
+
+/// Sequence parameters shared by sequences and identity columns.
+///
+/// Parameters left unset are omitted, so the server default (or, in ALTER statements, the current
+/// setting) applies.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SequenceOptions {
+    data_type: Option<ColumnType>,
+    increment: Option<i64>,
+    min_value: Option<Option<i64>>,
+    max_value: Option<Option<i64>>,
+    start: Option<i64>,
+    cache: Option<i64>,
+    cycle: Option<bool>,
+    owned_by: Option<Option<ColumnName>>,
+}
+
+impl SequenceOptions {
+    /// Create an empty set of sequence parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the data type of the sequence, one of `smallint`, `integer` or `bigint`.
+    pub fn as_type(mut self, ty: ColumnType) -> Self {
+        self.data_type = Some(ty);
+        self
+    }
+
+    /// Set the value added to the current value to create a new value.
+    pub fn increment_by(mut self, increment: i64) -> Self {
+        self.increment = Some(increment);
+        self
+    }
+
+    /// Set the minimum value the sequence can generate.
+    pub fn min_value(mut self, value: i64) -> Self {
+        self.min_value = Some(Some(value));
+        self
+    }
+
+    /// Use the default minimum value of the data type (NO MINVALUE).
+    pub fn no_min_value(mut self) -> Self {
+        self.min_value = Some(None);
+        self
+    }
+
+    /// Set the maximum value the sequence can generate.
+    pub fn max_value(mut self, value: i64) -> Self {
+        self.max_value = Some(Some(value));
+        self
+    }
+
+    /// Use the default maximum value of the data type (NO MAXVALUE).
+    pub fn no_max_value(mut self) -> Self {
+        self.max_value = Some(None);
+        self
+    }
+
+    /// Set the starting value of the sequence.
+    pub fn start_with(mut self, start: i64) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Set how many sequence numbers are preallocated and stored in memory.
+    pub fn cache(mut self, cache: i64) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Wrap around when the sequence reaches its limit.
+    pub fn cycle(mut self) -> Self {
+        self.cycle = Some(true);
+        self
+    }
+
+    /// Fail when the sequence reaches its limit.
+    pub fn no_cycle(mut self) -> Self {
+        self.cycle = Some(false);
+        self
+    }
+
+    /// Associate the sequence with a table column, so that it is dropped with the column.
+    pub fn owned_by<C>(mut self, column: C) -> Self
+    where
+        C: Into<ColumnName>,
+    {
+        self.owned_by = Some(Some(column.into()));
+        self
+    }
+
+    /// Remove any existing column association (OWNED BY NONE).
+    pub fn owned_by_none(mut self) -> Self {
+        self.owned_by = Some(None);
+        self
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.data_type.is_none()
+            && self.increment.is_none()
+            && self.min_value.is_none()
+            && self.max_value.is_none()
+            && self.start.is_none()
+            && self.cache.is_none()
+            && self.cycle.is_none()
+            && self.owned_by.is_none()
+    }
+}
+
+/// Write the sequence parameters separated by spaces, each preceded by `prefix`.
+pub(crate) fn write_sequence_options<W: SqlWriter>(
+    w: &mut W,
+    options: &SequenceOptions,
+    prefix: &str,
+) {
+    let mut is_first = true;
+
+    macro_rules! write_space_if_not_first {
+        () => {
+            if is_first {
+                is_first = false
+            } else {
+                w.push_char(' ');
+            }
+            w.push_str(prefix);
+        };
+    }
+
+    if let Some(ty) = &options.data_type {
+        write_space_if_not_first!();
+        w.push_str("AS ");
+        write_column_type(w, ty);
+    }
+    if let Some(increment) = options.increment {
+        write_space_if_not_first!();
+        w.push_fmt(format_args!("INCREMENT BY {increment}"));
+    }
+    if let Some(min_value) = options.min_value {
+        write_space_if_not_first!();
+        match min_value {
+            Some(value) => w.push_fmt(format_args!("MINVALUE {value}")),
+            None => w.push_str("NO MINVALUE"),
+        }
+    }
+    if let Some(max_value) = options.max_value {
+        write_space_if_not_first!();
+        match max_value {
+            Some(value) => w.push_fmt(format_args!("MAXVALUE {value}")),
+            None => w.push_str("NO MAXVALUE"),
+        }
+    }
+    if let Some(start) = options.start {
+        write_space_if_not_first!();
+        w.push_fmt(format_args!("START WITH {start}"));
+    }
+    if let Some(cache) = options.cache {
+        write_space_if_not_first!();
+        w.push_fmt(format_args!("CACHE {cache}"));
+    }
+    if let Some(cycle) = options.cycle {
+        write_space_if_not_first!();
+        w.push_str(if cycle { "CYCLE" } else { "NO CYCLE" });
+    }
+    if let Some(owned_by) = &options.owned_by {
+        write_space_if_not_first!();
+        w.push_str("OWNED BY ");
+        match owned_by {
+            Some(ColumnName(table, column)) => {
+                if let Some(table) = table {
+                    write_table_name(w, table);
+                    w.push_char('.');
+                }
+                write_iden(w, column);
+            }
+            None => w.push_str("NONE"),
+        }
+    }
+
+    let _ = is_first;
+}
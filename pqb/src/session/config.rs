@@ -0,0 +1,195 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::write_iden;
+use crate::value::write_string_value;
+use crate::writer::SqlWriter;
+
+/// SET statement builder for configuration parameters.
+///
+/// Values are written as string literals, since SET does not accept bind parameters. Without
+/// values, the parameter is set to its default value.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Set {
+    local: bool,
+    name: Option<Iden>,
+    values: Vec<String>,
+}
+
+impl Set {
+    /// Create a new SET statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_set(&mut w, self);
+        w
+    }
+
+    /// Convert the SET statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_set(&mut sql, self);
+        sql
+    }
+
+    /// Set the configuration parameter name.
+    pub fn name<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Add a value.
+    pub fn value<V>(mut self, value: V) -> Self
+    where
+        V: Into<String>,
+    {
+        self.values.push(value.into());
+        self
+    }
+
+    /// Add multiple values, for list parameters such as `search_path`.
+    pub fn values<V, I>(mut self, values: I) -> Self
+    where
+        V: Into<String>,
+        I: IntoIterator<Item = V>,
+    {
+        self.values.extend(values.into_iter().map(|v| v.into()));
+        self
+    }
+
+    /// Only change the parameter until the end of the current transaction.
+    pub fn local(mut self) -> Self {
+        self.local = true;
+        self
+    }
+}
+
+fn write_set<W: SqlWriter>(w: &mut W, set: &Set) {
+    w.push_str("SET ");
+    if set.local {
+        w.push_str("LOCAL ");
+    }
+    if let Some(name) = &set.name {
+        write_iden(w, name);
+    }
+    if set.values.is_empty() {
+        w.push_str(" TO DEFAULT");
+        return;
+    }
+    w.push_str(" = ");
+    for (i, value) in set.values.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        write_string_value(w, value);
+    }
+}
+
+/// RESET statement builder.
+///
+/// Without a name, all configuration parameters are reset.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Reset {
+    name: Option<Iden>,
+}
+
+impl Reset {
+    /// Create a new RESET statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_parameter_command(&mut w, "RESET", self.name.as_ref());
+        w
+    }
+
+    /// Convert the RESET statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_parameter_command(&mut sql, "RESET", self.name.as_ref());
+        sql
+    }
+
+    /// Set the configuration parameter name.
+    pub fn name<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+}
+
+/// SHOW statement builder.
+///
+/// Without a name, all configuration parameters are shown.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Show {
+    name: Option<Iden>,
+}
+
+impl Show {
+    /// Create a new SHOW statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_parameter_command(&mut w, "SHOW", self.name.as_ref());
+        w
+    }
+
+    /// Convert the SHOW statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_parameter_command(&mut sql, "SHOW", self.name.as_ref());
+        sql
+    }
+
+    /// Set the configuration parameter name.
+    pub fn name<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+}
+
+fn write_parameter_command<W: SqlWriter>(w: &mut W, command: &str, name: Option<&Iden>) {
+    w.push_str(command);
+    w.push_char(' ');
+    match name {
+        Some(name) => write_iden(w, name),
+        None => w.push_str("ALL"),
+    }
+}
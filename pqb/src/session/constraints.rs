@@ -0,0 +1,100 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::write_iden;
+use crate::writer::SqlWriter;
+
+/// SET CONSTRAINTS statement builder.
+///
+/// Without constraints, the statement applies to all deferrable constraints.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetConstraints {
+    constraints: Vec<Iden>,
+    deferred: bool,
+}
+
+impl SetConstraints {
+    /// Create a new SET CONSTRAINTS statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_set_constraints(&mut w, self);
+        w
+    }
+
+    /// Convert the SET CONSTRAINTS statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_set_constraints(&mut sql, self);
+        sql
+    }
+
+    /// Add a constraint to set.
+    pub fn constraint<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.constraints.push(name.into_iden());
+        self
+    }
+
+    /// Add multiple constraints to set.
+    pub fn constraints<T, I>(mut self, names: I) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.constraints
+            .extend(names.into_iter().map(|n| n.into_iden()));
+        self
+    }
+
+    /// Check the constraints at the end of the transaction.
+    pub fn deferred(mut self) -> Self {
+        self.deferred = true;
+        self
+    }
+
+    /// Check the constraints at the end of each statement (default).
+    pub fn immediate(mut self) -> Self {
+        self.deferred = false;
+        self
+    }
+}
+
+fn write_set_constraints<W: SqlWriter>(w: &mut W, set: &SetConstraints) {
+    w.push_str("SET CONSTRAINTS ");
+    if set.constraints.is_empty() {
+        w.push_str("ALL");
+    }
+    for (i, constraint) in set.constraints.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        write_iden(w, constraint);
+    }
+    w.push_str(if set.deferred {
+        " DEFERRED"
+    } else {
+        " IMMEDIATE"
+    });
+}
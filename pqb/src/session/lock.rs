@@ -0,0 +1,134 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::types::TableName;
+use crate::types::write_table_name;
+use crate::writer::SqlWriter;
+
+/// Table lock modes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+#[expect(missing_docs)]
+pub enum LockMode {
+    AccessShare,
+    RowShare,
+    RowExclusive,
+    ShareUpdateExclusive,
+    Share,
+    ShareRowExclusive,
+    Exclusive,
+    AccessExclusive,
+}
+
+/// LOCK TABLE statement builder.
+///
+/// Without a lock mode, tables are locked in ACCESS EXCLUSIVE mode.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LockTable {
+    tables: Vec<TableName>,
+    only: bool,
+    mode: Option<LockMode>,
+    nowait: bool,
+}
+
+impl LockTable {
+    /// Create a new LOCK TABLE statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_lock_table(&mut w, self);
+        w
+    }
+
+    /// Convert the LOCK TABLE statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_lock_table(&mut sql, self);
+        sql
+    }
+
+    /// Add a table to lock.
+    pub fn table<T>(mut self, table: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.tables.push(table.into());
+        self
+    }
+
+    /// Add multiple tables to lock.
+    pub fn tables<T, I>(mut self, tables: I) -> Self
+    where
+        T: Into<TableName>,
+        I: IntoIterator<Item = T>,
+    {
+        self.tables.extend(tables.into_iter().map(Into::into));
+        self
+    }
+
+    /// Lock only the named tables, not their descendant tables.
+    pub fn only(mut self) -> Self {
+        self.only = true;
+        self
+    }
+
+    /// Set the lock mode.
+    pub fn mode(mut self, mode: LockMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Fail instead of waiting if the lock cannot be acquired immediately.
+    pub fn nowait(mut self) -> Self {
+        self.nowait = true;
+        self
+    }
+}
+
+fn write_lock_table<W: SqlWriter>(w: &mut W, lock: &LockTable) {
+    w.push_str("LOCK TABLE ");
+    for (i, table) in lock.tables.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        if lock.only {
+            w.push_str("ONLY ");
+        }
+        write_table_name(w, table);
+    }
+    if let Some(mode) = lock.mode {
+        w.push_str(" IN ");
+        w.push_str(match mode {
+            LockMode::AccessShare => "ACCESS SHARE",
+            LockMode::RowShare => "ROW SHARE",
+            LockMode::RowExclusive => "ROW EXCLUSIVE",
+            LockMode::ShareUpdateExclusive => "SHARE UPDATE EXCLUSIVE",
+            LockMode::Share => "SHARE",
+            LockMode::ShareRowExclusive => "SHARE ROW EXCLUSIVE",
+            LockMode::Exclusive => "EXCLUSIVE",
+            LockMode::AccessExclusive => "ACCESS EXCLUSIVE",
+        });
+        w.push_str(" MODE");
+    }
+    if lock.nowait {
+        w.push_str(" NOWAIT");
+    }
+}
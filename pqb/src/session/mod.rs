@@ -0,0 +1,29 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Transaction control, locking, session configuration and notification statements.
+
+mod config;
+mod constraints;
+mod lock;
+mod notify;
+mod savepoint;
+mod transaction;
+
+pub use self::config::*;
+pub use self::constraints::*;
+pub use self::lock::*;
+pub use self::notify::*;
+pub use self::savepoint::*;
+pub use self::transaction::*;
@@ -0,0 +1,177 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::write_iden;
+use crate::value::Value;
+use crate::value::write_value;
+use crate::writer::SqlWriter;
+
+/// LISTEN statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Listen {
+    channel: Option<Iden>,
+}
+
+impl Listen {
+    /// Create a new LISTEN statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_listen(&mut w, self);
+        w
+    }
+
+    /// Convert the LISTEN statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_listen(&mut sql, self);
+        sql
+    }
+
+    /// Set the channel to listen on.
+    pub fn channel<N>(mut self, channel: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.channel = Some(channel.into_iden());
+        self
+    }
+}
+
+fn write_listen<W: SqlWriter>(w: &mut W, listen: &Listen) {
+    w.push_str("LISTEN");
+    if let Some(channel) = &listen.channel {
+        w.push_char(' ');
+        write_iden(w, channel);
+    }
+}
+
+/// UNLISTEN statement builder.
+///
+/// Without a channel, the session stops listening on all channels.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Unlisten {
+    channel: Option<Iden>,
+}
+
+impl Unlisten {
+    /// Create a new UNLISTEN statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_unlisten(&mut w, self);
+        w
+    }
+
+    /// Convert the UNLISTEN statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_unlisten(&mut sql, self);
+        sql
+    }
+
+    /// Set the channel to stop listening on.
+    pub fn channel<N>(mut self, channel: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.channel = Some(channel.into_iden());
+        self
+    }
+}
+
+fn write_unlisten<W: SqlWriter>(w: &mut W, unlisten: &Unlisten) {
+    w.push_str("UNLISTEN ");
+    match &unlisten.channel {
+        Some(channel) => write_iden(w, channel),
+        None => w.push_char('*'),
+    }
+}
+
+/// NOTIFY statement builder.
+///
+/// NOTIFY does not accept bind parameters, so the payload is always written as an escaped string
+/// literal. Use [`FunctionCall::pg_notify`] to send a payload computed by an expression.
+///
+/// [`FunctionCall::pg_notify`]: crate::func::FunctionCall::pg_notify
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Notify {
+    channel: Option<Iden>,
+    payload: Option<String>,
+}
+
+impl Notify {
+    /// Create a new NOTIFY statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_notify(&mut w, self);
+        w
+    }
+
+    /// Convert the NOTIFY statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_notify(&mut sql, self);
+        sql
+    }
+
+    /// Set the channel to notify.
+    pub fn channel<N>(mut self, channel: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.channel = Some(channel.into_iden());
+        self
+    }
+
+    /// Set the payload of the notification.
+    pub fn payload<S>(mut self, payload: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.payload = Some(payload.into());
+        self
+    }
+}
+
+fn write_notify<W: SqlWriter>(w: &mut W, notify: &Notify) {
+    w.push_str("NOTIFY");
+    if let Some(channel) = &notify.channel {
+        w.push_char(' ');
+        write_iden(w, channel);
+    }
+    if let Some(payload) = &notify.payload {
+        w.push_str(", ");
+        write_value(w, &Value::from(payload));
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::write_iden;
+use crate::writer::SqlWriter;
+
+/// SAVEPOINT statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Savepoint {
+    name: Option<Iden>,
+}
+
+impl Savepoint {
+    /// Create a new SAVEPOINT statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_savepoint(&mut w, "SAVEPOINT", self.name.as_ref());
+        w
+    }
+
+    /// Convert the SAVEPOINT statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_savepoint(&mut sql, "SAVEPOINT", self.name.as_ref());
+        sql
+    }
+
+    /// Set the savepoint name.
+    pub fn name<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+}
+
+/// RELEASE SAVEPOINT statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReleaseSavepoint {
+    name: Option<Iden>,
+}
+
+impl ReleaseSavepoint {
+    /// Create a new RELEASE SAVEPOINT statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_savepoint(&mut w, "RELEASE SAVEPOINT", self.name.as_ref());
+        w
+    }
+
+    /// Convert the RELEASE SAVEPOINT statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_savepoint(&mut sql, "RELEASE SAVEPOINT", self.name.as_ref());
+        sql
+    }
+
+    /// Set the name of the savepoint to release.
+    pub fn name<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+}
+
+/// ROLLBACK TO SAVEPOINT statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollbackToSavepoint {
+    name: Option<Iden>,
+}
+
+impl RollbackToSavepoint {
+    /// Create a new ROLLBACK TO SAVEPOINT statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_savepoint(&mut w, "ROLLBACK TO SAVEPOINT", self.name.as_ref());
+        w
+    }
+
+    /// Convert the ROLLBACK TO SAVEPOINT statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_savepoint(&mut sql, "ROLLBACK TO SAVEPOINT", self.name.as_ref());
+        sql
+    }
+
+    /// Set the name of the savepoint to roll back to.
+    pub fn name<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+}
+
+fn write_savepoint<W: SqlWriter>(w: &mut W, command: &str, name: Option<&Iden>) {
+    w.push_str(command);
+    if let Some(name) = name {
+        w.push_char(' ');
+        write_iden(w, name);
+    }
+}
@@ -0,0 +1,325 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::value::write_string_value;
+use crate::writer::SqlWriter;
+
+/// Transaction isolation levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+#[expect(missing_docs)]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+/// Transaction modes shared by BEGIN and SET TRANSACTION.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct TransactionModes {
+    isolation_level: Option<IsolationLevel>,
+    read_only: Option<bool>,
+    deferrable: Option<bool>,
+}
+
+/// BEGIN statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Begin {
+    modes: TransactionModes,
+}
+
+impl Begin {
+    /// Create a new BEGIN statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_begin(&mut w, self);
+        w
+    }
+
+    /// Convert the BEGIN statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_begin(&mut sql, self);
+        sql
+    }
+
+    /// Set the isolation level of the transaction.
+    pub fn isolation_level(mut self, level: IsolationLevel) -> Self {
+        self.modes.isolation_level = Some(level);
+        self
+    }
+
+    /// Start a READ ONLY transaction.
+    pub fn read_only(mut self) -> Self {
+        self.modes.read_only = Some(true);
+        self
+    }
+
+    /// Start a READ WRITE transaction.
+    pub fn read_write(mut self) -> Self {
+        self.modes.read_only = Some(false);
+        self
+    }
+
+    /// Start a DEFERRABLE transaction.
+    pub fn deferrable(mut self) -> Self {
+        self.modes.deferrable = Some(true);
+        self
+    }
+
+    /// Start a NOT DEFERRABLE transaction.
+    pub fn not_deferrable(mut self) -> Self {
+        self.modes.deferrable = Some(false);
+        self
+    }
+}
+
+fn write_begin<W: SqlWriter>(w: &mut W, begin: &Begin) {
+    w.push_str("BEGIN");
+    write_transaction_modes(w, &begin.modes);
+}
+
+/// COMMIT statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Commit {
+    and_chain: bool,
+}
+
+impl Commit {
+    /// Create a new COMMIT statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_commit(&mut w, self);
+        w
+    }
+
+    /// Convert the COMMIT statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_commit(&mut sql, self);
+        sql
+    }
+
+    /// Start a new transaction with the same characteristics right after committing.
+    pub fn and_chain(mut self) -> Self {
+        self.and_chain = true;
+        self
+    }
+}
+
+fn write_commit<W: SqlWriter>(w: &mut W, commit: &Commit) {
+    w.push_str("COMMIT");
+    if commit.and_chain {
+        w.push_str(" AND CHAIN");
+    }
+}
+
+/// ROLLBACK statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rollback {
+    and_chain: bool,
+}
+
+impl Rollback {
+    /// Create a new ROLLBACK statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_rollback(&mut w, self);
+        w
+    }
+
+    /// Convert the ROLLBACK statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_rollback(&mut sql, self);
+        sql
+    }
+
+    /// Start a new transaction with the same characteristics right after rolling back.
+    pub fn and_chain(mut self) -> Self {
+        self.and_chain = true;
+        self
+    }
+}
+
+fn write_rollback<W: SqlWriter>(w: &mut W, rollback: &Rollback) {
+    w.push_str("ROLLBACK");
+    if rollback.and_chain {
+        w.push_str(" AND CHAIN");
+    }
+}
+
+/// SET TRANSACTION statement builder.
+///
+/// Without a snapshot or transaction modes, the statement is invalid. With [`Self::session`], the
+/// modes become the defaults of the following transactions of the session.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetTransaction {
+    session: bool,
+    snapshot: Option<String>,
+    modes: TransactionModes,
+}
+
+impl SetTransaction {
+    /// Create a new SET TRANSACTION statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_set_transaction(&mut w, self);
+        w
+    }
+
+    /// Convert the SET TRANSACTION statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_set_transaction(&mut sql, self);
+        sql
+    }
+
+    /// Set the default modes of the session with SET SESSION CHARACTERISTICS AS TRANSACTION.
+    pub fn session(mut self) -> Self {
+        self.session = true;
+        self
+    }
+
+    /// Run the transaction with the snapshot exported by another transaction.
+    ///
+    /// ## Panics
+    /// Rendering the statement will panic if the snapshot is combined with [`Self::session`] or
+    /// with transaction modes.
+    pub fn snapshot<S>(mut self, snapshot_id: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.snapshot = Some(snapshot_id.into());
+        self
+    }
+
+    /// Set the isolation level of the transaction.
+    pub fn isolation_level(mut self, level: IsolationLevel) -> Self {
+        self.modes.isolation_level = Some(level);
+        self
+    }
+
+    /// Make the transaction READ ONLY.
+    pub fn read_only(mut self) -> Self {
+        self.modes.read_only = Some(true);
+        self
+    }
+
+    /// Make the transaction READ WRITE.
+    pub fn read_write(mut self) -> Self {
+        self.modes.read_only = Some(false);
+        self
+    }
+
+    /// Make the transaction DEFERRABLE.
+    pub fn deferrable(mut self) -> Self {
+        self.modes.deferrable = Some(true);
+        self
+    }
+
+    /// Make the transaction NOT DEFERRABLE.
+    pub fn not_deferrable(mut self) -> Self {
+        self.modes.deferrable = Some(false);
+        self
+    }
+}
+
+fn write_set_transaction<W: SqlWriter>(w: &mut W, set: &SetTransaction) {
+    if set.snapshot.is_some() {
+        if set.session {
+            panic!("SET SESSION CHARACTERISTICS cannot set a transaction snapshot.");
+        }
+        let modes = &set.modes;
+        if modes.isolation_level.is_some()
+            || modes.read_only.is_some()
+            || modes.deferrable.is_some()
+        {
+            panic!("A transaction snapshot cannot be combined with transaction modes.");
+        }
+    }
+    if set.session {
+        w.push_str("SET SESSION CHARACTERISTICS AS TRANSACTION");
+    } else {
+        w.push_str("SET TRANSACTION");
+    }
+    if let Some(snapshot) = &set.snapshot {
+        w.push_str(" SNAPSHOT ");
+        write_string_value(w, snapshot);
+    } else {
+        write_transaction_modes(w, &set.modes);
+    }
+}
+
+fn write_transaction_modes<W: SqlWriter>(w: &mut W, modes: &TransactionModes) {
+    let mut first = true;
+    let mut write_separator = |w: &mut W| {
+        if !first {
+            w.push_char(',');
+        }
+        first = false;
+        w.push_char(' ');
+    };
+
+    if let Some(level) = modes.isolation_level {
+        write_separator(w);
+        w.push_str("ISOLATION LEVEL ");
+        w.push_str(match level {
+            IsolationLevel::ReadUncommitted => "READ UNCOMMITTED",
+            IsolationLevel::ReadCommitted => "READ COMMITTED",
+            IsolationLevel::RepeatableRead => "REPEATABLE READ",
+            IsolationLevel::Serializable => "SERIALIZABLE",
+        });
+    }
+    if let Some(read_only) = modes.read_only {
+        write_separator(w);
+        w.push_str(if read_only { "READ ONLY" } else { "READ WRITE" });
+    }
+    if let Some(deferrable) = modes.deferrable {
+        write_separator(w);
+        w.push_str(if deferrable {
+            "DEFERRABLE"
+        } else {
+            "NOT DEFERRABLE"
+        });
+    }
+}
@@ -13,22 +13,40 @@
 // limitations under the License.
 
 use crate::SqlWriterValues;
+use crate::expr::Expr;
 use crate::expr::write_expr;
+use crate::sequence::SequenceOptions;
+use crate::sequence::write_sequence_options;
 use crate::table::ColumnDef;
+use crate::table::ColumnType;
+use crate::table::IdentityColumn;
+use crate::table::IdentityKind;
+use crate::table::PartitionBound;
+use crate::table::TableConstraint;
 use crate::table::write_column_spec;
 use crate::table::write_column_type;
+use crate::table::write_identity_column;
+use crate::table::write_identity_kind;
+use crate::table::write_partition_bound;
+use crate::table::write_table_constraint;
+use crate::types::DropBehavior;
 use crate::types::Iden;
 use crate::types::IntoIden;
 use crate::types::IntoTableRef;
+use crate::types::TableName;
 use crate::types::TableRef;
 use crate::types::write_iden;
+use crate::types::write_table_name;
 use crate::types::write_table_ref;
 use crate::writer::SqlWriter;
 
 /// Alter table statement.
 #[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AlterTable {
     table: Option<TableRef>,
+    if_exists: bool,
+    only: bool,
     options: Vec<TableAlterOption>,
 }
 
@@ -61,6 +79,18 @@ impl AlterTable {
         self
     }
 
+    /// Do not throw an error if the table does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Only alter the named table, not its descendant tables.
+    pub fn only(mut self) -> Self {
+        self.only = true;
+        self
+    }
+
     /// Add a column to an existing table
     pub fn add_column(mut self, column: ColumnDef) -> Self {
         self.options
@@ -102,24 +132,538 @@ impl AlterTable {
         ));
         self
     }
+
+    /// Change the type of a column.
+    pub fn alter_column_type<T>(mut self, col: T, ty: ColumnType) -> Self
+    where
+        T: IntoIden,
+    {
+        self.options.push(TableAlterOption::AlterColumn(
+            col.into_iden(),
+            AlterColumnOption::Type(ty, None),
+        ));
+        self
+    }
+
+    /// Change the type of a column, computing the new values with the given expression.
+    pub fn alter_column_type_using<T, E>(mut self, col: T, ty: ColumnType, using: E) -> Self
+    where
+        T: IntoIden,
+        E: Into<Expr>,
+    {
+        self.options.push(TableAlterOption::AlterColumn(
+            col.into_iden(),
+            AlterColumnOption::Type(ty, Some(using.into())),
+        ));
+        self
+    }
+
+    /// Remove the default value of a column.
+    pub fn drop_default<T>(mut self, col: T) -> Self
+    where
+        T: IntoIden,
+    {
+        self.options.push(TableAlterOption::AlterColumn(
+            col.into_iden(),
+            AlterColumnOption::DropDefault,
+        ));
+        self
+    }
+
+    /// Replace the generation expression of a stored generated column.
+    pub fn set_expression<T, E>(mut self, col: T, expr: E) -> Self
+    where
+        T: IntoIden,
+        E: Into<Expr>,
+    {
+        self.options.push(TableAlterOption::AlterColumn(
+            col.into_iden(),
+            AlterColumnOption::SetExpression(expr.into()),
+        ));
+        self
+    }
+
+    /// Turn a stored generated column into a normal base column.
+    pub fn drop_expression<T>(mut self, col: T) -> Self
+    where
+        T: IntoIden,
+    {
+        self.options.push(TableAlterOption::AlterColumn(
+            col.into_iden(),
+            AlterColumnOption::DropExpression,
+        ));
+        self
+    }
+
+    /// Set the per-column statistics-gathering target.
+    pub fn set_statistics<T>(mut self, col: T, target: i32) -> Self
+    where
+        T: IntoIden,
+    {
+        self.options.push(TableAlterOption::AlterColumn(
+            col.into_iden(),
+            AlterColumnOption::SetStatistics(target),
+        ));
+        self
+    }
+
+    /// Set the storage mode of a column.
+    pub fn set_storage<T>(mut self, col: T, storage: ColumnStorage) -> Self
+    where
+        T: IntoIden,
+    {
+        self.options.push(TableAlterOption::AlterColumn(
+            col.into_iden(),
+            AlterColumnOption::SetStorage(storage),
+        ));
+        self
+    }
+
+    /// Turn a column into an identity column.
+    pub fn add_identity<T>(mut self, col: T, kind: IdentityKind, options: SequenceOptions) -> Self
+    where
+        T: IntoIden,
+    {
+        self.options.push(TableAlterOption::AlterColumn(
+            col.into_iden(),
+            AlterColumnOption::AddIdentity(IdentityColumn { kind, options }),
+        ));
+        self
+    }
+
+    /// Change whether an identity column accepts user-specified values.
+    pub fn set_generated<T>(mut self, col: T, kind: IdentityKind) -> Self
+    where
+        T: IntoIden,
+    {
+        self.options.push(TableAlterOption::AlterColumn(
+            col.into_iden(),
+            AlterColumnOption::SetGenerated(kind),
+        ));
+        self
+    }
+
+    /// Change the parameters of the sequence backing an identity column.
+    pub fn set_identity_options<T>(mut self, col: T, options: SequenceOptions) -> Self
+    where
+        T: IntoIden,
+    {
+        self.options.push(TableAlterOption::AlterColumn(
+            col.into_iden(),
+            AlterColumnOption::SetIdentityOptions(options),
+        ));
+        self
+    }
+
+    /// Restart the sequence backing an identity column at its recorded start value.
+    pub fn restart_identity<T>(mut self, col: T) -> Self
+    where
+        T: IntoIden,
+    {
+        self.options.push(TableAlterOption::AlterColumn(
+            col.into_iden(),
+            AlterColumnOption::RestartIdentity(None),
+        ));
+        self
+    }
+
+    /// Restart the sequence backing an identity column at the given value.
+    pub fn restart_identity_with<T>(mut self, col: T, value: i64) -> Self
+    where
+        T: IntoIden,
+    {
+        self.options.push(TableAlterOption::AlterColumn(
+            col.into_iden(),
+            AlterColumnOption::RestartIdentity(Some(value)),
+        ));
+        self
+    }
+
+    /// Turn an identity column into a normal column.
+    pub fn drop_identity<T>(mut self, col: T) -> Self
+    where
+        T: IntoIden,
+    {
+        self.options.push(TableAlterOption::AlterColumn(
+            col.into_iden(),
+            AlterColumnOption::DropIdentity(false),
+        ));
+        self
+    }
+
+    /// Turn an identity column into a normal column, if it is an identity column.
+    pub fn drop_identity_if_exists<T>(mut self, col: T) -> Self
+    where
+        T: IntoIden,
+    {
+        self.options.push(TableAlterOption::AlterColumn(
+            col.into_iden(),
+            AlterColumnOption::DropIdentity(true),
+        ));
+        self
+    }
+
+    /// Add a table constraint.
+    pub fn add_constraint(mut self, constraint: TableConstraint) -> Self {
+        self.options
+            .push(TableAlterOption::AddConstraint(constraint, false));
+        self
+    }
+
+    /// Add a table constraint without checking existing rows.
+    ///
+    /// The constraint can be validated later with [`AlterTable::validate_constraint`].
+    pub fn add_constraint_not_valid(mut self, constraint: TableConstraint) -> Self {
+        self.options
+            .push(TableAlterOption::AddConstraint(constraint, true));
+        self
+    }
+
+    /// Drop a table constraint.
+    pub fn drop_constraint<D>(mut self, constraint: D) -> Self
+    where
+        D: Into<DropConstraint>,
+    {
+        self.options
+            .push(TableAlterOption::DropConstraint(constraint.into()));
+        self
+    }
+
+    /// Validate a constraint previously added as NOT VALID.
+    pub fn validate_constraint<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.options
+            .push(TableAlterOption::ValidateConstraint(name.into_iden()));
+        self
+    }
+
+    /// Rename a table constraint.
+    ///
+    /// ## Panics
+    /// Rendering the statement will panic if it is combined with any other action.
+    pub fn rename_constraint<T, R>(mut self, from_name: T, to_name: R) -> Self
+    where
+        T: IntoIden,
+        R: IntoIden,
+    {
+        self.options.push(TableAlterOption::RenameConstraint(
+            from_name.into_iden(),
+            to_name.into_iden(),
+        ));
+        self
+    }
+
+    /// Rename the table.
+    ///
+    /// ## Panics
+    /// Rendering the statement will panic if it is combined with any other action.
+    pub fn rename_to<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.options
+            .push(TableAlterOption::RenameTo(name.into_iden()));
+        self
+    }
+
+    /// Move the table into another schema.
+    ///
+    /// ## Panics
+    /// Rendering the statement will panic if it is combined with any other action.
+    pub fn set_schema<S>(mut self, schema: S) -> Self
+    where
+        S: IntoIden,
+    {
+        self.options
+            .push(TableAlterOption::SetSchema(schema.into_iden()));
+        self
+    }
+
+    /// Change the owner of the table.
+    pub fn owner_to<R>(mut self, role: R) -> Self
+    where
+        R: IntoIden,
+    {
+        self.options
+            .push(TableAlterOption::OwnerTo(role.into_iden()));
+        self
+    }
+
+    /// Attach an existing table as a partition of this partitioned table.
+    ///
+    /// ## Panics
+    /// Rendering the statement will panic if it is combined with any other action.
+    pub fn attach_partition<T>(mut self, partition: T, bound: PartitionBound) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.options
+            .push(TableAlterOption::AttachPartition(partition.into(), bound));
+        self
+    }
+
+    /// Detach a partition from this partitioned table.
+    ///
+    /// ## Panics
+    /// Rendering the statement will panic if it is combined with any other action.
+    pub fn detach_partition<T>(mut self, partition: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.options
+            .push(TableAlterOption::DetachPartition(partition.into(), None));
+        self
+    }
+
+    /// Detach a partition without blocking concurrent queries on the partitioned table.
+    ///
+    /// This cannot run inside a transaction block.
+    ///
+    /// ## Panics
+    /// Rendering the statement will panic if it is combined with any other action.
+    pub fn detach_partition_concurrently<T>(mut self, partition: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.options.push(TableAlterOption::DetachPartition(
+            partition.into(),
+            Some(DetachPartitionMode::Concurrently),
+        ));
+        self
+    }
+
+    /// Complete a previously interrupted concurrent detach of a partition.
+    ///
+    /// ## Panics
+    /// Rendering the statement will panic if it is combined with any other action.
+    pub fn detach_partition_finalize<T>(mut self, partition: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.options.push(TableAlterOption::DetachPartition(
+            partition.into(),
+            Some(DetachPartitionMode::Finalize),
+        ));
+        self
+    }
+
+    /// Enable row-level security policies for the table.
+    pub fn enable_row_level_security(mut self) -> Self {
+        self.options.push(TableAlterOption::RowLevelSecurity(true));
+        self
+    }
+
+    /// Disable row-level security policies for the table.
+    pub fn disable_row_level_security(mut self) -> Self {
+        self.options.push(TableAlterOption::RowLevelSecurity(false));
+        self
+    }
+
+    /// Apply row-level security policies to the table owner as well.
+    pub fn force_row_level_security(mut self) -> Self {
+        self.options
+            .push(TableAlterOption::ForceRowLevelSecurity(true));
+        self
+    }
+
+    /// Exempt the table owner from row-level security policies.
+    pub fn no_force_row_level_security(mut self) -> Self {
+        self.options
+            .push(TableAlterOption::ForceRowLevelSecurity(false));
+        self
+    }
+
+    /// Enable the given trigger.
+    pub fn enable_trigger<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.options.push(TableAlterOption::Trigger(
+            TriggerState::Enable,
+            Some(name.into_iden()),
+        ));
+        self
+    }
+
+    /// Enable the given trigger to fire only in replica mode.
+    pub fn enable_replica_trigger<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.options.push(TableAlterOption::Trigger(
+            TriggerState::EnableReplica,
+            Some(name.into_iden()),
+        ));
+        self
+    }
+
+    /// Enable the given trigger to fire regardless of the replication role.
+    pub fn enable_always_trigger<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.options.push(TableAlterOption::Trigger(
+            TriggerState::EnableAlways,
+            Some(name.into_iden()),
+        ));
+        self
+    }
+
+    /// Disable the given trigger.
+    pub fn disable_trigger<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.options.push(TableAlterOption::Trigger(
+            TriggerState::Disable,
+            Some(name.into_iden()),
+        ));
+        self
+    }
+
+    /// Enable all triggers of the table, including internal ones.
+    pub fn enable_all_triggers(mut self) -> Self {
+        self.options
+            .push(TableAlterOption::Trigger(TriggerState::Enable, None));
+        self
+    }
+
+    /// Disable all triggers of the table, including internal ones.
+    pub fn disable_all_triggers(mut self) -> Self {
+        self.options
+            .push(TableAlterOption::Trigger(TriggerState::Disable, None));
+        self
+    }
 }
 
+/// DROP CONSTRAINT action of an ALTER TABLE statement.
 #[derive(Debug, Clone)]
-#[allow(clippy::enum_variant_names)] // add_foreign_key, drop_foreign_key, ...
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DropConstraint {
+    name: Iden,
+    if_exists: bool,
+    behavior: Option<DropBehavior>,
+}
+
+impl DropConstraint {
+    /// Drop the constraint with the given name.
+    pub fn new<N>(name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        Self {
+            name: name.into_iden(),
+            if_exists: false,
+            behavior: None,
+        }
+    }
+
+    /// Do not throw an error if the constraint does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Add CASCADE to drop dependent objects.
+    pub fn cascade(mut self) -> Self {
+        self.behavior = Some(DropBehavior::Cascade);
+        self
+    }
+
+    /// Add RESTRICT to drop (explicitly).
+    pub fn restrict(mut self) -> Self {
+        self.behavior = Some(DropBehavior::Restrict);
+        self
+    }
+}
+
+impl<N> From<N> for DropConstraint
+where
+    N: IntoIden,
+{
+    fn from(name: N) -> Self {
+        DropConstraint::new(name)
+    }
+}
+
+/// Column storage modes for SET STORAGE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+#[expect(missing_docs)]
+pub enum ColumnStorage {
+    Plain,
+    External,
+    Extended,
+    Main,
+    Default,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum TableAlterOption {
     AddColumn(AddColumnOption),
     ModifyColumn(ColumnDef),
     RenameColumn(Iden, Iden),
     DropColumn(DropColumnOption),
+    AlterColumn(Iden, AlterColumnOption),
+    AddConstraint(TableConstraint, bool),
+    DropConstraint(DropConstraint),
+    ValidateConstraint(Iden),
+    RenameConstraint(Iden, Iden),
+    RenameTo(Iden),
+    SetSchema(Iden),
+    OwnerTo(Iden),
+    RowLevelSecurity(bool),
+    ForceRowLevelSecurity(bool),
+    Trigger(TriggerState, Option<Iden>),
+    AttachPartition(TableName, PartitionBound),
+    DetachPartition(TableName, Option<DetachPartitionMode>),
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum TriggerState {
+    Enable,
+    EnableReplica,
+    EnableAlways,
+    Disable,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum DetachPartitionMode {
+    Concurrently,
+    Finalize,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum AlterColumnOption {
+    Type(ColumnType, Option<Expr>),
+    DropDefault,
+    SetExpression(Expr),
+    DropExpression,
+    SetStatistics(i32),
+    SetStorage(ColumnStorage),
+    AddIdentity(IdentityColumn),
+    SetGenerated(IdentityKind),
+    SetIdentityOptions(SequenceOptions),
+    RestartIdentity(Option<i64>),
+    DropIdentity(bool),
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct AddColumnOption {
     column: ColumnDef,
     if_not_exists: bool,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct DropColumnOption {
     column: Iden,
     if_exists: bool,
@@ -127,10 +671,31 @@ struct DropColumnOption {
 
 fn write_alter_table<W: SqlWriter>(w: &mut W, alter: &AlterTable) {
     w.push_str("ALTER TABLE ");
+    if alter.if_exists {
+        w.push_str("IF EXISTS ");
+    }
+    if alter.only {
+        w.push_str("ONLY ");
+    }
     if let Some(table) = &alter.table {
         write_table_ref(w, table);
         w.push_char(' ');
     }
+    let standalone = alter.options.iter().any(|option| {
+        matches!(
+            option,
+            TableAlterOption::RenameTo(_)
+                | TableAlterOption::SetSchema(_)
+                | TableAlterOption::RenameConstraint(..)
+                | TableAlterOption::AttachPartition(..)
+                | TableAlterOption::DetachPartition(..)
+        )
+    });
+    if standalone && alter.options.len() > 1 {
+        panic!(
+            "RENAME TO, SET SCHEMA, RENAME CONSTRAINT, ATTACH PARTITION and DETACH PARTITION cannot be combined with other actions."
+        );
+    }
     for (i, option) in alter.options.iter().enumerate() {
         if i > 0 {
             w.push_str(", ");
@@ -220,6 +785,150 @@ fn write_alter_table<W: SqlWriter>(w: &mut W, alter: &AlterTable) {
                 }
                 write_iden(w, column);
             }
+            TableAlterOption::AlterColumn(column, option) => {
+                w.push_str("ALTER COLUMN ");
+                write_iden(w, column);
+                write_alter_column_option(w, option);
+            }
+            TableAlterOption::AddConstraint(constraint, not_valid) => {
+                w.push_str("ADD ");
+                write_table_constraint(w, constraint);
+                if *not_valid {
+                    w.push_str(" NOT VALID");
+                }
+            }
+            TableAlterOption::DropConstraint(DropConstraint {
+                name,
+                if_exists,
+                behavior,
+            }) => {
+                w.push_str("DROP CONSTRAINT ");
+                if *if_exists {
+                    w.push_str("IF EXISTS ");
+                }
+                write_iden(w, name);
+                if let Some(behavior) = behavior {
+                    w.push_char(' ');
+                    match behavior {
+                        DropBehavior::Cascade => w.push_str("CASCADE"),
+                        DropBehavior::Restrict => w.push_str("RESTRICT"),
+                    }
+                }
+            }
+            TableAlterOption::ValidateConstraint(name) => {
+                w.push_str("VALIDATE CONSTRAINT ");
+                write_iden(w, name);
+            }
+            TableAlterOption::RenameConstraint(from, to) => {
+                w.push_str("RENAME CONSTRAINT ");
+                write_iden(w, from);
+                w.push_str(" TO ");
+                write_iden(w, to);
+            }
+            TableAlterOption::RenameTo(name) => {
+                w.push_str("RENAME TO ");
+                write_iden(w, name);
+            }
+            TableAlterOption::SetSchema(schema) => {
+                w.push_str("SET SCHEMA ");
+                write_iden(w, schema);
+            }
+            TableAlterOption::OwnerTo(role) => {
+                w.push_str("OWNER TO ");
+                write_iden(w, role);
+            }
+            TableAlterOption::RowLevelSecurity(enable) => {
+                w.push_str(if *enable { "ENABLE" } else { "DISABLE" });
+                w.push_str(" ROW LEVEL SECURITY");
+            }
+            TableAlterOption::ForceRowLevelSecurity(force) => {
+                w.push_str(if *force { "FORCE" } else { "NO FORCE" });
+                w.push_str(" ROW LEVEL SECURITY");
+            }
+            TableAlterOption::Trigger(state, name) => {
+                w.push_str(match state {
+                    TriggerState::Enable => "ENABLE TRIGGER ",
+                    TriggerState::EnableReplica => "ENABLE REPLICA TRIGGER ",
+                    TriggerState::EnableAlways => "ENABLE ALWAYS TRIGGER ",
+                    TriggerState::Disable => "DISABLE TRIGGER ",
+                });
+                match name {
+                    Some(name) => write_iden(w, name),
+                    None => w.push_str("ALL"),
+                }
+            }
+            TableAlterOption::AttachPartition(partition, bound) => {
+                w.push_str("ATTACH PARTITION ");
+                write_table_name(w, partition);
+                w.push_char(' ');
+                write_partition_bound(w, bound);
+            }
+            TableAlterOption::DetachPartition(partition, mode) => {
+                w.push_str("DETACH PARTITION ");
+                write_table_name(w, partition);
+                match mode {
+                    Some(DetachPartitionMode::Concurrently) => w.push_str(" CONCURRENTLY"),
+                    Some(DetachPartitionMode::Finalize) => w.push_str(" FINALIZE"),
+                    None => {}
+                }
+            }
+        }
+    }
+}
+
+fn write_alter_column_option<W: SqlWriter>(w: &mut W, option: &AlterColumnOption) {
+    match option {
+        AlterColumnOption::Type(ty, using) => {
+            w.push_str(" TYPE ");
+            write_column_type(w, ty);
+            if let Some(using) = using {
+                w.push_str(" USING ");
+                write_expr(w, using);
+            }
+        }
+        AlterColumnOption::DropDefault => w.push_str(" DROP DEFAULT"),
+        AlterColumnOption::SetExpression(expr) => {
+            w.push_str(" SET EXPRESSION AS (");
+            write_expr(w, expr);
+            w.push_char(')');
+        }
+        AlterColumnOption::DropExpression => w.push_str(" DROP EXPRESSION"),
+        AlterColumnOption::SetStatistics(target) => {
+            w.push_fmt(format_args!(" SET STATISTICS {target}"));
+        }
+        AlterColumnOption::SetStorage(storage) => {
+            w.push_str(" SET STORAGE ");
+            w.push_str(match storage {
+                ColumnStorage::Plain => "PLAIN",
+                ColumnStorage::External => "EXTERNAL",
+                ColumnStorage::Extended => "EXTENDED",
+                ColumnStorage::Main => "MAIN",
+                ColumnStorage::Default => "DEFAULT",
+            });
+        }
+        AlterColumnOption::AddIdentity(identity) => {
+            w.push_str(" ADD ");
+            write_identity_column(w, identity);
+        }
+        AlterColumnOption::SetGenerated(kind) => {
+            w.push_str(" SET ");
+            write_identity_kind(w, *kind);
+        }
+        AlterColumnOption::SetIdentityOptions(options) => {
+            w.push_char(' ');
+            write_sequence_options(w, options, "SET ");
+        }
+        AlterColumnOption::RestartIdentity(restart) => {
+            w.push_str(" RESTART");
+            if let Some(value) = restart {
+                w.push_fmt(format_args!(" WITH {value}"));
+            }
+        }
+        AlterColumnOption::DropIdentity(if_exists) => {
+            w.push_str(" DROP IDENTITY");
+            if *if_exists {
+                w.push_str(" IF EXISTS");
+            }
         }
     }
 }
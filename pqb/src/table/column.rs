@@ -16,11 +16,18 @@ use std::sync::Arc;
 
 use crate::expr::Expr;
 use crate::expr::write_expr;
+use crate::sequence::SequenceOptions;
+use crate::sequence::write_sequence_options;
+use crate::table::References;
+use crate::table::write_references;
 use crate::types::Iden;
+use crate::types::TypeName;
+use crate::types::write_type_name;
 use crate::writer::SqlWriter;
 
 /// Specification of a table column.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct ColumnDef {
     pub(crate) name: Iden,
@@ -44,11 +51,14 @@ impl ColumnDef {
     /// Set default value for the column.
     ///
     /// ## Panics
-    /// This method will panic if the column is a generated column.
+    /// This method will panic if the column is a generated or identity column.
     pub fn default(mut self, expr: Expr) -> Self {
         if self.spec.generated.is_some() {
             panic!("A generated column cannot have a default value.");
         }
+        if self.spec.identity.is_some() {
+            panic!("An identity column cannot have a default value.");
+        }
         self.spec.default = Some(expr);
         self
     }
@@ -269,16 +279,40 @@ impl ColumnDef {
         self
     }
 
+    /// Set column type as a user-defined type
+    pub fn custom<T>(mut self, name: T) -> Self
+    where
+        T: Into<TypeName>,
+    {
+        self.ty = Some(ColumnType::Custom(name.into()));
+        self
+    }
+
     /// Set column type as array of the given element type.
     pub fn array_of(mut self, ty: ColumnType) -> Self {
         self.ty = Some(ColumnType::Array(Arc::new(ty)));
         self
     }
 
+    /// Add a column CHECK constraint.
+    pub fn check<E>(mut self, expr: E) -> Self
+    where
+        E: Into<Expr>,
+    {
+        self.spec.check = Some(expr.into());
+        self
+    }
+
+    /// Add a column REFERENCES constraint.
+    pub fn references(mut self, references: References) -> Self {
+        self.spec.references = Some(references);
+        self
+    }
+
     /// Set column as generated with expression and stored storage.
     ///
     /// ## Panics
-    /// This method will panic if the column has a default value set.
+    /// This method will panic if the column has a default value set or is an identity column.
     pub fn generated_as_stored<E>(mut self, expr: E) -> Self
     where
         E: Into<Expr>,
@@ -286,6 +320,9 @@ impl ColumnDef {
         if self.spec.default.is_some() {
             panic!("A generated column cannot have a default value.");
         }
+        if self.spec.identity.is_some() {
+            panic!("A column cannot be both a generated and an identity column.");
+        }
         self.spec.generated = Some(GeneratedColumn {
             expr: expr.into(),
             kind: GeneratedColumnKind::Stored,
@@ -299,7 +336,7 @@ impl ColumnDef {
     /// Before PostgreSQL 18, STORED is the only supported kind and must be specified.
     ///
     /// ## Panics
-    /// This method will panic if the column has a default value set.
+    /// This method will panic if the column has a default value set or is an identity column.
     pub fn generated_as_virtual<E>(mut self, expr: E) -> Self
     where
         E: Into<Expr>,
@@ -307,16 +344,63 @@ impl ColumnDef {
         if self.spec.default.is_some() {
             panic!("A generated column cannot have a default value.");
         }
+        if self.spec.identity.is_some() {
+            panic!("A column cannot be both a generated and an identity column.");
+        }
         self.spec.generated = Some(GeneratedColumn {
             expr: expr.into(),
             kind: GeneratedColumnKind::Virtual,
         });
         self
     }
+
+    /// Set column as an identity column that always generates its values from a sequence.
+    ///
+    /// ## Panics
+    /// This method will panic if the column has a default value set or is a generated column.
+    pub fn generated_always_as_identity(self) -> Self {
+        self.generated_as_identity(IdentityKind::Always)
+    }
+
+    /// Set column as an identity column that generates its values from a sequence unless a value
+    /// is given explicitly.
+    ///
+    /// ## Panics
+    /// This method will panic if the column has a default value set or is a generated column.
+    pub fn generated_by_default_as_identity(self) -> Self {
+        self.generated_as_identity(IdentityKind::ByDefault)
+    }
+
+    /// Set the parameters of the sequence backing an identity column.
+    ///
+    /// ## Panics
+    /// This method will panic if the column is not an identity column.
+    pub fn identity_options(mut self, options: SequenceOptions) -> Self {
+        match &mut self.spec.identity {
+            Some(identity) => identity.options = options,
+            None => panic!("Identity options require an identity column."),
+        }
+        self
+    }
+
+    fn generated_as_identity(mut self, kind: IdentityKind) -> Self {
+        if self.spec.default.is_some() {
+            panic!("An identity column cannot have a default value.");
+        }
+        if self.spec.generated.is_some() {
+            panic!("A column cannot be both a generated and an identity column.");
+        }
+        self.spec.identity = Some(IdentityColumn {
+            kind,
+            options: SequenceOptions::default(),
+        });
+        self
+    }
 }
 
 /// Column data types.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 #[expect(missing_docs)]
 pub enum ColumnType {
@@ -365,10 +449,20 @@ pub enum ColumnType {
     Uuid,
 
     Array(Arc<ColumnType>),
+
+    /// A user-defined type, such as an enum, composite, range or domain type.
+    Custom(TypeName),
+}
+
+impl From<TypeName> for ColumnType {
+    fn from(name: TypeName) -> Self {
+        ColumnType::Custom(name)
+    }
 }
 
 /// Specification of column attributes.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 #[expect(missing_docs)]
 pub struct ColumnSpec {
@@ -377,10 +471,14 @@ pub struct ColumnSpec {
     pub generated: Option<GeneratedColumn>,
     pub unique: bool,
     pub primary_key: bool,
+    pub check: Option<Expr>,
+    pub references: Option<References>,
+    pub identity: Option<IdentityColumn>,
 }
 
 /// Generated column specification.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 #[expect(missing_docs)]
 pub struct GeneratedColumn {
@@ -391,6 +489,7 @@ pub struct GeneratedColumn {
 
 /// Generated column storage kind.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 #[expect(missing_docs)]
 pub enum GeneratedColumnKind {
@@ -398,6 +497,44 @@ pub enum GeneratedColumnKind {
     Virtual,
 }
 
+/// Identity column specification.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+#[expect(missing_docs)]
+pub struct IdentityColumn {
+    pub kind: IdentityKind,
+    pub options: SequenceOptions,
+}
+
+/// Whether an identity column accepts user-specified values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum IdentityKind {
+    /// GENERATED ALWAYS: user values are rejected unless OVERRIDING SYSTEM VALUE is given.
+    Always,
+    /// GENERATED BY DEFAULT: user values take precedence over the sequence.
+    ByDefault,
+}
+
+pub(crate) fn write_identity_kind<W: SqlWriter>(w: &mut W, kind: IdentityKind) {
+    w.push_str(match kind {
+        IdentityKind::Always => "GENERATED ALWAYS",
+        IdentityKind::ByDefault => "GENERATED BY DEFAULT",
+    });
+}
+
+pub(crate) fn write_identity_column<W: SqlWriter>(w: &mut W, identity: &IdentityColumn) {
+    write_identity_kind(w, identity.kind);
+    w.push_str(" AS IDENTITY");
+    if !identity.options.is_empty() {
+        w.push_str(" (");
+        write_sequence_options(w, &identity.options, "");
+        w.push_char(')');
+    }
+}
+
 pub(crate) fn write_column_type<W: SqlWriter>(w: &mut W, column_type: &ColumnType) {
     match column_type {
         ColumnType::Char(size) => {
@@ -458,6 +595,8 @@ pub(crate) fn write_column_type<W: SqlWriter>(w: &mut W, column_type: &ColumnTyp
             write_column_type(w, ty);
             w.push_str("[]");
         }
+
+        ColumnType::Custom(name) => write_type_name(w, name),
     }
 }
 
@@ -468,6 +607,9 @@ pub(crate) fn write_column_spec<W: SqlWriter>(w: &mut W, column_spec: &ColumnSpe
         generated,
         unique,
         primary_key,
+        check,
+        references,
+        identity,
     } = column_spec;
 
     if let Some(nullable) = nullable {
@@ -496,6 +638,11 @@ pub(crate) fn write_column_spec<W: SqlWriter>(w: &mut W, column_spec: &ColumnSpe
         });
     }
 
+    if let Some(identity) = identity {
+        w.push_char(' ');
+        write_identity_column(w, identity);
+    }
+
     if *primary_key {
         w.push_str(" PRIMARY KEY");
     }
@@ -503,4 +650,15 @@ pub(crate) fn write_column_spec<W: SqlWriter>(w: &mut W, column_spec: &ColumnSpe
     if *unique {
         w.push_str(" UNIQUE");
     }
+
+    if let Some(check) = check {
+        w.push_str(" CHECK (");
+        write_expr(w, check);
+        w.push_str(")");
+    }
+
+    if let Some(references) = references {
+        w.push_str(" ");
+        write_references(w, references);
+    }
 }
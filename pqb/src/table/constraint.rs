@@ -0,0 +1,561 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+
+use crate::expr::Expr;
+use crate::expr::write_expr;
+use crate::index::IndexMethod;
+use crate::index::write_index_method;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::TableName;
+use crate::types::write_iden;
+use crate::types::write_table_name;
+use crate::writer::SqlWriter;
+
+/// A table constraint, used in CREATE TABLE.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableConstraint {
+    name: Option<Iden>,
+    kind: ConstraintKind,
+    deferrable: Option<bool>,
+    initially_deferred: Option<bool>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum ConstraintKind {
+    Check(Expr),
+    PrimaryKey(PrimaryKey),
+    Unique(Unique),
+    ForeignKey(Vec<Iden>, References),
+    Exclude(Exclude),
+}
+
+impl TableConstraint {
+    fn new(kind: ConstraintKind) -> Self {
+        Self {
+            name: None,
+            kind,
+            deferrable: None,
+            initially_deferred: None,
+        }
+    }
+
+    /// Create a CHECK constraint.
+    pub fn check<E>(expr: E) -> Self
+    where
+        E: Into<Expr>,
+    {
+        Self::new(ConstraintKind::Check(expr.into()))
+    }
+
+    /// Create a PRIMARY KEY constraint.
+    pub fn primary_key(primary_key: PrimaryKey) -> Self {
+        Self::new(ConstraintKind::PrimaryKey(primary_key))
+    }
+
+    /// Create a UNIQUE constraint.
+    pub fn unique(unique: Unique) -> Self {
+        Self::new(ConstraintKind::Unique(unique))
+    }
+
+    /// Create a FOREIGN KEY constraint on the given columns.
+    pub fn foreign_key<T, I>(columns: I, references: References) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        let columns = columns.into_iter().map(|c| c.into_iden()).collect();
+        Self::new(ConstraintKind::ForeignKey(columns, references))
+    }
+
+    /// Create an EXCLUDE constraint.
+    pub fn exclude(exclude: Exclude) -> Self {
+        Self::new(ConstraintKind::Exclude(exclude))
+    }
+
+    /// Set the constraint name.
+    pub fn name<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    pub(crate) fn is_using_index(&self) -> bool {
+        match &self.kind {
+            ConstraintKind::PrimaryKey(primary_key) => primary_key.using_index.is_some(),
+            ConstraintKind::Unique(unique) => unique.using_index.is_some(),
+            _ => false,
+        }
+    }
+
+    /// Mark the constraint as DEFERRABLE.
+    ///
+    /// ## Panics
+    /// This method will panic if the constraint is a CHECK constraint.
+    pub fn deferrable(mut self) -> Self {
+        if matches!(self.kind, ConstraintKind::Check(_)) {
+            panic!("CHECK constraints cannot be marked DEFERRABLE.");
+        }
+        self.deferrable = Some(true);
+        self
+    }
+
+    /// Mark the constraint as NOT DEFERRABLE.
+    pub fn not_deferrable(mut self) -> Self {
+        self.deferrable = Some(false);
+        self
+    }
+
+    /// Check the constraint at the end of the transaction by default.
+    ///
+    /// ## Panics
+    /// This method will panic if the constraint is a CHECK constraint.
+    pub fn initially_deferred(mut self) -> Self {
+        if matches!(self.kind, ConstraintKind::Check(_)) {
+            panic!("CHECK constraints cannot be marked INITIALLY DEFERRED.");
+        }
+        self.initially_deferred = Some(true);
+        self
+    }
+
+    /// Check the constraint after each statement by default.
+    pub fn initially_immediate(mut self) -> Self {
+        self.initially_deferred = Some(false);
+        self
+    }
+}
+
+/// PRIMARY KEY constraint specification.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrimaryKey {
+    columns: Vec<Iden>,
+    include_columns: Vec<Iden>,
+    using_index: Option<Iden>,
+}
+
+impl PrimaryKey {
+    /// Create a PRIMARY KEY constraint over the given columns.
+    pub fn columns<T, I>(columns: I) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            columns: columns.into_iter().map(|c| c.into_iden()).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Promote an existing unique index to a PRIMARY KEY constraint.
+    ///
+    /// Only valid in ALTER TABLE ... ADD CONSTRAINT, [`CreateTable`] panics on it.
+    ///
+    /// [`CreateTable`]: crate::table::CreateTable
+    pub fn using_index<N>(index: N) -> Self
+    where
+        N: IntoIden,
+    {
+        Self {
+            using_index: Some(index.into_iden()),
+            ..Default::default()
+        }
+    }
+
+    /// Add non-key columns to the underlying index.
+    ///
+    /// ## Panics
+    /// This method will panic if the constraint uses an existing index.
+    pub fn include_columns<T, I>(mut self, columns: I) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        if self.using_index.is_some() {
+            panic!("A USING INDEX constraint cannot have INCLUDE columns.");
+        }
+        self.include_columns
+            .extend(columns.into_iter().map(|c| c.into_iden()));
+        self
+    }
+}
+
+/// UNIQUE constraint specification.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Unique {
+    columns: Vec<Iden>,
+    nulls_not_distinct: bool,
+    include_columns: Vec<Iden>,
+    using_index: Option<Iden>,
+}
+
+impl Unique {
+    /// Create a UNIQUE constraint over the given columns.
+    pub fn columns<T, I>(columns: I) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            columns: columns.into_iter().map(|c| c.into_iden()).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Turn an existing unique index into a UNIQUE constraint.
+    ///
+    /// Only valid in ALTER TABLE ... ADD CONSTRAINT, [`CreateTable`] panics on it.
+    ///
+    /// [`CreateTable`]: crate::table::CreateTable
+    pub fn using_index<N>(index: N) -> Self
+    where
+        N: IntoIden,
+    {
+        Self {
+            using_index: Some(index.into_iden()),
+            ..Default::default()
+        }
+    }
+
+    /// Treat NULL values as equal, i.e., NULLS NOT DISTINCT.
+    ///
+    /// ## Panics
+    /// This method will panic if the constraint uses an existing index.
+    pub fn nulls_not_distinct(mut self) -> Self {
+        if self.using_index.is_some() {
+            panic!("A USING INDEX constraint cannot be NULLS NOT DISTINCT.");
+        }
+        self.nulls_not_distinct = true;
+        self
+    }
+
+    /// Add non-key columns to the underlying index.
+    ///
+    /// ## Panics
+    /// This method will panic if the constraint uses an existing index.
+    pub fn include_columns<T, I>(mut self, columns: I) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        if self.using_index.is_some() {
+            panic!("A USING INDEX constraint cannot have INCLUDE columns.");
+        }
+        self.include_columns
+            .extend(columns.into_iter().map(|c| c.into_iden()));
+        self
+    }
+}
+
+/// The REFERENCES clause of a foreign key, used by both column and table constraints.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct References {
+    table: TableName,
+    columns: Vec<Iden>,
+    match_type: Option<ForeignKeyMatch>,
+    on_delete: Option<ReferentialAction>,
+    on_update: Option<ReferentialAction>,
+}
+
+impl References {
+    /// Reference the given table, using its primary key if no columns are specified.
+    pub fn table<T>(table: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        Self {
+            table: table.into(),
+            columns: vec![],
+            match_type: None,
+            on_delete: None,
+            on_update: None,
+        }
+    }
+
+    /// Add a referenced column.
+    pub fn column<C>(mut self, column: C) -> Self
+    where
+        C: IntoIden,
+    {
+        self.columns.push(column.into_iden());
+        self
+    }
+
+    /// Add referenced columns.
+    pub fn columns<T, I>(mut self, columns: I) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.columns
+            .extend(columns.into_iter().map(|c| c.into_iden()));
+        self
+    }
+
+    /// Set the MATCH type.
+    pub fn match_type(mut self, match_type: ForeignKeyMatch) -> Self {
+        self.match_type = Some(match_type);
+        self
+    }
+
+    /// Set the action to take when a referenced row is deleted.
+    pub fn on_delete(mut self, action: ReferentialAction) -> Self {
+        self.on_delete = Some(action);
+        self
+    }
+
+    /// Set the action to take when a referenced column is updated.
+    pub fn on_update(mut self, action: ReferentialAction) -> Self {
+        self.on_update = Some(action);
+        self
+    }
+}
+
+/// Foreign key MATCH types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+#[expect(missing_docs)]
+pub enum ForeignKeyMatch {
+    Full,
+    Partial,
+    Simple,
+}
+
+/// Referential actions for ON DELETE and ON UPDATE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+#[expect(missing_docs)]
+pub enum ReferentialAction {
+    NoAction,
+    Restrict,
+    Cascade,
+    SetNull,
+    SetDefault,
+}
+
+/// EXCLUDE constraint specification.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Exclude {
+    method: Option<IndexMethod>,
+    elements: Vec<(Expr, Cow<'static, str>)>,
+    include_columns: Vec<Iden>,
+    predicate: Option<Expr>,
+}
+
+impl Exclude {
+    /// Create a new EXCLUDE constraint.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the index method, e.g. [`IndexMethod::Gist`].
+    pub fn using(mut self, method: IndexMethod) -> Self {
+        self.method = Some(method);
+        self
+    }
+
+    /// Add an exclusion element, i.e., an expression and the operator it is compared with.
+    pub fn element<E, O>(mut self, expr: E, operator: O) -> Self
+    where
+        E: Into<Expr>,
+        O: Into<Cow<'static, str>>,
+    {
+        self.elements.push((expr.into(), operator.into()));
+        self
+    }
+
+    /// Add non-key columns to the underlying index.
+    pub fn include_columns<T, I>(mut self, columns: I) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.include_columns
+            .extend(columns.into_iter().map(|c| c.into_iden()));
+        self
+    }
+
+    /// Only apply the constraint to rows matching the predicate.
+    pub fn index_where<E>(mut self, expr: E) -> Self
+    where
+        E: Into<Expr>,
+    {
+        self.predicate = Some(expr.into());
+        self
+    }
+}
+
+pub(crate) fn write_table_constraint<W: SqlWriter>(w: &mut W, constraint: &TableConstraint) {
+    if let Some(name) = &constraint.name {
+        w.push_str("CONSTRAINT ");
+        write_iden(w, name);
+        w.push_char(' ');
+    }
+
+    match &constraint.kind {
+        ConstraintKind::Check(expr) => {
+            w.push_str("CHECK (");
+            write_expr(w, expr);
+            w.push_char(')');
+        }
+        ConstraintKind::PrimaryKey(primary_key) => write_primary_key(w, primary_key),
+        ConstraintKind::Unique(unique) => write_unique(w, unique),
+        ConstraintKind::ForeignKey(columns, references) => {
+            w.push_str("FOREIGN KEY ");
+            write_column_list(w, columns);
+            w.push_char(' ');
+            write_references(w, references);
+        }
+        ConstraintKind::Exclude(exclude) => write_exclude(w, exclude),
+    }
+
+    if let Some(deferrable) = constraint.deferrable {
+        w.push_str(if deferrable {
+            " DEFERRABLE"
+        } else {
+            " NOT DEFERRABLE"
+        });
+    }
+    if let Some(initially_deferred) = constraint.initially_deferred {
+        w.push_str(if initially_deferred {
+            " INITIALLY DEFERRED"
+        } else {
+            " INITIALLY IMMEDIATE"
+        });
+    }
+}
+
+fn write_primary_key<W: SqlWriter>(w: &mut W, primary_key: &PrimaryKey) {
+    w.push_str("PRIMARY KEY ");
+    if let Some(index) = &primary_key.using_index {
+        write_using_index(w, index);
+        return;
+    }
+    write_column_list(w, &primary_key.columns);
+    write_include(w, &primary_key.include_columns);
+}
+
+fn write_unique<W: SqlWriter>(w: &mut W, unique: &Unique) {
+    w.push_str("UNIQUE ");
+    if let Some(index) = &unique.using_index {
+        write_using_index(w, index);
+        return;
+    }
+    if unique.nulls_not_distinct {
+        w.push_str("NULLS NOT DISTINCT ");
+    }
+    write_column_list(w, &unique.columns);
+    write_include(w, &unique.include_columns);
+}
+
+fn write_using_index<W: SqlWriter>(w: &mut W, index: &Iden) {
+    w.push_str("USING INDEX ");
+    write_iden(w, index);
+}
+
+pub(crate) fn write_references<W: SqlWriter>(w: &mut W, references: &References) {
+    w.push_str("REFERENCES ");
+    write_table_name(w, &references.table);
+    if !references.columns.is_empty() {
+        w.push_char(' ');
+        write_column_list(w, &references.columns);
+    }
+    if let Some(match_type) = references.match_type {
+        w.push_str(match match_type {
+            ForeignKeyMatch::Full => " MATCH FULL",
+            ForeignKeyMatch::Partial => " MATCH PARTIAL",
+            ForeignKeyMatch::Simple => " MATCH SIMPLE",
+        });
+    }
+    if let Some(action) = references.on_delete {
+        w.push_str(" ON DELETE ");
+        write_referential_action(w, action);
+    }
+    if let Some(action) = references.on_update {
+        w.push_str(" ON UPDATE ");
+        write_referential_action(w, action);
+    }
+}
+
+fn write_referential_action<W: SqlWriter>(w: &mut W, action: ReferentialAction) {
+    w.push_str(match action {
+        ReferentialAction::NoAction => "NO ACTION",
+        ReferentialAction::Restrict => "RESTRICT",
+        ReferentialAction::Cascade => "CASCADE",
+        ReferentialAction::SetNull => "SET NULL",
+        ReferentialAction::SetDefault => "SET DEFAULT",
+    });
+}
+
+fn write_exclude<W: SqlWriter>(w: &mut W, exclude: &Exclude) {
+    w.push_str("EXCLUDE ");
+    if let Some(method) = &exclude.method {
+        w.push_str("USING ");
+        write_index_method(w, method);
+        w.push_char(' ');
+    }
+    w.push_char('(');
+    for (i, (expr, operator)) in exclude.elements.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        match expr {
+            Expr::Column(_) => write_expr(w, expr),
+            _ => {
+                w.push_char('(');
+                write_expr(w, expr);
+                w.push_char(')');
+            }
+        }
+        w.push_str(" WITH ");
+        w.push_str(operator);
+    }
+    w.push_char(')');
+    write_include(w, &exclude.include_columns);
+    if let Some(predicate) = &exclude.predicate {
+        w.push_str(" WHERE (");
+        write_expr(w, predicate);
+        w.push_char(')');
+    }
+}
+
+fn write_column_list<W: SqlWriter>(w: &mut W, columns: &[Iden]) {
+    w.push_char('(');
+    for (i, col) in columns.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        write_iden(w, col);
+    }
+    w.push_char(')');
+}
+
+fn write_include<W: SqlWriter>(w: &mut W, columns: &[Iden]) {
+    if columns.is_empty() {
+        return;
+    }
+    w.push_str(" INCLUDE ");
+    write_column_list(w, columns);
+}
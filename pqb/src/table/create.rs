@@ -13,25 +13,115 @@
 // limitations under the License.
 
 use crate::SqlWriterValues;
-use crate::index::CreateIndex;
-use crate::index::write_table_index;
+use crate::expr::Expr;
+use crate::index::IndexOption;
+use crate::index::write_index_options;
+use crate::query::Select;
+use crate::query::write_select;
 use crate::table::ColumnDef;
+use crate::table::PartitionBound;
+use crate::table::PartitionBy;
+use crate::table::PrimaryKey;
+use crate::table::TableConstraint;
 use crate::table::write_column_spec;
 use crate::table::write_column_type;
+use crate::table::write_partition_bound;
+use crate::table::write_partition_by;
+use crate::table::write_table_constraint;
+use crate::types::Iden;
+use crate::types::IntoIden;
 use crate::types::IntoTableRef;
+use crate::types::TableName;
 use crate::types::TableRef;
 use crate::types::write_iden;
+use crate::types::write_table_name;
 use crate::types::write_table_ref;
 use crate::writer::SqlWriter;
 
 /// Create a table.
 #[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CreateTable {
     table: Option<TableRef>,
     columns: Vec<ColumnDef>,
-    indexes: Vec<CreateIndex>,
+    constraints: Vec<TableConstraint>,
     if_not_exists: bool,
     temporary: bool,
+    unlogged: bool,
+    likes: Vec<TableLike>,
+    inherits: Vec<TableName>,
+    partition_by: Option<PartitionBy>,
+    partition_of: Option<(TableName, PartitionBound)>,
+    method: Option<Iden>,
+    options: Vec<IndexOption>,
+    on_commit: Option<OnCommit>,
+    tablespace: Option<Iden>,
+    query: Option<Box<Select>>,
+    with_data: Option<bool>,
+}
+
+/// A `LIKE source [INCLUDING | EXCLUDING ...]` clause copying the definition of another table.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableLike {
+    source: TableName,
+    options: Vec<(bool, LikeOption)>,
+}
+
+impl TableLike {
+    /// Copy column names, types and not-null constraints from the given table.
+    pub fn new<T>(source: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        Self {
+            source: source.into(),
+            options: vec![],
+        }
+    }
+
+    /// Also copy the given properties of the source table.
+    pub fn including(mut self, option: LikeOption) -> Self {
+        self.options.push((true, option));
+        self
+    }
+
+    /// Do not copy the given properties of the source table.
+    pub fn excluding(mut self, option: LikeOption) -> Self {
+        self.options.push((false, option));
+        self
+    }
+}
+
+/// Properties that a LIKE clause can include or exclude.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+#[expect(missing_docs)]
+pub enum LikeOption {
+    Comments,
+    Compression,
+    Constraints,
+    Defaults,
+    Generated,
+    Identity,
+    Indexes,
+    Statistics,
+    Storage,
+    All,
+}
+
+/// What happens to a temporary table at the end of a transaction block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum OnCommit {
+    /// Keep all rows (the default).
+    PreserveRows,
+    /// Delete all rows, like a TRUNCATE.
+    DeleteRows,
+    /// Drop the table.
+    Drop,
 }
 
 impl CreateTable {
@@ -66,6 +156,12 @@ impl CreateTable {
         self
     }
 
+    /// Create unlogged table, which is not written to the write-ahead log.
+    pub fn unlogged(mut self) -> Self {
+        self.unlogged = true;
+        self
+    }
+
     /// Set table name.
     pub fn table<T>(mut self, table: T) -> Self
     where
@@ -81,18 +177,126 @@ impl CreateTable {
         self
     }
 
-    /// Add a primary key index.
-    pub fn primary_key(mut self, index: CreateIndex) -> Self {
-        self.indexes.push(index.primary());
+    /// Add a PRIMARY KEY table constraint.
+    ///
+    /// ## Panics
+    /// This method will panic if the constraint is built with [`PrimaryKey::using_index`].
+    pub fn primary_key(self, primary_key: PrimaryKey) -> Self {
+        self.constraint(TableConstraint::primary_key(primary_key))
+    }
+
+    /// Add a table constraint.
+    ///
+    /// ## Panics
+    /// This method will panic if the constraint uses an existing index, i.e., USING INDEX.
+    pub fn constraint(mut self, constraint: TableConstraint) -> Self {
+        if constraint.is_using_index() {
+            panic!("USING INDEX constraints are only allowed in ALTER TABLE.");
+        }
+        self.constraints.push(constraint);
+        self
+    }
+
+    /// Copy the definition of another table.
+    pub fn like(mut self, like: TableLike) -> Self {
+        self.likes.push(like);
+        self
+    }
+
+    /// Inherit all columns from the given parent table.
+    pub fn inherits<T>(mut self, parent: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.inherits.push(parent.into());
+        self
+    }
+
+    /// Make this a partitioned table with the given partition key.
+    pub fn partition_by(mut self, partition_by: PartitionBy) -> Self {
+        self.partition_by = Some(partition_by);
+        self
+    }
+
+    /// Create the table as a partition of the given parent table.
+    ///
+    /// Columns are inherited from the parent, so only column constraints and table constraints
+    /// need to be given.
+    pub fn partition_of<T>(mut self, parent: T, bound: PartitionBound) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.partition_of = Some((parent.into(), bound));
+        self
+    }
+
+    /// Set the table access method.
+    pub fn using<M>(mut self, method: M) -> Self
+    where
+        M: IntoIden,
+    {
+        self.method = Some(method.into_iden());
+        self
+    }
+
+    /// Add a storage parameter to the WITH clause, e.g. `fillfactor`.
+    pub fn with_option<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: IntoIden,
+        V: Into<Expr>,
+    {
+        self.options.push(IndexOption::new(name, value));
+        self
+    }
+
+    /// Add storage parameters to the WITH clause.
+    pub fn with_options<I, T>(mut self, options: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<IndexOption>,
+    {
+        self.options.extend(options.into_iter().map(Into::into));
+        self
+    }
+
+    /// Set the behavior of a temporary table at the end of a transaction block.
+    pub fn on_commit(mut self, on_commit: OnCommit) -> Self {
+        self.on_commit = Some(on_commit);
+        self
+    }
+
+    /// Set the tablespace.
+    pub fn tablespace<T>(mut self, tablespace: T) -> Self
+    where
+        T: IntoIden,
+    {
+        self.tablespace = Some(tablespace.into_iden());
+        self
+    }
+
+    /// Create the table from the result of a query (CREATE TABLE AS).
+    ///
+    /// Column types are taken from the query, so only the names of added columns are used.
+    pub fn as_select(mut self, select: Select) -> Self {
+        self.query = Some(Box::new(select));
+        self
+    }
+
+    /// Whether to fill a table created from a query (WITH [NO] DATA).
+    pub fn with_data(mut self, with_data: bool) -> Self {
+        self.with_data = Some(with_data);
         self
     }
 }
 
-fn write_create_table<W: SqlWriter>(w: &mut W, table: &CreateTable) {
+pub(crate) fn write_create_table<W: SqlWriter>(w: &mut W, table: &CreateTable) {
     w.push_str("CREATE ");
     if table.temporary {
         w.push_str("TEMPORARY ");
     }
+    if table.unlogged {
+        w.push_str("UNLOGGED ");
+    }
     w.push_str("TABLE ");
     if table.if_not_exists {
         w.push_str("IF NOT EXISTS ");
@@ -101,6 +305,91 @@ fn write_create_table<W: SqlWriter>(w: &mut W, table: &CreateTable) {
         write_table_ref(w, table_ref);
     }
 
+    if let Some((parent, _)) = &table.partition_of {
+        w.push_str(" PARTITION OF ");
+        write_table_name(w, parent);
+    }
+
+    if table.query.is_some() {
+        write_column_names(w, &table.columns);
+    } else {
+        let has_elements =
+            !table.likes.is_empty() || !table.columns.is_empty() || !table.constraints.is_empty();
+        if has_elements || table.partition_of.is_none() {
+            write_table_elements(w, table);
+        }
+    }
+
+    if !table.inherits.is_empty() {
+        w.push_str(" INHERITS (");
+        for (i, parent) in table.inherits.iter().enumerate() {
+            if i > 0 {
+                w.push_str(", ");
+            }
+            write_table_name(w, parent);
+        }
+        w.push_char(')');
+    }
+
+    if let Some((_, bound)) = &table.partition_of {
+        w.push_char(' ');
+        write_partition_bound(w, bound);
+    }
+
+    if let Some(partition_by) = &table.partition_by {
+        w.push_char(' ');
+        write_partition_by(w, partition_by);
+    }
+
+    if let Some(method) = &table.method {
+        w.push_str(" USING ");
+        write_iden(w, method);
+    }
+
+    write_index_options(w, &table.options);
+
+    if let Some(on_commit) = table.on_commit {
+        w.push_str(match on_commit {
+            OnCommit::PreserveRows => " ON COMMIT PRESERVE ROWS",
+            OnCommit::DeleteRows => " ON COMMIT DELETE ROWS",
+            OnCommit::Drop => " ON COMMIT DROP",
+        });
+    }
+
+    if let Some(tablespace) = &table.tablespace {
+        w.push_str(" TABLESPACE ");
+        write_iden(w, tablespace);
+    }
+
+    if let Some(query) = &table.query {
+        w.push_str(" AS ");
+        write_select(w, query);
+        if let Some(with_data) = table.with_data {
+            w.push_str(if with_data {
+                " WITH DATA"
+            } else {
+                " WITH NO DATA"
+            });
+        }
+    }
+}
+
+fn write_column_names<W: SqlWriter>(w: &mut W, columns: &[ColumnDef]) {
+    if columns.is_empty() {
+        return;
+    }
+
+    w.push_str(" (");
+    for (i, col) in columns.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        write_iden(w, &col.name);
+    }
+    w.push_char(')');
+}
+
+fn write_table_elements<W: SqlWriter>(w: &mut W, table: &CreateTable) {
     w.push_str(" ( ");
     let mut is_first = true;
     macro_rules! write_comma_if_not_first {
@@ -112,6 +401,11 @@ fn write_create_table<W: SqlWriter>(w: &mut W, table: &CreateTable) {
             }
         };
     }
+    for like in &table.likes {
+        write_comma_if_not_first!();
+        write_table_like(w, like);
+    }
+
     for col in &table.columns {
         write_comma_if_not_first!();
         write_iden(w, &col.name);
@@ -122,10 +416,34 @@ fn write_create_table<W: SqlWriter>(w: &mut W, table: &CreateTable) {
         write_column_spec(w, &col.spec);
     }
 
-    for idx in &table.indexes {
+    for constraint in &table.constraints {
         write_comma_if_not_first!();
-        write_table_index(w, idx);
+        write_table_constraint(w, constraint);
     }
     let _ = is_first;
     w.push_str(" )");
 }
+
+fn write_table_like<W: SqlWriter>(w: &mut W, like: &TableLike) {
+    w.push_str("LIKE ");
+    write_table_name(w, &like.source);
+    for (including, option) in &like.options {
+        w.push_str(if *including {
+            " INCLUDING "
+        } else {
+            " EXCLUDING "
+        });
+        w.push_str(match option {
+            LikeOption::Comments => "COMMENTS",
+            LikeOption::Compression => "COMPRESSION",
+            LikeOption::Constraints => "CONSTRAINTS",
+            LikeOption::Defaults => "DEFAULTS",
+            LikeOption::Generated => "GENERATED",
+            LikeOption::Identity => "IDENTITY",
+            LikeOption::Indexes => "INDEXES",
+            LikeOption::Statistics => "STATISTICS",
+            LikeOption::Storage => "STORAGE",
+            LikeOption::All => "ALL",
+        });
+    }
+}
@@ -20,6 +20,7 @@ use crate::writer::SqlWriter;
 
 /// DROP TABLE statement builder.
 #[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DropTable {
     tables: Vec<TableName>,
     if_exists: bool,
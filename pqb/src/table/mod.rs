@@ -16,10 +16,16 @@
 
 mod alter;
 mod column;
+mod constraint;
 mod create;
 mod drop;
+mod partition;
+mod truncate;
 
 pub use self::alter::*;
 pub use self::column::*;
+pub use self::constraint::*;
 pub use self::create::*;
 pub use self::drop::*;
+pub use self::partition::*;
+pub use self::truncate::*;
@@ -0,0 +1,196 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::expr::Expr;
+use crate::expr::write_expr;
+use crate::expr::write_tuple;
+use crate::types::IntoIden;
+use crate::writer::SqlWriter;
+
+/// Partitioning strategy and partition key of a partitioned table.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PartitionBy {
+    strategy: PartitionStrategy,
+    elements: Vec<Expr>,
+}
+
+/// Partitioning strategies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+#[expect(missing_docs)]
+pub enum PartitionStrategy {
+    Range,
+    List,
+    Hash,
+}
+
+impl PartitionBy {
+    /// Partition by ranges of the given key columns.
+    pub fn range<I, T>(columns: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: IntoIden,
+    {
+        Self::new(PartitionStrategy::Range).columns(columns)
+    }
+
+    /// Partition by explicit lists of key values of the given column.
+    pub fn list<C>(column: C) -> Self
+    where
+        C: IntoIden,
+    {
+        Self::new(PartitionStrategy::List).columns([column])
+    }
+
+    /// Partition by hash of the given key columns.
+    pub fn hash<I, T>(columns: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: IntoIden,
+    {
+        Self::new(PartitionStrategy::Hash).columns(columns)
+    }
+
+    /// Create a partition key with the given strategy and no key elements.
+    pub fn new(strategy: PartitionStrategy) -> Self {
+        Self {
+            strategy,
+            elements: vec![],
+        }
+    }
+
+    /// Add key columns.
+    pub fn columns<I, T>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: IntoIden,
+    {
+        self.elements
+            .extend(columns.into_iter().map(|c| Expr::column(c.into_iden())));
+        self
+    }
+
+    /// Add a key expression, e.g. `date_trunc('month', created_at)`.
+    pub fn expr<E>(mut self, expr: E) -> Self
+    where
+        E: Into<Expr>,
+    {
+        self.elements.push(expr.into());
+        self
+    }
+}
+
+/// Partition bound of a partition, as given in `FOR VALUES` or `DEFAULT`.
+///
+/// Range bounds may use `Expr::custom("MINVALUE")` and `Expr::custom("MAXVALUE")` for unbounded
+/// ends.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum PartitionBound {
+    /// `FOR VALUES FROM (...) TO (...)`, with an inclusive lower and exclusive upper bound.
+    Range {
+        /// Lower bound values, one per partition key element.
+        from: Vec<Expr>,
+        /// Upper bound values, one per partition key element.
+        to: Vec<Expr>,
+    },
+    /// `FOR VALUES IN (...)`.
+    List(Vec<Expr>),
+    /// `FOR VALUES WITH (MODULUS m, REMAINDER r)`.
+    Hash {
+        /// The number of hash partitions.
+        modulus: u32,
+        /// The hash remainder this partition accepts.
+        remainder: u32,
+    },
+    /// `DEFAULT`, accepting all rows that do not fit any other partition.
+    Default,
+}
+
+impl PartitionBound {
+    /// Create a range bound from the lower (inclusive) and upper (exclusive) bound values.
+    pub fn range<F, T, A, B>(from: F, to: T) -> Self
+    where
+        F: IntoIterator<Item = A>,
+        T: IntoIterator<Item = B>,
+        A: Into<Expr>,
+        B: Into<Expr>,
+    {
+        PartitionBound::Range {
+            from: from.into_iter().map(Into::into).collect(),
+            to: to.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Create a list bound from the accepted values.
+    pub fn list<I, T>(values: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Expr>,
+    {
+        PartitionBound::List(values.into_iter().map(Into::into).collect())
+    }
+
+    /// Create a hash bound.
+    pub fn hash(modulus: u32, remainder: u32) -> Self {
+        PartitionBound::Hash { modulus, remainder }
+    }
+}
+
+pub(crate) fn write_partition_by<W: SqlWriter>(w: &mut W, partition_by: &PartitionBy) {
+    w.push_str("PARTITION BY ");
+    w.push_str(match partition_by.strategy {
+        PartitionStrategy::Range => "RANGE",
+        PartitionStrategy::List => "LIST",
+        PartitionStrategy::Hash => "HASH",
+    });
+    w.push_str(" (");
+    for (i, element) in partition_by.elements.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        match element {
+            Expr::Column(_) | Expr::FunctionCall(_) => write_expr(w, element),
+            _ => {
+                w.push_char('(');
+                write_expr(w, element);
+                w.push_char(')');
+            }
+        }
+    }
+    w.push_char(')');
+}
+
+pub(crate) fn write_partition_bound<W: SqlWriter>(w: &mut W, bound: &PartitionBound) {
+    match bound {
+        PartitionBound::Range { from, to } => {
+            w.push_str("FOR VALUES FROM ");
+            write_tuple(w, from);
+            w.push_str(" TO ");
+            write_tuple(w, to);
+        }
+        PartitionBound::List(values) => {
+            w.push_str("FOR VALUES IN ");
+            write_tuple(w, values);
+        }
+        PartitionBound::Hash { modulus, remainder } => {
+            w.push_fmt(format_args!(
+                "FOR VALUES WITH (MODULUS {modulus}, REMAINDER {remainder})"
+            ));
+        }
+        PartitionBound::Default => w.push_str("DEFAULT"),
+    }
+}
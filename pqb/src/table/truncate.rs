@@ -0,0 +1,126 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::types::DropBehavior;
+use crate::types::TableName;
+use crate::types::write_table_name;
+use crate::writer::SqlWriter;
+
+/// TRUNCATE statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Truncate {
+    tables: Vec<TableName>,
+    only: bool,
+    restart_identity: Option<bool>,
+    behavior: Option<DropBehavior>,
+}
+
+impl Truncate {
+    /// Create a new TRUNCATE statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_truncate(&mut w, self);
+        w
+    }
+
+    /// Convert the TRUNCATE statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_truncate(&mut sql, self);
+        sql
+    }
+
+    /// Add a table name to truncate.
+    pub fn table<T>(mut self, table: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.tables.push(table.into());
+        self
+    }
+
+    /// Add multiple table names to truncate.
+    pub fn tables<I, T>(mut self, tables: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<TableName>,
+    {
+        self.tables.extend(tables.into_iter().map(Into::into));
+        self
+    }
+
+    /// Truncate only the named tables, not their descendant tables.
+    pub fn only(mut self) -> Self {
+        self.only = true;
+        self
+    }
+
+    /// Restart the sequences owned by columns of the truncated tables.
+    pub fn restart_identity(mut self) -> Self {
+        self.restart_identity = Some(true);
+        self
+    }
+
+    /// Keep the sequences unchanged (default).
+    pub fn continue_identity(mut self) -> Self {
+        self.restart_identity = Some(false);
+        self
+    }
+
+    /// Add CASCADE to also truncate tables that reference the truncated tables.
+    pub fn cascade(mut self) -> Self {
+        self.behavior = Some(DropBehavior::Cascade);
+        self
+    }
+
+    /// Add RESTRICT to refuse to truncate referenced tables (explicitly).
+    pub fn restrict(mut self) -> Self {
+        self.behavior = Some(DropBehavior::Restrict);
+        self
+    }
+}
+
+fn write_truncate<W: SqlWriter>(w: &mut W, truncate: &Truncate) {
+    w.push_str("TRUNCATE ");
+    for (i, table) in truncate.tables.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        if truncate.only {
+            w.push_str("ONLY ");
+        }
+        write_table_name(w, table);
+    }
+    if let Some(restart_identity) = truncate.restart_identity {
+        w.push_str(if restart_identity {
+            " RESTART IDENTITY"
+        } else {
+            " CONTINUE IDENTITY"
+        });
+    }
+    if let Some(behavior) = truncate.behavior {
+        w.push_char(' ');
+        match behavior {
+            DropBehavior::Cascade => w.push_str("CASCADE"),
+            DropBehavior::Restrict => w.push_str("RESTRICT"),
+        }
+    }
+}
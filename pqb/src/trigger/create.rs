@@ -0,0 +1,363 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::expr::Expr;
+use crate::expr::write_expr;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::TableName;
+use crate::types::write_iden;
+use crate::types::write_table_name;
+use crate::value::write_string_value;
+use crate::writer::SqlWriter;
+
+/// CREATE TRIGGER statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateTrigger {
+    name: Option<Iden>,
+    or_replace: bool,
+    constraint: bool,
+    timing: Option<TriggerTiming>,
+    events: Vec<TriggerEvent>,
+    table: Option<TableName>,
+    referenced_table: Option<TableName>,
+    deferrable: Option<bool>,
+    initially_deferred: Option<bool>,
+    old_table: Option<Iden>,
+    new_table: Option<Iden>,
+    for_each_row: Option<bool>,
+    condition: Option<Expr>,
+    function: Option<TableName>,
+    args: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum TriggerTiming {
+    Before,
+    After,
+    InsteadOf,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum TriggerEvent {
+    Insert,
+    Update(Vec<Iden>),
+    Delete,
+    Truncate,
+}
+
+impl CreateTrigger {
+    /// Create a new CREATE TRIGGER statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_create_trigger(&mut w, self);
+        w
+    }
+
+    /// Convert the CREATE TRIGGER statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_create_trigger(&mut sql, self);
+        sql
+    }
+
+    /// Set the trigger name.
+    pub fn name<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Replace the trigger if it already exists.
+    pub fn or_replace(mut self) -> Self {
+        self.or_replace = true;
+        self
+    }
+
+    /// Create a constraint trigger, i.e., CREATE CONSTRAINT TRIGGER.
+    pub fn constraint(mut self) -> Self {
+        self.constraint = true;
+        self
+    }
+
+    /// Fire the trigger before the operation.
+    pub fn before(mut self) -> Self {
+        self.timing = Some(TriggerTiming::Before);
+        self
+    }
+
+    /// Fire the trigger after the operation.
+    pub fn after(mut self) -> Self {
+        self.timing = Some(TriggerTiming::After);
+        self
+    }
+
+    /// Fire the trigger instead of the operation, for views only.
+    pub fn instead_of(mut self) -> Self {
+        self.timing = Some(TriggerTiming::InsteadOf);
+        self
+    }
+
+    /// Fire the trigger on INSERT.
+    pub fn on_insert(mut self) -> Self {
+        self.events.push(TriggerEvent::Insert);
+        self
+    }
+
+    /// Fire the trigger on UPDATE.
+    pub fn on_update(mut self) -> Self {
+        self.events.push(TriggerEvent::Update(vec![]));
+        self
+    }
+
+    /// Fire the trigger on UPDATE of any of the given columns.
+    pub fn on_update_of<T, I>(mut self, columns: I) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        let columns = columns.into_iter().map(|c| c.into_iden()).collect();
+        self.events.push(TriggerEvent::Update(columns));
+        self
+    }
+
+    /// Fire the trigger on DELETE.
+    pub fn on_delete(mut self) -> Self {
+        self.events.push(TriggerEvent::Delete);
+        self
+    }
+
+    /// Fire the trigger on TRUNCATE.
+    pub fn on_truncate(mut self) -> Self {
+        self.events.push(TriggerEvent::Truncate);
+        self
+    }
+
+    /// Set the table or view the trigger is for.
+    pub fn table<T>(mut self, table: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.table = Some(table.into());
+        self
+    }
+
+    /// Set the table referenced by a constraint trigger, i.e., FROM.
+    pub fn from<T>(mut self, table: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.referenced_table = Some(table.into());
+        self
+    }
+
+    /// Mark the constraint trigger as DEFERRABLE.
+    pub fn deferrable(mut self) -> Self {
+        self.deferrable = Some(true);
+        self
+    }
+
+    /// Mark the constraint trigger as NOT DEFERRABLE.
+    pub fn not_deferrable(mut self) -> Self {
+        self.deferrable = Some(false);
+        self
+    }
+
+    /// Fire the constraint trigger at the end of the transaction by default.
+    pub fn initially_deferred(mut self) -> Self {
+        self.initially_deferred = Some(true);
+        self
+    }
+
+    /// Fire the constraint trigger after each statement by default.
+    pub fn initially_immediate(mut self) -> Self {
+        self.initially_deferred = Some(false);
+        self
+    }
+
+    /// Expose the rows before the operation as a transition table.
+    pub fn referencing_old_table<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.old_table = Some(name.into_iden());
+        self
+    }
+
+    /// Expose the rows after the operation as a transition table.
+    pub fn referencing_new_table<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.new_table = Some(name.into_iden());
+        self
+    }
+
+    /// Fire the trigger once for every modified row.
+    pub fn for_each_row(mut self) -> Self {
+        self.for_each_row = Some(true);
+        self
+    }
+
+    /// Fire the trigger once per statement.
+    pub fn for_each_statement(mut self) -> Self {
+        self.for_each_row = Some(false);
+        self
+    }
+
+    /// Only fire the trigger when the condition holds.
+    pub fn when<E>(mut self, condition: E) -> Self
+    where
+        E: Into<Expr>,
+    {
+        self.condition = Some(condition.into());
+        self
+    }
+
+    /// Set the trigger function to execute.
+    pub fn execute_function<T>(mut self, function: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.function = Some(function.into());
+        self
+    }
+
+    /// Add a string argument passed to the trigger function.
+    pub fn arg<A>(mut self, arg: A) -> Self
+    where
+        A: Into<String>,
+    {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// Add string arguments passed to the trigger function.
+    pub fn args<I, A>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = A>,
+        A: Into<String>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+}
+
+fn write_create_trigger<W: SqlWriter>(w: &mut W, create: &CreateTrigger) {
+    w.push_str("CREATE ");
+    if create.or_replace {
+        w.push_str("OR REPLACE ");
+    }
+    if create.constraint {
+        w.push_str("CONSTRAINT ");
+    }
+    w.push_str("TRIGGER ");
+    if let Some(name) = &create.name {
+        write_iden(w, name);
+    }
+
+    if let Some(timing) = create.timing {
+        w.push_str(match timing {
+            TriggerTiming::Before => " BEFORE",
+            TriggerTiming::After => " AFTER",
+            TriggerTiming::InsteadOf => " INSTEAD OF",
+        });
+    }
+    for (i, event) in create.events.iter().enumerate() {
+        w.push_str(if i > 0 { " OR " } else { " " });
+        match event {
+            TriggerEvent::Insert => w.push_str("INSERT"),
+            TriggerEvent::Update(columns) => {
+                w.push_str("UPDATE");
+                for (j, col) in columns.iter().enumerate() {
+                    w.push_str(if j > 0 { ", " } else { " OF " });
+                    write_iden(w, col);
+                }
+            }
+            TriggerEvent::Delete => w.push_str("DELETE"),
+            TriggerEvent::Truncate => w.push_str("TRUNCATE"),
+        }
+    }
+
+    w.push_str(" ON ");
+    if let Some(table) = &create.table {
+        write_table_name(w, table);
+    }
+    if let Some(referenced_table) = &create.referenced_table {
+        w.push_str(" FROM ");
+        write_table_name(w, referenced_table);
+    }
+    if let Some(deferrable) = create.deferrable {
+        w.push_str(if deferrable {
+            " DEFERRABLE"
+        } else {
+            " NOT DEFERRABLE"
+        });
+    }
+    if let Some(initially_deferred) = create.initially_deferred {
+        w.push_str(if initially_deferred {
+            " INITIALLY DEFERRED"
+        } else {
+            " INITIALLY IMMEDIATE"
+        });
+    }
+
+    if create.old_table.is_some() || create.new_table.is_some() {
+        w.push_str(" REFERENCING");
+        if let Some(old_table) = &create.old_table {
+            w.push_str(" OLD TABLE AS ");
+            write_iden(w, old_table);
+        }
+        if let Some(new_table) = &create.new_table {
+            w.push_str(" NEW TABLE AS ");
+            write_iden(w, new_table);
+        }
+    }
+    if let Some(for_each_row) = create.for_each_row {
+        w.push_str(if for_each_row {
+            " FOR EACH ROW"
+        } else {
+            " FOR EACH STATEMENT"
+        });
+    }
+    if let Some(condition) = &create.condition {
+        w.push_str(" WHEN (");
+        write_expr(w, condition);
+        w.push_char(')');
+    }
+
+    w.push_str(" EXECUTE FUNCTION ");
+    if let Some(function) = &create.function {
+        write_table_name(w, function);
+    }
+    w.push_char('(');
+    for (i, arg) in create.args.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        write_string_value(w, arg);
+    }
+    w.push_char(')');
+}
@@ -21,8 +21,12 @@ use crate::query::write_select;
 use crate::writer::SqlWriter;
 
 mod qualification;
+#[cfg(feature = "serde")]
+mod serialize;
 
 /// An identifier string.
+///
+/// With the `serde` feature, an identifier is serialized as a plain string.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Iden {
     name: Cow<'static, str>,
@@ -128,7 +132,8 @@ where
 pub struct Asterisk;
 
 /// Table references
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum TableRef {
     /// A table identifier with optional Alias. Potentially qualified.
@@ -170,7 +175,11 @@ pub trait IntoTableRef: Into<TableRef> {
 impl<T> IntoTableRef for T where T: Into<TableRef> {}
 
 /// Column references.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// With the `serde` feature, a column reference is externally tagged, e.g. `{"Column": ["users",
+/// "id"]}` for `"users"."id"` and `{"Asterisk": null}` for a bare `*`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum ColumnRef {
     /// A column name, potentially qualified as `(database.)(schema.)(table.)column`.
@@ -236,6 +245,7 @@ impl<T> IntoColumnRef for T where T: Into<ColumnRef> {}
 
 /// An identifier that represents a database name.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DatabaseName(pub Iden);
 
 /// A schema name, potentially qualified as `(database.)schema`.
@@ -243,15 +253,24 @@ pub struct DatabaseName(pub Iden);
 pub struct SchemaName(pub Option<DatabaseName>, pub Iden);
 
 /// A table name, potentially qualified as `(database.)(schema.)table`.
+///
+/// With the `serde` feature, a table name is serialized as a sequence of identifiers from the
+/// outermost qualifier to the table, e.g. `["public", "users"]`. Schema and column names follow
+/// the same representation.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TableName(pub Option<SchemaName>, pub Iden);
 
+/// A type name, potentially qualified as `(database.)(schema.)type`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TypeName(pub Option<SchemaName>, pub Iden);
+
 /// A column name, potentially qualified as `(database.)(schema.)(table.)column`.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ColumnName(pub Option<TableName>, pub Iden);
 
 /// Join types.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 #[expect(missing_docs)]
 pub enum JoinType {
@@ -260,7 +279,8 @@ pub enum JoinType {
 }
 
 /// Drop behavior for DROP statements.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DropBehavior {
     /// Drop dependent objects too.
     Cascade,
@@ -297,6 +317,15 @@ pub(crate) fn write_table_name<W: SqlWriter>(w: &mut W, table_name: &TableName)
     write_iden(w, table);
 }
 
+pub(crate) fn write_type_name<W: SqlWriter>(w: &mut W, type_name: &TypeName) {
+    let TypeName(schema_name, ty) = type_name;
+    if let Some(schema_name) = schema_name {
+        write_schema_name(w, schema_name);
+        w.push_char('.');
+    }
+    write_iden(w, ty);
+}
+
 pub(crate) fn write_schema_name<W: SqlWriter>(w: &mut W, schema_name: &SchemaName) {
     let SchemaName(database_name, schema) = schema_name;
     if let Some(DatabaseName(database)) = database_name {
@@ -20,6 +20,7 @@ use crate::types::Iden;
 use crate::types::IntoIden;
 use crate::types::SchemaName;
 use crate::types::TableName;
+use crate::types::TypeName;
 
 /// A name that can be unqualified (`foo`) or qualified once (`foo.bar`).
 ///
@@ -120,6 +121,17 @@ where
     }
 }
 
+/// Construct a [`TypeName`] from 1-3 parts (`(database?).(schema?).type`)
+impl<T> From<T> for TypeName
+where
+    T: MaybeQualifiedTwice,
+{
+    fn from(value: T) -> Self {
+        let TableName(schema_name, ty) = value.into();
+        TypeName(schema_name, ty)
+    }
+}
+
 /// Construct a [`ColumnName`] from 1-3 parts (`(schema?).(table?).column`)
 impl<T> From<T> for ColumnName
 where
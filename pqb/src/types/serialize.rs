@@ -0,0 +1,139 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serde representations of identifiers and qualified names.
+//!
+//! An [`Iden`] is serialized as a plain string. Qualified names are serialized as a sequence of
+//! identifiers from the outermost qualifier to the base name, e.g. `["public", "users"]` for the
+//! table `"public"."users"`, or `["users", "id"]` for the column `"users"."id"`.
+
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+use serde::de::Error;
+
+use crate::types::ColumnName;
+use crate::types::DatabaseName;
+use crate::types::Iden;
+use crate::types::SchemaName;
+use crate::types::TableName;
+use crate::types::TypeName;
+
+impl Serialize for Iden {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.name)
+    }
+}
+
+impl<'de> Deserialize<'de> for Iden {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Iden::new)
+    }
+}
+
+impl SchemaName {
+    fn into_parts(self) -> Vec<Iden> {
+        let SchemaName(database, schema) = self;
+        let mut parts = Vec::with_capacity(2);
+        if let Some(DatabaseName(database)) = database {
+            parts.push(database);
+        }
+        parts.push(schema);
+        parts
+    }
+
+    fn from_parts(mut parts: Vec<Iden>) -> Option<Self> {
+        let schema = parts.pop()?;
+        let database = match parts.len() {
+            0 => None,
+            1 => Some(DatabaseName(parts.pop()?)),
+            _ => return None,
+        };
+        Some(SchemaName(database, schema))
+    }
+}
+
+impl TableName {
+    fn into_parts(self) -> Vec<Iden> {
+        let TableName(schema, table) = self;
+        let mut parts = schema.map(SchemaName::into_parts).unwrap_or_default();
+        parts.push(table);
+        parts
+    }
+
+    fn from_parts(mut parts: Vec<Iden>) -> Option<Self> {
+        let table = parts.pop()?;
+        let schema = match parts.len() {
+            0 => None,
+            _ => Some(SchemaName::from_parts(parts)?),
+        };
+        Some(TableName(schema, table))
+    }
+}
+
+impl TypeName {
+    fn into_parts(self) -> Vec<Iden> {
+        let TypeName(schema, ty) = self;
+        TableName(schema, ty).into_parts()
+    }
+
+    fn from_parts(parts: Vec<Iden>) -> Option<Self> {
+        let TableName(schema, ty) = TableName::from_parts(parts)?;
+        Some(TypeName(schema, ty))
+    }
+}
+
+impl ColumnName {
+    fn into_parts(self) -> Vec<Iden> {
+        let ColumnName(table, column) = self;
+        let mut parts = table.map(TableName::into_parts).unwrap_or_default();
+        parts.push(column);
+        parts
+    }
+
+    fn from_parts(mut parts: Vec<Iden>) -> Option<Self> {
+        let column = parts.pop()?;
+        let table = match parts.len() {
+            0 => None,
+            _ => Some(TableName::from_parts(parts)?),
+        };
+        Some(ColumnName(table, column))
+    }
+}
+
+macro_rules! impl_serde_for_qualified_name {
+    ($name:ident, $max_parts:literal) => {
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.clone().into_parts().serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let parts = Vec::<Iden>::deserialize(deserializer)?;
+                let len = parts.len();
+                $name::from_parts(parts).ok_or_else(|| {
+                    D::Error::invalid_length(len, &concat!("1 to ", $max_parts, " identifiers"))
+                })
+            }
+        }
+    };
+}
+
+impl_serde_for_qualified_name!(SchemaName, 2);
+impl_serde_for_qualified_name!(TableName, 3);
+impl_serde_for_qualified_name!(TypeName, 3);
+impl_serde_for_qualified_name!(ColumnName, 4);
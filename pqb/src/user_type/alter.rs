@@ -0,0 +1,175 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::SqlWriterValues;
+use crate::types::TypeName;
+use crate::types::write_type_name;
+use crate::value::write_string_value;
+use crate::writer::SqlWriter;
+
+/// ALTER TYPE statement builder for enum types.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlterType {
+    name: Option<TypeName>,
+    action: Option<TypeAlterAction>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum TypeAlterAction {
+    AddValue {
+        value: String,
+        if_not_exists: bool,
+        position: Option<(EnumValuePosition, String)>,
+    },
+    RenameValue(String, String),
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum EnumValuePosition {
+    Before,
+    After,
+}
+
+impl AlterType {
+    /// Create a new ALTER TYPE statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_alter_type(&mut w, self);
+        w
+    }
+
+    /// Convert the ALTER TYPE statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_alter_type(&mut sql, self);
+        sql
+    }
+
+    /// Set the type name.
+    pub fn name<T>(mut self, name: T) -> Self
+    where
+        T: Into<TypeName>,
+    {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Add a new label at the end of the enum.
+    pub fn add_value<S>(self, value: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.add_value_at(value.into(), None)
+    }
+
+    /// Add a new label immediately before an existing one.
+    pub fn add_value_before<S, E>(self, value: S, existing: E) -> Self
+    where
+        S: Into<String>,
+        E: Into<String>,
+    {
+        self.add_value_at(
+            value.into(),
+            Some((EnumValuePosition::Before, existing.into())),
+        )
+    }
+
+    /// Add a new label immediately after an existing one.
+    pub fn add_value_after<S, E>(self, value: S, existing: E) -> Self
+    where
+        S: Into<String>,
+        E: Into<String>,
+    {
+        self.add_value_at(
+            value.into(),
+            Some((EnumValuePosition::After, existing.into())),
+        )
+    }
+
+    /// Do not throw an error if the added label already exists.
+    ///
+    /// ## Panics
+    /// This method will panic if no label is being added.
+    pub fn if_not_exists(mut self) -> Self {
+        match &mut self.action {
+            Some(TypeAlterAction::AddValue { if_not_exists, .. }) => *if_not_exists = true,
+            _ => panic!("IF NOT EXISTS requires ADD VALUE."),
+        }
+        self
+    }
+
+    /// Rename an existing label of the enum.
+    pub fn rename_value<S, T>(mut self, from: S, to: T) -> Self
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        self.action = Some(TypeAlterAction::RenameValue(from.into(), to.into()));
+        self
+    }
+
+    fn add_value_at(
+        mut self,
+        value: String,
+        position: Option<(EnumValuePosition, String)>,
+    ) -> Self {
+        self.action = Some(TypeAlterAction::AddValue {
+            value,
+            if_not_exists: false,
+            position,
+        });
+        self
+    }
+}
+
+fn write_alter_type<W: SqlWriter>(w: &mut W, alter: &AlterType) {
+    w.push_str("ALTER TYPE ");
+    if let Some(name) = &alter.name {
+        write_type_name(w, name);
+    }
+    match &alter.action {
+        Some(TypeAlterAction::AddValue {
+            value,
+            if_not_exists,
+            position,
+        }) => {
+            w.push_str(" ADD VALUE ");
+            if *if_not_exists {
+                w.push_str("IF NOT EXISTS ");
+            }
+            write_string_value(w, value);
+            if let Some((position, existing)) = position {
+                w.push_str(match position {
+                    EnumValuePosition::Before => " BEFORE ",
+                    EnumValuePosition::After => " AFTER ",
+                });
+                write_string_value(w, existing);
+            }
+        }
+        Some(TypeAlterAction::RenameValue(from, to)) => {
+            w.push_str(" RENAME VALUE ");
+            write_string_value(w, from);
+            w.push_str(" TO ");
+            write_string_value(w, to);
+        }
+        None => {}
+    }
+}
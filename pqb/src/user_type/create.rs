@@ -0,0 +1,225 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::SqlWriterValues;
+use crate::table::ColumnType;
+use crate::table::write_column_type;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::TypeName;
+use crate::types::write_iden;
+use crate::types::write_type_name;
+use crate::value::write_string_value;
+use crate::writer::SqlWriter;
+
+/// CREATE TYPE statement builder.
+///
+/// Without a definition, this creates a shell type to be completed later.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateType {
+    name: Option<TypeName>,
+    definition: Option<TypeDefinition>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum TypeDefinition {
+    Enum(Vec<String>),
+    Composite(Vec<(Iden, ColumnType)>),
+    Range(Box<RangeType>),
+}
+
+impl CreateType {
+    /// Create a new CREATE TYPE statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_create_type(&mut w, self);
+        w
+    }
+
+    /// Convert the CREATE TYPE statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_create_type(&mut sql, self);
+        sql
+    }
+
+    /// Set the type name.
+    pub fn name<T>(mut self, name: T) -> Self
+    where
+        T: Into<TypeName>,
+    {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Define an enum type with the given labels.
+    pub fn as_enum<I, S>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.definition = Some(TypeDefinition::Enum(
+            labels.into_iter().map(Into::into).collect(),
+        ));
+        self
+    }
+
+    /// Add an attribute to a composite type.
+    pub fn attribute<N>(mut self, name: N, ty: ColumnType) -> Self
+    where
+        N: IntoIden,
+    {
+        let attribute = (name.into_iden(), ty);
+        match &mut self.definition {
+            Some(TypeDefinition::Composite(attributes)) => attributes.push(attribute),
+            _ => self.definition = Some(TypeDefinition::Composite(vec![attribute])),
+        }
+        self
+    }
+
+    /// Define a range type.
+    pub fn as_range(mut self, range: RangeType) -> Self {
+        self.definition = Some(TypeDefinition::Range(Box::new(range)));
+        self
+    }
+}
+
+/// Definition of a range type for [`CreateType::as_range`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RangeType {
+    subtype: ColumnType,
+    subtype_opclass: Option<Iden>,
+    collation: Option<Iden>,
+    canonical: Option<Iden>,
+    subtype_diff: Option<Iden>,
+    multirange_type_name: Option<Iden>,
+}
+
+impl RangeType {
+    /// Create a range type over the given element type.
+    pub fn subtype(subtype: ColumnType) -> Self {
+        Self {
+            subtype,
+            subtype_opclass: None,
+            collation: None,
+            canonical: None,
+            subtype_diff: None,
+            multirange_type_name: None,
+        }
+    }
+
+    /// Set the B-tree operator class used to order the subtype.
+    pub fn subtype_opclass<N>(mut self, opclass: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.subtype_opclass = Some(opclass.into_iden());
+        self
+    }
+
+    /// Set the collation used to order the subtype.
+    pub fn collation<N>(mut self, collation: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.collation = Some(collation.into_iden());
+        self
+    }
+
+    /// Set the function that converts range values to canonical form.
+    pub fn canonical<N>(mut self, function: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.canonical = Some(function.into_iden());
+        self
+    }
+
+    /// Set the function that returns the difference between two subtype values.
+    pub fn subtype_diff<N>(mut self, function: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.subtype_diff = Some(function.into_iden());
+        self
+    }
+
+    /// Set the name of the corresponding multirange type.
+    pub fn multirange_type_name<N>(mut self, name: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.multirange_type_name = Some(name.into_iden());
+        self
+    }
+}
+
+fn write_create_type<W: SqlWriter>(w: &mut W, create: &CreateType) {
+    w.push_str("CREATE TYPE ");
+    if let Some(name) = &create.name {
+        write_type_name(w, name);
+    }
+    match &create.definition {
+        Some(TypeDefinition::Enum(labels)) => {
+            w.push_str(" AS ENUM (");
+            for (i, label) in labels.iter().enumerate() {
+                if i > 0 {
+                    w.push_str(", ");
+                }
+                write_string_value(w, label);
+            }
+            w.push_char(')');
+        }
+        Some(TypeDefinition::Composite(attributes)) => {
+            w.push_str(" AS (");
+            for (i, (name, ty)) in attributes.iter().enumerate() {
+                if i > 0 {
+                    w.push_str(", ");
+                }
+                write_iden(w, name);
+                w.push_char(' ');
+                write_column_type(w, ty);
+            }
+            w.push_char(')');
+        }
+        Some(TypeDefinition::Range(range)) => {
+            w.push_str(" AS RANGE (SUBTYPE = ");
+            write_column_type(w, &range.subtype);
+            let params = [
+                ("SUBTYPE_OPCLASS", &range.subtype_opclass),
+                ("COLLATION", &range.collation),
+                ("CANONICAL", &range.canonical),
+                ("SUBTYPE_DIFF", &range.subtype_diff),
+                ("MULTIRANGE_TYPE_NAME", &range.multirange_type_name),
+            ];
+            for (param, value) in params {
+                if let Some(value) = value {
+                    w.push_str(", ");
+                    w.push_str(param);
+                    w.push_str(" = ");
+                    write_iden(w, value);
+                }
+            }
+            w.push_char(')');
+        }
+        None => {}
+    }
+}
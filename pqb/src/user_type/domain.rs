@@ -0,0 +1,153 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::SqlWriterValues;
+use crate::expr::Expr;
+use crate::expr::write_expr;
+use crate::table::ColumnType;
+use crate::table::write_column_type;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::TypeName;
+use crate::types::write_iden;
+use crate::types::write_type_name;
+use crate::writer::SqlWriter;
+
+/// CREATE DOMAIN statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateDomain {
+    name: Option<TypeName>,
+    data_type: Option<ColumnType>,
+    collation: Option<Iden>,
+    default: Option<Expr>,
+    nullable: Option<bool>,
+    checks: Vec<(Option<Iden>, Expr)>,
+}
+
+impl CreateDomain {
+    /// Create a new CREATE DOMAIN statement.
+    pub fn new() -> Self {
+        // `Self::default` is the DEFAULT clause builder method
+        <Self as Default>::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_create_domain(&mut w, self);
+        w
+    }
+
+    /// Convert the CREATE DOMAIN statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_create_domain(&mut sql, self);
+        sql
+    }
+
+    /// Set the domain name.
+    pub fn name<T>(mut self, name: T) -> Self
+    where
+        T: Into<TypeName>,
+    {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the underlying data type of the domain.
+    pub fn as_type(mut self, ty: ColumnType) -> Self {
+        self.data_type = Some(ty);
+        self
+    }
+
+    /// Set the collation of the domain.
+    pub fn collate<N>(mut self, collation: N) -> Self
+    where
+        N: IntoIden,
+    {
+        self.collation = Some(collation.into_iden());
+        self
+    }
+
+    /// Set the default value for columns of the domain.
+    pub fn default<E>(mut self, expr: E) -> Self
+    where
+        E: Into<Expr>,
+    {
+        self.default = Some(expr.into());
+        self
+    }
+
+    /// Disallow null values.
+    pub fn not_null(mut self) -> Self {
+        self.nullable = Some(false);
+        self
+    }
+
+    /// Allow null values (explicitly).
+    pub fn null(mut self) -> Self {
+        self.nullable = Some(true);
+        self
+    }
+
+    /// Add a CHECK constraint. Use `Expr::custom("VALUE")` to refer to the value being tested.
+    pub fn check<E>(mut self, expr: E) -> Self
+    where
+        E: Into<Expr>,
+    {
+        self.checks.push((None, expr.into()));
+        self
+    }
+
+    /// Add a named CHECK constraint.
+    pub fn named_check<N, E>(mut self, name: N, expr: E) -> Self
+    where
+        N: IntoIden,
+        E: Into<Expr>,
+    {
+        self.checks.push((Some(name.into_iden()), expr.into()));
+        self
+    }
+}
+
+fn write_create_domain<W: SqlWriter>(w: &mut W, create: &CreateDomain) {
+    w.push_str("CREATE DOMAIN ");
+    if let Some(name) = &create.name {
+        write_type_name(w, name);
+    }
+    if let Some(ty) = &create.data_type {
+        w.push_str(" AS ");
+        write_column_type(w, ty);
+    }
+    if let Some(collation) = &create.collation {
+        w.push_str(" COLLATE ");
+        write_iden(w, collation);
+    }
+    if let Some(default) = &create.default {
+        w.push_str(" DEFAULT ");
+        write_expr(w, default);
+    }
+    if let Some(nullable) = create.nullable {
+        w.push_str(if nullable { " NULL" } else { " NOT NULL" });
+    }
+    for (name, check) in &create.checks {
+        if let Some(name) = name {
+            w.push_str(" CONSTRAINT ");
+            write_iden(w, name);
+        }
+        w.push_str(" CHECK (");
+        write_expr(w, check);
+        w.push_char(')');
+    }
+}
@@ -0,0 +1,193 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::types::DropBehavior;
+use crate::types::TypeName;
+use crate::types::write_type_name;
+use crate::writer::SqlWriter;
+
+/// DROP TYPE statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DropType {
+    names: Vec<TypeName>,
+    if_exists: bool,
+    behavior: Option<DropBehavior>,
+}
+
+impl DropType {
+    /// Create a new DROP TYPE statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_drop_type(&mut w, self);
+        w
+    }
+
+    /// Convert the DROP TYPE statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_drop_type(&mut sql, self);
+        sql
+    }
+
+    /// Add a type name to drop.
+    pub fn name<T>(mut self, name: T) -> Self
+    where
+        T: Into<TypeName>,
+    {
+        self.names.push(name.into());
+        self
+    }
+
+    /// Add multiple type names to drop.
+    pub fn names<I, T>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<TypeName>,
+    {
+        self.names.extend(names.into_iter().map(Into::into));
+        self
+    }
+
+    /// Drop the type if it exists.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Add CASCADE to drop dependent objects.
+    pub fn cascade(mut self) -> Self {
+        self.behavior = Some(DropBehavior::Cascade);
+        self
+    }
+
+    /// Add RESTRICT to drop (explicitly).
+    pub fn restrict(mut self) -> Self {
+        self.behavior = Some(DropBehavior::Restrict);
+        self
+    }
+}
+
+fn write_drop_type<W: SqlWriter>(w: &mut W, drop_type: &DropType) {
+    w.push_str("DROP TYPE ");
+    if drop_type.if_exists {
+        w.push_str("IF EXISTS ");
+    }
+    for (i, name) in drop_type.names.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        write_type_name(w, name);
+    }
+    if let Some(behavior) = drop_type.behavior {
+        w.push_char(' ');
+        match behavior {
+            DropBehavior::Cascade => w.push_str("CASCADE"),
+            DropBehavior::Restrict => w.push_str("RESTRICT"),
+        }
+    }
+}
+
+/// DROP DOMAIN statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DropDomain {
+    names: Vec<TypeName>,
+    if_exists: bool,
+    behavior: Option<DropBehavior>,
+}
+
+impl DropDomain {
+    /// Create a new DROP DOMAIN statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_drop_domain(&mut w, self);
+        w
+    }
+
+    /// Convert the DROP DOMAIN statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_drop_domain(&mut sql, self);
+        sql
+    }
+
+    /// Add a domain name to drop.
+    pub fn name<T>(mut self, name: T) -> Self
+    where
+        T: Into<TypeName>,
+    {
+        self.names.push(name.into());
+        self
+    }
+
+    /// Add multiple domain names to drop.
+    pub fn names<I, T>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<TypeName>,
+    {
+        self.names.extend(names.into_iter().map(Into::into));
+        self
+    }
+
+    /// Drop the domain if it exists.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Add CASCADE to drop dependent objects.
+    pub fn cascade(mut self) -> Self {
+        self.behavior = Some(DropBehavior::Cascade);
+        self
+    }
+
+    /// Add RESTRICT to drop (explicitly).
+    pub fn restrict(mut self) -> Self {
+        self.behavior = Some(DropBehavior::Restrict);
+        self
+    }
+}
+
+fn write_drop_domain<W: SqlWriter>(w: &mut W, drop_domain: &DropDomain) {
+    w.push_str("DROP DOMAIN ");
+    if drop_domain.if_exists {
+        w.push_str("IF EXISTS ");
+    }
+    for (i, name) in drop_domain.names.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        write_type_name(w, name);
+    }
+    if let Some(behavior) = drop_domain.behavior {
+        w.push_char(' ');
+        match behavior {
+            DropBehavior::Cascade => w.push_str("CASCADE"),
+            DropBehavior::Restrict => w.push_str("RESTRICT"),
+        }
+    }
+}
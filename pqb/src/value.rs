@@ -14,6 +14,10 @@
 
 //! Container for all SQL value types.
 
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::mem;
+
 #[cfg(feature = "with-json")]
 pub use serde_json::Value as Json;
 #[cfg(feature = "with-uuid")]
@@ -22,7 +26,12 @@ pub use uuid::Uuid;
 use crate::writer::SqlWriter;
 
 /// SQL value variants.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Floating-point values are compared and hashed by their bit patterns, so that values (and the
+/// statements containing them) can be used as hash keys. As a consequence, `NaN` equals itself and
+/// `0.0` does not equal `-0.0`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[expect(missing_docs)]
 pub enum Value {
     Bool(Option<bool>),
@@ -44,6 +53,58 @@ pub enum Value {
     Uuid(Option<Uuid>),
 }
 
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Value::Bool(a), Value::Bool(b)) => a == b,
+            (Value::TinyInt(a), Value::TinyInt(b)) => a == b,
+            (Value::SmallInt(a), Value::SmallInt(b)) => a == b,
+            (Value::Int(a), Value::Int(b)) => a == b,
+            (Value::BigInt(a), Value::BigInt(b)) => a == b,
+            (Value::TinyUnsigned(a), Value::TinyUnsigned(b)) => a == b,
+            (Value::SmallUnsigned(a), Value::SmallUnsigned(b)) => a == b,
+            (Value::Unsigned(a), Value::Unsigned(b)) => a == b,
+            (Value::BigUnsigned(a), Value::BigUnsigned(b)) => a == b,
+            (Value::Float(a), Value::Float(b)) => a.map(f32::to_bits) == b.map(f32::to_bits),
+            (Value::Double(a), Value::Double(b)) => a.map(f64::to_bits) == b.map(f64::to_bits),
+            (Value::String(a), Value::String(b)) => a == b,
+            (Value::Array(a), Value::Array(b)) => a == b,
+            #[cfg(feature = "with-json")]
+            (Value::Json(a), Value::Json(b)) => a == b,
+            #[cfg(feature = "with-uuid")]
+            (Value::Uuid(a), Value::Uuid(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Value {}
+
+impl Hash for Value {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match self {
+            Value::Bool(v) => v.hash(state),
+            Value::TinyInt(v) => v.hash(state),
+            Value::SmallInt(v) => v.hash(state),
+            Value::Int(v) => v.hash(state),
+            Value::BigInt(v) => v.hash(state),
+            Value::TinyUnsigned(v) => v.hash(state),
+            Value::SmallUnsigned(v) => v.hash(state),
+            Value::Unsigned(v) => v.hash(state),
+            Value::BigUnsigned(v) => v.hash(state),
+            Value::Float(v) => v.map(f32::to_bits).hash(state),
+            Value::Double(v) => v.map(f64::to_bits).hash(state),
+            Value::String(v) => v.hash(state),
+            Value::Array(v) => v.hash(state),
+            #[cfg(feature = "with-json")]
+            Value::Json(v) => v.hash(state),
+            #[cfg(feature = "with-uuid")]
+            Value::Uuid(v) => v.hash(state),
+        }
+    }
+}
+
 impl Value {
     /// Create a new array value.
     pub fn array<T, I>(values: I) -> Value
@@ -203,7 +264,7 @@ fn write_array_value<W: SqlWriter>(w: &mut W, values: &[Value]) {
     }
 }
 
-fn write_string_value<W: SqlWriter>(w: &mut W, value: &str) {
+pub(crate) fn write_string_value<W: SqlWriter>(w: &mut W, value: &str) {
     if should_escape(value) {
         write_string_escaped(w, value)
     } else {
@@ -213,6 +274,24 @@ fn write_string_value<W: SqlWriter>(w: &mut W, value: &str) {
     }
 }
 
+/// Write a dollar-quoted string, picking a tag that does not occur in the body.
+pub(crate) fn write_dollar_quoted<W: SqlWriter>(w: &mut W, body: &str) {
+    let is_safe = |tag: &str| format!("{body}{tag}").find(tag) == Some(body.len());
+    let mut tag = "$$".to_string();
+    let mut n = 0;
+    while !is_safe(&tag) {
+        tag = if n == 0 {
+            "$body$".to_string()
+        } else {
+            format!("$body{n}$")
+        };
+        n += 1;
+    }
+    w.push_str(&tag);
+    w.push_str(body);
+    w.push_str(&tag);
+}
+
 fn write_string_escaped<W: SqlWriter>(w: &mut W, value: &str) {
     w.push_str("E'");
     for c in value.chars() {
@@ -0,0 +1,328 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::expr::Expr;
+use crate::index::IndexOption;
+use crate::index::write_index_options;
+use crate::query::Select;
+use crate::query::write_select;
+use crate::types::Iden;
+use crate::types::IntoIden;
+use crate::types::TableName;
+use crate::types::write_iden;
+use crate::types::write_table_name;
+use crate::writer::SqlWriter;
+
+/// CREATE VIEW statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateView {
+    view: Option<TableName>,
+    or_replace: bool,
+    temporary: bool,
+    recursive: bool,
+    columns: Vec<Iden>,
+    options: Vec<IndexOption>,
+    query: Option<Select>,
+    check_option: Option<ViewCheckOption>,
+}
+
+/// The scope of the WITH CHECK OPTION clause of a view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum ViewCheckOption {
+    /// Check the conditions of this view and all underlying views.
+    Cascaded,
+    /// Only check the conditions of this view.
+    Local,
+}
+
+impl CreateView {
+    /// Create a new CREATE VIEW statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_create_view(&mut w, self);
+        w
+    }
+
+    /// Convert the CREATE VIEW statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_create_view(&mut sql, self);
+        sql
+    }
+
+    /// Set the view name.
+    pub fn view<T>(mut self, view: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.view = Some(view.into());
+        self
+    }
+
+    /// Replace the view if it already exists.
+    pub fn or_replace(mut self) -> Self {
+        self.or_replace = true;
+        self
+    }
+
+    /// Create a temporary view.
+    pub fn temporary(mut self) -> Self {
+        self.temporary = true;
+        self
+    }
+
+    /// Create a recursive view.
+    ///
+    /// A recursive view requires an explicit column list.
+    pub fn recursive(mut self) -> Self {
+        self.recursive = true;
+        self
+    }
+
+    /// Add a view column name.
+    pub fn column<C>(mut self, column: C) -> Self
+    where
+        C: IntoIden,
+    {
+        self.columns.push(column.into_iden());
+        self
+    }
+
+    /// Add view column names.
+    pub fn columns<T, I>(mut self, columns: I) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.columns
+            .extend(columns.into_iter().map(|c| c.into_iden()));
+        self
+    }
+
+    /// Add a view option to the WITH clause, e.g. `security_barrier`.
+    pub fn with_option<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: IntoIden,
+        V: Into<Expr>,
+    {
+        self.options.push(IndexOption::new(name, value));
+        self
+    }
+
+    /// Set the query that defines the view.
+    pub fn select(mut self, select: Select) -> Self {
+        self.query = Some(select);
+        self
+    }
+
+    /// Check that rows inserted or updated through the view satisfy the view condition.
+    pub fn check_option(mut self, check_option: ViewCheckOption) -> Self {
+        self.check_option = Some(check_option);
+        self
+    }
+}
+
+/// CREATE MATERIALIZED VIEW statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateMaterializedView {
+    view: Option<TableName>,
+    if_not_exists: bool,
+    columns: Vec<Iden>,
+    method: Option<Iden>,
+    options: Vec<IndexOption>,
+    tablespace: Option<Iden>,
+    query: Option<Select>,
+    with_data: Option<bool>,
+}
+
+impl CreateMaterializedView {
+    /// Create a new CREATE MATERIALIZED VIEW statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_create_materialized_view(&mut w, self);
+        w
+    }
+
+    /// Convert the CREATE MATERIALIZED VIEW statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_create_materialized_view(&mut sql, self);
+        sql
+    }
+
+    /// Set the materialized view name.
+    pub fn view<T>(mut self, view: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.view = Some(view.into());
+        self
+    }
+
+    /// Create the materialized view if it does not exist.
+    pub fn if_not_exists(mut self) -> Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Add a column name.
+    pub fn column<C>(mut self, column: C) -> Self
+    where
+        C: IntoIden,
+    {
+        self.columns.push(column.into_iden());
+        self
+    }
+
+    /// Add column names.
+    pub fn columns<T, I>(mut self, columns: I) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.columns
+            .extend(columns.into_iter().map(|c| c.into_iden()));
+        self
+    }
+
+    /// Set the table access method.
+    pub fn using<M>(mut self, method: M) -> Self
+    where
+        M: IntoIden,
+    {
+        self.method = Some(method.into_iden());
+        self
+    }
+
+    /// Add a storage parameter to the WITH clause.
+    pub fn with_option<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: IntoIden,
+        V: Into<Expr>,
+    {
+        self.options.push(IndexOption::new(name, value));
+        self
+    }
+
+    /// Set the tablespace.
+    pub fn tablespace<T>(mut self, tablespace: T) -> Self
+    where
+        T: IntoIden,
+    {
+        self.tablespace = Some(tablespace.into_iden());
+        self
+    }
+
+    /// Set the query that defines the materialized view.
+    pub fn select(mut self, select: Select) -> Self {
+        self.query = Some(select);
+        self
+    }
+
+    /// Whether to populate the materialized view at creation time (WITH [NO] DATA).
+    pub fn with_data(mut self, with_data: bool) -> Self {
+        self.with_data = Some(with_data);
+        self
+    }
+}
+
+fn write_create_view<W: SqlWriter>(w: &mut W, create: &CreateView) {
+    w.push_str("CREATE ");
+    if create.or_replace {
+        w.push_str("OR REPLACE ");
+    }
+    if create.temporary {
+        w.push_str("TEMPORARY ");
+    }
+    if create.recursive {
+        w.push_str("RECURSIVE ");
+    }
+    w.push_str("VIEW ");
+    if let Some(view) = &create.view {
+        write_table_name(w, view);
+    }
+    write_view_columns(w, &create.columns);
+    write_index_options(w, &create.options);
+    if let Some(query) = &create.query {
+        w.push_str(" AS ");
+        write_select(w, query);
+    }
+    if let Some(check_option) = create.check_option {
+        w.push_str(match check_option {
+            ViewCheckOption::Cascaded => " WITH CASCADED CHECK OPTION",
+            ViewCheckOption::Local => " WITH LOCAL CHECK OPTION",
+        });
+    }
+}
+
+fn write_create_materialized_view<W: SqlWriter>(w: &mut W, create: &CreateMaterializedView) {
+    w.push_str("CREATE MATERIALIZED VIEW ");
+    if create.if_not_exists {
+        w.push_str("IF NOT EXISTS ");
+    }
+    if let Some(view) = &create.view {
+        write_table_name(w, view);
+    }
+    write_view_columns(w, &create.columns);
+    if let Some(method) = &create.method {
+        w.push_str(" USING ");
+        write_iden(w, method);
+    }
+    write_index_options(w, &create.options);
+    if let Some(tablespace) = &create.tablespace {
+        w.push_str(" TABLESPACE ");
+        write_iden(w, tablespace);
+    }
+    if let Some(query) = &create.query {
+        w.push_str(" AS ");
+        write_select(w, query);
+    }
+    if let Some(with_data) = create.with_data {
+        w.push_str(if with_data {
+            " WITH DATA"
+        } else {
+            " WITH NO DATA"
+        });
+    }
+}
+
+fn write_view_columns<W: SqlWriter>(w: &mut W, columns: &[Iden]) {
+    if columns.is_empty() {
+        return;
+    }
+
+    w.push_str(" (");
+    for (i, col) in columns.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        write_iden(w, col);
+    }
+    w.push_str(")");
+}
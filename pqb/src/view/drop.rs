@@ -0,0 +1,193 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::types::DropBehavior;
+use crate::types::TableName;
+use crate::types::write_table_name;
+use crate::writer::SqlWriter;
+
+/// DROP VIEW statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DropView {
+    views: Vec<TableName>,
+    if_exists: bool,
+    behavior: Option<DropBehavior>,
+}
+
+impl DropView {
+    /// Create a new DROP VIEW statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_drop_view(&mut w, self);
+        w
+    }
+
+    /// Convert the DROP VIEW statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_drop_view(&mut sql, self);
+        sql
+    }
+
+    /// Add a view name to drop.
+    pub fn view<T>(mut self, view: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.views.push(view.into());
+        self
+    }
+
+    /// Add multiple view names to drop.
+    pub fn views<I, T>(mut self, views: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<TableName>,
+    {
+        self.views.extend(views.into_iter().map(Into::into));
+        self
+    }
+
+    /// Drop the view if it exists.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Add CASCADE to drop dependent objects.
+    pub fn cascade(mut self) -> Self {
+        self.behavior = Some(DropBehavior::Cascade);
+        self
+    }
+
+    /// Add RESTRICT to drop (explicitly).
+    pub fn restrict(mut self) -> Self {
+        self.behavior = Some(DropBehavior::Restrict);
+        self
+    }
+}
+
+fn write_drop_view<W: SqlWriter>(w: &mut W, drop_view: &DropView) {
+    w.push_str("DROP VIEW ");
+    if drop_view.if_exists {
+        w.push_str("IF EXISTS ");
+    }
+    for (i, view) in drop_view.views.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        write_table_name(w, view);
+    }
+    if let Some(behavior) = drop_view.behavior {
+        w.push_char(' ');
+        match behavior {
+            DropBehavior::Cascade => w.push_str("CASCADE"),
+            DropBehavior::Restrict => w.push_str("RESTRICT"),
+        }
+    }
+}
+
+/// DROP MATERIALIZED VIEW statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DropMaterializedView {
+    views: Vec<TableName>,
+    if_exists: bool,
+    behavior: Option<DropBehavior>,
+}
+
+impl DropMaterializedView {
+    /// Create a new DROP MATERIALIZED VIEW statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_drop_materialized_view(&mut w, self);
+        w
+    }
+
+    /// Convert the DROP MATERIALIZED VIEW statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_drop_materialized_view(&mut sql, self);
+        sql
+    }
+
+    /// Add a materialized view name to drop.
+    pub fn view<T>(mut self, view: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.views.push(view.into());
+        self
+    }
+
+    /// Add multiple materialized view names to drop.
+    pub fn views<I, T>(mut self, views: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<TableName>,
+    {
+        self.views.extend(views.into_iter().map(Into::into));
+        self
+    }
+
+    /// Drop the materialized view if it exists.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Add CASCADE to drop dependent objects.
+    pub fn cascade(mut self) -> Self {
+        self.behavior = Some(DropBehavior::Cascade);
+        self
+    }
+
+    /// Add RESTRICT to drop (explicitly).
+    pub fn restrict(mut self) -> Self {
+        self.behavior = Some(DropBehavior::Restrict);
+        self
+    }
+}
+
+fn write_drop_materialized_view<W: SqlWriter>(w: &mut W, drop_view: &DropMaterializedView) {
+    w.push_str("DROP MATERIALIZED VIEW ");
+    if drop_view.if_exists {
+        w.push_str("IF EXISTS ");
+    }
+    for (i, view) in drop_view.views.iter().enumerate() {
+        if i > 0 {
+            w.push_str(", ");
+        }
+        write_table_name(w, view);
+    }
+    if let Some(behavior) = drop_view.behavior {
+        w.push_char(' ');
+        match behavior {
+            DropBehavior::Cascade => w.push_str("CASCADE"),
+            DropBehavior::Restrict => w.push_str("RESTRICT"),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::SqlWriterValues;
+use crate::types::TableName;
+use crate::types::write_table_name;
+use crate::writer::SqlWriter;
+
+/// REFRESH MATERIALIZED VIEW statement builder.
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RefreshMaterializedView {
+    view: Option<TableName>,
+    concurrently: bool,
+    with_data: Option<bool>,
+}
+
+impl RefreshMaterializedView {
+    /// Create a new REFRESH MATERIALIZED VIEW statement.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the SQL string with placeholders and return collected values.
+    pub fn to_values(&self) -> SqlWriterValues {
+        let mut w = SqlWriterValues::new();
+        write_refresh_materialized_view(&mut w, self);
+        w
+    }
+
+    /// Convert the REFRESH MATERIALIZED VIEW statement to a PostgreSQL query string.
+    pub fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        write_refresh_materialized_view(&mut sql, self);
+        sql
+    }
+
+    /// Set the materialized view name.
+    pub fn view<T>(mut self, view: T) -> Self
+    where
+        T: Into<TableName>,
+    {
+        self.view = Some(view.into());
+        self
+    }
+
+    /// Refresh without locking out concurrent selects.
+    ///
+    /// This requires a unique index on the materialized view.
+    pub fn concurrently(mut self) -> Self {
+        self.concurrently = true;
+        self
+    }
+
+    /// Whether to repopulate the materialized view (WITH [NO] DATA).
+    pub fn with_data(mut self, with_data: bool) -> Self {
+        self.with_data = Some(with_data);
+        self
+    }
+}
+
+fn write_refresh_materialized_view<W: SqlWriter>(w: &mut W, refresh: &RefreshMaterializedView) {
+    w.push_str("REFRESH MATERIALIZED VIEW ");
+    if refresh.concurrently {
+        w.push_str("CONCURRENTLY ");
+    }
+    if let Some(view) = &refresh.view {
+        write_table_name(w, view);
+    }
+    if let Some(with_data) = refresh.with_data {
+        w.push_str(if with_data {
+            " WITH DATA"
+        } else {
+            " WITH NO DATA"
+        });
+    }
+}
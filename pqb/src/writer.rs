@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt;
 use std::fmt::Arguments;
 use std::fmt::Write;
+use std::hash::Hasher;
 
 use crate::value::Value;
 use crate::value::write_value;
@@ -46,6 +48,18 @@ impl SqlWriter for String {
     }
 }
 
+/// Write the value of a boolean option in a parenthesized option list, e.g. of EXPLAIN or COPY.
+///
+/// You can write TRUE, ON, or 1 to enable the option, and FALSE, OFF, or 0 to disable it. The
+/// boolean value can also be omitted, in which case TRUE is assumed.
+///
+/// @see https://www.postgresql.org/docs/18/sql-explain.html
+pub(crate) fn write_false<W: SqlWriter>(w: &mut W, value: bool) {
+    if !value {
+        w.push_str(" 0");
+    }
+}
+
 /// SQL writer that collects parameters for prepared statements.
 pub struct SqlWriterValues {
     sql: String,
@@ -94,3 +108,45 @@ impl SqlWriter for SqlWriterValues {
         self.sql.write_fmt(args).unwrap();
     }
 }
+
+/// SQL writer that feeds the statement shape into a hasher.
+///
+/// Parameters are written as placeholders, exactly as [`SqlWriterValues`] does, so that statements
+/// differing only in their bound values produce the same hash.
+pub(crate) struct SqlWriterHasher<'a, H: Hasher> {
+    state: &'a mut H,
+    counter: usize,
+}
+
+impl<'a, H: Hasher> SqlWriterHasher<'a, H> {
+    pub(crate) fn new(state: &'a mut H) -> Self {
+        Self { state, counter: 0 }
+    }
+}
+
+impl<H: Hasher> Write for SqlWriterHasher<'_, H> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.state.write(s.as_bytes());
+        Ok(())
+    }
+}
+
+impl<H: Hasher> SqlWriter for SqlWriterHasher<'_, H> {
+    fn push_param(&mut self, _: Value) {
+        self.counter += 1;
+        let counter = self.counter;
+        write!(self, "${counter}").unwrap();
+    }
+
+    fn push_str(&mut self, value: &str) {
+        self.state.write(value.as_bytes());
+    }
+
+    fn push_char(&mut self, value: char) {
+        self.write_char(value).unwrap();
+    }
+
+    fn push_fmt(&mut self, args: Arguments) {
+        self.write_fmt(args).unwrap();
+    }
+}
@@ -0,0 +1,303 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use insta::assert_snapshot;
+use pqb::expr::Expr;
+use pqb::func::FunctionCall;
+use pqb::sequence::SequenceOptions;
+use pqb::table::AlterTable;
+use pqb::table::ColumnDef;
+use pqb::table::ColumnStorage;
+use pqb::table::ColumnType;
+use pqb::table::DropConstraint;
+use pqb::table::IdentityKind;
+use pqb::table::PartitionBound;
+use pqb::table::PrimaryKey;
+use pqb::table::References;
+use pqb::table::TableConstraint;
+use pqb::table::Unique;
+
+#[test]
+fn alter_table_columns() {
+    assert_snapshot!(
+        AlterTable::new()
+            .table("users")
+            .add_column(ColumnDef::new("age").int().not_null().default(Expr::value(0)))
+            .rename_column("name", "full_name")
+            .drop_column("legacy")
+            .to_sql(),
+        @r#"ALTER TABLE "users" ADD COLUMN "age" integer NOT NULL DEFAULT 0, RENAME COLUMN "name" TO "full_name", DROP COLUMN "legacy""#
+    );
+}
+
+#[test]
+fn alter_table_alter_column() {
+    assert_snapshot!(
+        AlterTable::new()
+            .table("users")
+            .alter_column_type("age", ColumnType::BigInt)
+            .alter_column_type_using(
+                "score",
+                ColumnType::Int,
+                FunctionCall::cast_as(Expr::column("score"), "integer"),
+            )
+            .drop_default("created_at")
+            .set_statistics("email", 500)
+            .set_storage("bio", ColumnStorage::External)
+            .to_sql(),
+        @r#"ALTER TABLE "users" ALTER COLUMN "age" TYPE bigint, ALTER COLUMN "score" TYPE integer USING CAST("score" AS integer), ALTER COLUMN "created_at" DROP DEFAULT, ALTER COLUMN "email" SET STATISTICS 500, ALTER COLUMN "bio" SET STORAGE EXTERNAL"#
+    );
+    assert_snapshot!(
+        AlterTable::new()
+            .table("calc")
+            .set_expression("total", Expr::column("a").add(Expr::column("b")))
+            .drop_expression("legacy_total")
+            .to_sql(),
+        @r#"ALTER TABLE "calc" ALTER COLUMN "total" SET EXPRESSION AS ("a" + "b"), ALTER COLUMN "legacy_total" DROP EXPRESSION"#
+    );
+}
+
+#[test]
+fn alter_table_identity() {
+    assert_snapshot!(
+        AlterTable::new()
+            .table("users")
+            .add_identity("id", IdentityKind::Always, SequenceOptions::new())
+            .add_identity(
+                "legacy_id",
+                IdentityKind::ByDefault,
+                SequenceOptions::new().start_with(1000),
+            )
+            .to_sql(),
+        @r#"ALTER TABLE "users" ALTER COLUMN "id" ADD GENERATED ALWAYS AS IDENTITY, ALTER COLUMN "legacy_id" ADD GENERATED BY DEFAULT AS IDENTITY (START WITH 1000)"#
+    );
+    assert_snapshot!(
+        AlterTable::new()
+            .table("users")
+            .set_generated("id", IdentityKind::ByDefault)
+            .set_identity_options("id", SequenceOptions::new().increment_by(2).no_cycle())
+            .restart_identity("id")
+            .restart_identity_with("legacy_id", 1)
+            .drop_identity("old_id")
+            .drop_identity_if_exists("other_id")
+            .to_sql(),
+        @r#"ALTER TABLE "users" ALTER COLUMN "id" SET GENERATED BY DEFAULT, ALTER COLUMN "id" SET INCREMENT BY 2 SET NO CYCLE, ALTER COLUMN "id" RESTART, ALTER COLUMN "legacy_id" RESTART WITH 1, ALTER COLUMN "old_id" DROP IDENTITY, ALTER COLUMN "other_id" DROP IDENTITY IF EXISTS"#
+    );
+}
+
+#[test]
+fn alter_table_triggers() {
+    assert_snapshot!(
+        AlterTable::new()
+            .table("users")
+            .disable_trigger("users_audit")
+            .enable_trigger("users_touch")
+            .enable_replica_trigger("users_sync")
+            .enable_always_trigger("users_guard")
+            .to_sql(),
+        @r#"ALTER TABLE "users" DISABLE TRIGGER "users_audit", ENABLE TRIGGER "users_touch", ENABLE REPLICA TRIGGER "users_sync", ENABLE ALWAYS TRIGGER "users_guard""#
+    );
+    assert_snapshot!(
+        AlterTable::new()
+            .table("users")
+            .disable_all_triggers()
+            .enable_all_triggers()
+            .to_sql(),
+        @r#"ALTER TABLE "users" DISABLE TRIGGER ALL, ENABLE TRIGGER ALL"#
+    );
+}
+
+#[test]
+fn alter_table_constraint_using_index() {
+    assert_snapshot!(
+        AlterTable::new()
+            .table("users")
+            .add_constraint(
+                TableConstraint::primary_key(PrimaryKey::using_index("users_id_idx"))
+                    .name("users_pkey"),
+            )
+            .add_constraint(
+                TableConstraint::unique(Unique::using_index("users_email_idx"))
+                    .name("users_email_key")
+                    .deferrable()
+                    .initially_deferred(),
+            )
+            .to_sql(),
+        @r#"ALTER TABLE "users" ADD CONSTRAINT "users_pkey" PRIMARY KEY USING INDEX "users_id_idx", ADD CONSTRAINT "users_email_key" UNIQUE USING INDEX "users_email_idx" DEFERRABLE INITIALLY DEFERRED"#
+    );
+    assert_snapshot!(
+        AlterTable::new()
+            .table("users")
+            .add_constraint(TableConstraint::primary_key(PrimaryKey::columns(["id"])))
+            .add_constraint(
+                TableConstraint::unique(Unique::columns(["email"]).nulls_not_distinct()),
+            )
+            .to_sql(),
+        @r#"ALTER TABLE "users" ADD PRIMARY KEY ("id"), ADD UNIQUE NULLS NOT DISTINCT ("email")"#
+    );
+}
+
+#[test]
+fn alter_table_constraints() {
+    assert_snapshot!(
+        AlterTable::new()
+            .table("orders")
+            .add_constraint(
+                TableConstraint::check(Expr::column("quantity").gt(0)).name("positive_quantity"),
+            )
+            .add_constraint_not_valid(
+                TableConstraint::foreign_key(["user_id"], References::table("users"))
+                    .name("orders_user_fk"),
+            )
+            .drop_constraint("old_check")
+            .drop_constraint(DropConstraint::new("old_fk").if_exists().cascade())
+            .to_sql(),
+        @r#"ALTER TABLE "orders" ADD CONSTRAINT "positive_quantity" CHECK ("quantity" > 0), ADD CONSTRAINT "orders_user_fk" FOREIGN KEY ("user_id") REFERENCES "users" NOT VALID, DROP CONSTRAINT "old_check", DROP CONSTRAINT IF EXISTS "old_fk" CASCADE"#
+    );
+    assert_snapshot!(
+        AlterTable::new()
+            .table("orders")
+            .validate_constraint("orders_user_fk")
+            .to_sql(),
+        @r#"ALTER TABLE "orders" VALIDATE CONSTRAINT "orders_user_fk""#
+    );
+    assert_snapshot!(
+        AlterTable::new()
+            .table("orders")
+            .rename_constraint("orders_user_fk", "orders_customer_fk")
+            .to_sql(),
+        @r#"ALTER TABLE "orders" RENAME CONSTRAINT "orders_user_fk" TO "orders_customer_fk""#
+    );
+}
+
+#[test]
+fn alter_table_table_options() {
+    assert_snapshot!(
+        AlterTable::new()
+            .table(("public", "users"))
+            .if_exists()
+            .rename_to("accounts")
+            .to_sql(),
+        @r#"ALTER TABLE IF EXISTS "public"."users" RENAME TO "accounts""#
+    );
+    assert_snapshot!(
+        AlterTable::new()
+            .table("users")
+            .set_schema("archive")
+            .to_sql(),
+        @r#"ALTER TABLE "users" SET SCHEMA "archive""#
+    );
+    assert_snapshot!(
+        AlterTable::new()
+            .table("measurements")
+            .only()
+            .owner_to("app_owner")
+            .enable_row_level_security()
+            .to_sql(),
+        @r#"ALTER TABLE ONLY "measurements" OWNER TO "app_owner", ENABLE ROW LEVEL SECURITY"#
+    );
+    assert_snapshot!(
+        AlterTable::new()
+            .table("measurements")
+            .disable_row_level_security()
+            .to_sql(),
+        @r#"ALTER TABLE "measurements" DISABLE ROW LEVEL SECURITY"#
+    );
+    assert_snapshot!(
+        AlterTable::new()
+            .table("documents")
+            .enable_row_level_security()
+            .force_row_level_security()
+            .to_sql(),
+        @r#"ALTER TABLE "documents" ENABLE ROW LEVEL SECURITY, FORCE ROW LEVEL SECURITY"#
+    );
+    assert_snapshot!(
+        AlterTable::new()
+            .table("documents")
+            .no_force_row_level_security()
+            .to_sql(),
+        @r#"ALTER TABLE "documents" NO FORCE ROW LEVEL SECURITY"#
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "RENAME TO, SET SCHEMA, RENAME CONSTRAINT, ATTACH PARTITION and DETACH PARTITION cannot be combined with other actions."
+)]
+fn alter_table_rename_with_other_action_should_panic() {
+    let _ = AlterTable::new()
+        .table("users")
+        .rename_to("accounts")
+        .owner_to("app_owner")
+        .to_sql();
+}
+
+#[test]
+fn alter_table_partitions() {
+    assert_snapshot!(
+        AlterTable::new()
+            .table("measurements")
+            .attach_partition(
+                "measurements_2025_02",
+                PartitionBound::range(["2025-02-01"], ["2025-03-01"]),
+            )
+            .to_sql(),
+        @r#"ALTER TABLE "measurements" ATTACH PARTITION "measurements_2025_02" FOR VALUES FROM ('2025-02-01') TO ('2025-03-01')"#
+    );
+    assert_snapshot!(
+        AlterTable::new()
+            .table("measurements")
+            .detach_partition("measurements_2024_01")
+            .to_sql(),
+        @r#"ALTER TABLE "measurements" DETACH PARTITION "measurements_2024_01""#
+    );
+    assert_snapshot!(
+        AlterTable::new()
+            .table("measurements")
+            .detach_partition_concurrently(("archive", "measurements_2023_12"))
+            .to_sql(),
+        @r#"ALTER TABLE "measurements" DETACH PARTITION "archive"."measurements_2023_12" CONCURRENTLY"#
+    );
+    assert_snapshot!(
+        AlterTable::new()
+            .table("measurements")
+            .detach_partition_finalize("measurements_2023_12")
+            .to_sql(),
+        @r#"ALTER TABLE "measurements" DETACH PARTITION "measurements_2023_12" FINALIZE"#
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "RENAME TO, SET SCHEMA, RENAME CONSTRAINT, ATTACH PARTITION and DETACH PARTITION cannot be combined with other actions."
+)]
+fn alter_table_detach_partition_with_other_action_should_panic() {
+    let _ = AlterTable::new()
+        .table("measurements")
+        .detach_partition_concurrently("measurements_2024_01")
+        .owner_to("app_owner")
+        .to_sql();
+}
+
+#[test]
+#[should_panic(expected = "A USING INDEX constraint cannot have INCLUDE columns.")]
+fn alter_table_constraint_using_index_with_include_should_panic() {
+    let _ = PrimaryKey::using_index("users_id_idx").include_columns(["email"]);
+}
+
+#[test]
+#[should_panic(expected = "A USING INDEX constraint cannot be NULLS NOT DISTINCT.")]
+fn alter_table_constraint_using_index_nulls_not_distinct_should_panic() {
+    let _ = Unique::using_index("users_email_idx").nulls_not_distinct();
+}
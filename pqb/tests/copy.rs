@@ -0,0 +1,132 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use insta::assert_snapshot;
+use pqb::expr::Expr;
+use pqb::query::CopyStatement;
+use pqb::query::Select;
+
+#[test]
+fn copy_from_stdin() {
+    assert_snapshot!(
+        CopyStatement::new()
+            .table(("app", "events"))
+            .columns(["id", "kind", "payload"])
+            .from_stdin()
+            .format_binary()
+            .freeze(true)
+            .to_sql(),
+        @r#"COPY "app"."events" ("id", "kind", "payload") FROM STDIN (FORMAT binary, FREEZE)"#
+    );
+    assert_snapshot!(
+        CopyStatement::new()
+            .table("users")
+            .columns(["id", "name", "email"])
+            .from_stdin()
+            .format_csv()
+            .header_match()
+            .delimiter(';')
+            .null("")
+            .quote('"')
+            .escape('\\')
+            .force_not_null(["name"])
+            .force_null(["email"])
+            .on_error_ignore()
+            .encoding("UTF8")
+            .and_where(Expr::column("id").gt(100))
+            .to_sql(),
+        @r#"COPY "users" ("id", "name", "email") FROM STDIN (FORMAT csv, DELIMITER ';', NULL '', HEADER MATCH, QUOTE '"', ESCAPE E'\\', FORCE_NOT_NULL ("name"), FORCE_NULL ("email"), ON_ERROR ignore, ENCODING 'UTF8') WHERE "id" > 100"#
+    );
+
+    let (sql, values) = CopyStatement::new()
+        .table("users")
+        .from_stdin()
+        .and_where(Expr::column("name").eq("alice"))
+        .to_values()
+        .into_parts();
+    assert_snapshot!(sql, @r#"COPY "users" FROM STDIN WHERE "name" = 'alice'"#);
+    assert!(values.is_empty());
+}
+
+#[test]
+fn copy_to_stdout() {
+    assert_snapshot!(
+        CopyStatement::new()
+            .table("users")
+            .to_stdout()
+            .format_csv()
+            .header(true)
+            .force_quote_all()
+            .to_sql(),
+        @r#"COPY "users" TO STDOUT (FORMAT csv, HEADER, FORCE_QUOTE *)"#
+    );
+    assert_snapshot!(
+        CopyStatement::new()
+            .query(
+                Select::new()
+                    .column("id")
+                    .column("name")
+                    .from("users")
+                    .and_where(Expr::column("active").eq(true)),
+            )
+            .to_stdout()
+            .format_csv()
+            .header(false)
+            .force_quote(["name"])
+            .to_sql(),
+        @r#"COPY (SELECT "id", "name" FROM "users" WHERE "active" = TRUE) TO STDOUT (FORMAT csv, HEADER 0, FORCE_QUOTE ("name"))"#
+    );
+    assert_snapshot!(CopyStatement::new().table("users").to_stdout().to_sql(), @r#"COPY "users" TO STDOUT"#);
+}
+
+#[test]
+fn copy_files() {
+    assert_snapshot!(
+        CopyStatement::new()
+            .table("users")
+            .from_file("/var/lib/import/users.tsv")
+            .format_text()
+            .freeze(false)
+            .on_error_stop()
+            .to_sql(),
+        @r#"COPY "users" FROM '/var/lib/import/users.tsv' (FORMAT text, FREEZE 0, ON_ERROR stop)"#
+    );
+    assert_snapshot!(
+        CopyStatement::new()
+            .table("users")
+            .to_file("/tmp/it's.csv")
+            .format_csv()
+            .to_sql(),
+        @r#"COPY "users" TO E'/tmp/it\'s.csv' (FORMAT csv)"#
+    );
+}
+
+#[test]
+#[should_panic(expected = "COPY of a query can only write the data with TO.")]
+fn copy_query_from_stdin_should_panic() {
+    let _ = CopyStatement::new()
+        .query(Select::new().column("id").from("users"))
+        .from_stdin()
+        .to_sql();
+}
+
+#[test]
+#[should_panic(expected = "COPY WHERE is only allowed with FROM.")]
+fn copy_to_with_where_should_panic() {
+    let _ = CopyStatement::new()
+        .table("users")
+        .to_stdout()
+        .and_where(Expr::column("id").gt(100))
+        .to_sql();
+}
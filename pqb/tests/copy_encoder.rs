@@ -0,0 +1,311 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use insta::assert_snapshot;
+use pqb::copy::BinaryCopyEncoder;
+use pqb::copy::CsvCopyEncoder;
+use pqb::copy::EncodeError;
+use pqb::copy::TextCopyEncoder;
+use pqb::table::ColumnType;
+use pqb::types::TypeName;
+use pqb::value::Value;
+
+const HEADER: &[u8] = b"PGCOPY\n\xff\r\n\0\0\0\0\0\0\0\0\0";
+const TRAILER: &[u8] = b"\xff\xff";
+
+fn binary_fixture(tuples: &[&[u8]]) -> Vec<u8> {
+    let mut bytes = HEADER.to_vec();
+    for tuple in tuples {
+        bytes.extend_from_slice(tuple);
+    }
+    bytes.extend_from_slice(TRAILER);
+    bytes
+}
+
+#[test]
+fn binary_copy_rows() {
+    let mut encoder = BinaryCopyEncoder::new("users")
+        .column("id", ColumnType::Int)
+        .column("name", ColumnType::Text)
+        .column("active", ColumnType::Boolean);
+    assert_snapshot!(
+        encoder.copy_statement().to_sql(),
+        @r#"COPY "users" ("id", "name", "active") FROM STDIN (FORMAT binary)"#
+    );
+    encoder
+        .write_row(&[Value::from(1), Value::from("a"), Value::from(true)])
+        .unwrap();
+    encoder
+        .write_row(&[Value::from(2i64), Value::String(None), Value::Bool(None)])
+        .unwrap();
+    assert_eq!(
+        encoder.finish(),
+        binary_fixture(&[
+            b"\0\x03\0\0\0\x04\0\0\0\x01\0\0\0\x01a\0\0\0\x01\x01",
+            b"\0\x03\0\0\0\x04\0\0\0\x02\xff\xff\xff\xff\xff\xff\xff\xff",
+        ])
+    );
+}
+
+#[test]
+fn binary_copy_numbers() {
+    let mut encoder = BinaryCopyEncoder::new("measurements")
+        .column("a", ColumnType::SmallInt)
+        .column("b", ColumnType::BigInt)
+        .column("c", ColumnType::Float)
+        .column("d", ColumnType::Double);
+    encoder
+        .write_row(&[
+            Value::from(-2i8),
+            Value::from(7u32),
+            Value::from(1.5f32),
+            Value::from(-0.25f64),
+        ])
+        .unwrap();
+    assert_eq!(
+        encoder.finish(),
+        binary_fixture(&[b"\0\x04\
+            \0\0\0\x02\xff\xfe\
+            \0\0\0\x08\0\0\0\0\0\0\0\x07\
+            \0\0\0\x04\x3f\xc0\0\0\
+            \0\0\0\x08\xbf\xd0\0\0\0\0\0\0"])
+    );
+}
+
+#[test]
+fn binary_copy_numeric() {
+    let mut encoder = BinaryCopyEncoder::new("prices").column("amount", ColumnType::Numeric(None));
+    encoder.write_row(&[Value::from("123.45")]).unwrap();
+    encoder.write_row(&[Value::from("-0.001")]).unwrap();
+    encoder.write_row(&[Value::from(10000)]).unwrap();
+    encoder.write_row(&[Value::from("0")]).unwrap();
+    encoder.write_row(&[Value::from("NaN")]).unwrap();
+    assert_eq!(
+        encoder.finish(),
+        binary_fixture(&[
+            b"\0\x01\0\0\0\x0c\0\x02\0\0\0\0\0\x02\0\x7b\x11\x94",
+            b"\0\x01\0\0\0\x0a\0\x01\xff\xff\x40\0\0\x03\0\x0a",
+            b"\0\x01\0\0\0\x0a\0\x01\0\x01\0\0\0\0\0\x01",
+            b"\0\x01\0\0\0\x08\0\0\0\0\0\0\0\0",
+            b"\0\x01\0\0\0\x08\0\0\0\0\xc0\0\0\0",
+        ])
+    );
+}
+
+#[test]
+fn binary_copy_arrays() {
+    let mut encoder = BinaryCopyEncoder::new("grids")
+        .column("tags", ColumnType::Array(Arc::new(ColumnType::Int)))
+        .column(
+            "cells",
+            ColumnType::Array(Arc::new(ColumnType::Array(Arc::new(ColumnType::SmallInt)))),
+        );
+    encoder
+        .write_row(&[
+            Value::Array(Some(vec![Value::from(1), Value::Int(None)])),
+            Value::array([Value::array([1i16, 2]), Value::array([3i16, 4])]),
+        ])
+        .unwrap();
+    encoder
+        .write_row(&[Value::Array(Some(vec![])), Value::Array(None)])
+        .unwrap();
+    assert_eq!(
+        encoder.finish(),
+        binary_fixture(&[
+            b"\0\x02\
+              \0\0\0\x20\0\0\0\x01\0\0\0\x01\0\0\0\x17\0\0\0\x02\0\0\0\x01\
+              \0\0\0\x04\0\0\0\x01\xff\xff\xff\xff\
+              \0\0\0\x34\0\0\0\x02\0\0\0\0\0\0\0\x15\0\0\0\x02\0\0\0\x01\0\0\0\x02\0\0\0\x01\
+              \0\0\0\x02\0\x01\0\0\0\x02\0\x02\0\0\0\x02\0\x03\0\0\0\x02\0\x04",
+            b"\0\x02\0\0\0\x0c\0\0\0\0\0\0\0\0\0\0\0\x17\xff\xff\xff\xff",
+        ])
+    );
+}
+
+#[cfg(feature = "with-json")]
+#[test]
+fn binary_copy_json() {
+    let mut encoder = BinaryCopyEncoder::new("events")
+        .column("payload", ColumnType::Json)
+        .column("meta", ColumnType::JsonBinary);
+    encoder
+        .write_row(&[
+            Value::from(serde_json::json!({"a": 1})),
+            Value::from(serde_json::json!([true])),
+        ])
+        .unwrap();
+    assert_eq!(
+        encoder.finish(),
+        binary_fixture(&[b"\0\x02\0\0\0\x07{\"a\":1}\0\0\0\x07\x01[true]"])
+    );
+}
+
+#[cfg(feature = "with-uuid")]
+#[test]
+fn binary_copy_uuid() {
+    let id = uuid::Uuid::from_bytes([
+        0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f, 0xe0,
+        0xc8,
+    ]);
+    let mut encoder = BinaryCopyEncoder::new("sessions").column("id", ColumnType::Uuid);
+    encoder.write_row(&[Value::from(id)]).unwrap();
+    assert_eq!(
+        encoder.finish(),
+        binary_fixture(&[
+            b"\0\x01\0\0\0\x10\x67\xe5\x50\x44\x10\xb1\x42\x6f\x92\x47\xbb\x68\x0e\x5f\xe0\xc8"
+        ])
+    );
+}
+
+#[test]
+fn binary_copy_errors() {
+    let mut encoder = BinaryCopyEncoder::new("users")
+        .column("id", ColumnType::SmallInt)
+        .column("created_at", ColumnType::Timestamp)
+        .column("grid", ColumnType::Array(Arc::new(ColumnType::Int)));
+    let before = encoder.clone().finish();
+
+    assert_eq!(
+        encoder.write_row(&[Value::from(1)]),
+        Err(EncodeError::ColumnCount {
+            expected: 3,
+            actual: 1
+        })
+    );
+    let err = encoder
+        .write_row(&[Value::from(70000), Value::String(None), Value::Array(None)])
+        .unwrap_err();
+    assert_snapshot!(err, @"invalid value for column id: value is out of range for smallint");
+    let err = encoder
+        .write_row(&[Value::from("1"), Value::String(None), Value::Array(None)])
+        .unwrap_err();
+    assert_snapshot!(err, @"invalid value for column id: cannot encode string value as smallint");
+    let err = encoder
+        .write_row(&[
+            Value::from(1),
+            Value::from("2025-01-01 00:00:00"),
+            Value::Array(None),
+        ])
+        .unwrap_err();
+    assert_snapshot!(err, @"invalid value for column created_at: binary encoding of timestamp is not supported, use a text encoder");
+    let err = encoder
+        .write_row(&[
+            Value::from(1),
+            Value::String(None),
+            Value::array([Value::array([1, 2]), Value::array([3])]),
+        ])
+        .unwrap_err();
+    assert_snapshot!(err, @"invalid value for column grid: multidimensional arrays must have sub-arrays of matching length");
+
+    // failed rows are not written
+    assert_eq!(encoder.finish(), before);
+}
+
+#[test]
+fn binary_copy_rejects_custom_types() {
+    let mut encoder =
+        BinaryCopyEncoder::new("people").column("mood", ColumnType::Custom(TypeName::from("mood")));
+    let err = encoder.write_row(&[Value::from("happy")]).unwrap_err();
+    assert_snapshot!(err, @r#"invalid value for column mood: binary encoding of "mood" is not supported, use a text encoder"#);
+}
+
+#[test]
+fn text_copy_rows() {
+    let mut encoder = TextCopyEncoder::new(("public", "notes"))
+        .columns(["id", "body", "extra", "done", "score", "tags"]);
+    assert_snapshot!(
+        encoder.copy_statement().to_sql(),
+        @r#"COPY "public"."notes" ("id", "body", "extra", "done", "score", "tags") FROM STDIN (FORMAT text)"#
+    );
+    encoder
+        .write_row(&[
+            Value::from(1),
+            Value::from("a\tb\\c\nd\r"),
+            Value::String(None),
+            Value::from(true),
+            Value::from(1.5f64),
+            Value::Array(Some(vec![
+                Value::from("x y"),
+                Value::String(None),
+                Value::from(""),
+                Value::from("q\""),
+                Value::from("null"),
+                Value::from("plain"),
+            ])),
+        ])
+        .unwrap();
+    encoder
+        .write_row(&[
+            Value::from(2),
+            Value::from("\\."),
+            Value::from(""),
+            Value::from(false),
+            Value::from(f64::NEG_INFINITY),
+            Value::array([Value::array([1, 2]), Value::array([3, 4])]),
+        ])
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(encoder.finish()).unwrap(),
+        "1\ta\\tb\\\\c\\nd\\r\t\\N\tt\t1.5\t{\"x y\",NULL,\"\",\"q\\\\\"\",\"null\",plain}\n\
+         2\t\\\\.\t\tf\t-Infinity\t{{1,2},{3,4}}\n"
+    );
+}
+
+#[test]
+fn csv_copy_rows() {
+    let mut encoder = CsvCopyEncoder::new("notes");
+    assert_snapshot!(
+        encoder.copy_statement().to_sql(),
+        @r#"COPY "notes" FROM STDIN (FORMAT csv)"#
+    );
+    encoder
+        .write_row(&[
+            Value::from(1),
+            Value::from(""),
+            Value::String(None),
+            Value::from("a,b"),
+            Value::from("say \"hi\""),
+            Value::from("\\."),
+            Value::from("two\nlines"),
+        ])
+        .unwrap();
+    encoder
+        .write_row(&[Value::from(f32::NAN), Value::array(["a", "b c"])])
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(encoder.take_buffer()).unwrap(),
+        "1,\"\",,\"a,b\",\"say \"\"hi\"\"\",\"\\.\",\"two\nlines\"\n\
+         NaN,\"{a,\"\"b c\"\"}\"\n"
+    );
+    assert!(encoder.finish().is_empty());
+}
+
+#[test]
+fn text_copy_errors() {
+    let mut encoder = CsvCopyEncoder::new("notes").columns(["id", "body"]);
+    assert_eq!(
+        encoder.write_row(&[Value::from(1)]),
+        Err(EncodeError::ColumnCount {
+            expected: 2,
+            actual: 1
+        })
+    );
+    let err = encoder
+        .write_row(&[Value::from(1), Value::from("nul\0")])
+        .unwrap_err();
+    assert_snapshot!(err, @"invalid value for column body: string contains a NUL character");
+    assert!(encoder.finish().is_empty());
+}
@@ -14,10 +14,26 @@
 
 use insta::assert_snapshot;
 use pqb::expr::Expr;
-use pqb::index::CreateIndex;
+use pqb::func::FunctionCall;
+use pqb::index::IndexMethod;
+use pqb::query::Select;
+use pqb::sequence::SequenceOptions;
 use pqb::table::ColumnDef;
 use pqb::table::ColumnType;
 use pqb::table::CreateTable;
+use pqb::table::Exclude;
+use pqb::table::ForeignKeyMatch;
+use pqb::table::LikeOption;
+use pqb::table::OnCommit;
+use pqb::table::PartitionBound;
+use pqb::table::PartitionBy;
+use pqb::table::PartitionStrategy;
+use pqb::table::PrimaryKey;
+use pqb::table::References;
+use pqb::table::ReferentialAction;
+use pqb::table::TableConstraint;
+use pqb::table::TableLike;
+use pqb::table::Unique;
 
 #[test]
 fn create_table_basic() {
@@ -48,16 +64,36 @@ fn create_table_if_not_exists_temporary() {
 }
 
 #[test]
-fn create_table_primary_key_index() {
+fn create_table_primary_key() {
     assert_snapshot!(
         CreateTable::new()
             .table("widgets")
             .column(ColumnDef::new("id").int())
             .column(ColumnDef::new("name").text())
-            .primary_key(CreateIndex::new().column("id"))
+            .primary_key(PrimaryKey::columns(["id"]))
             .to_sql(),
         @r#"CREATE TABLE "widgets" ( "id" integer, "name" text, PRIMARY KEY ("id") )"#
     );
+    assert_snapshot!(
+        CreateTable::new()
+            .table("order_items")
+            .column(ColumnDef::new("order_id").int())
+            .column(ColumnDef::new("line").int())
+            .column(ColumnDef::new("sku").text())
+            .constraint(
+                TableConstraint::primary_key(
+                    PrimaryKey::columns(["order_id", "line"]).include_columns(["sku"]),
+                )
+                .name("order_items_pkey")
+                .deferrable(),
+            )
+            .constraint(
+                TableConstraint::unique(Unique::columns(["order_id", "sku"]))
+                    .name("order_items_sku_key"),
+            )
+            .to_sql(),
+        @r#"CREATE TABLE "order_items" ( "order_id" integer, "line" integer, "sku" text, CONSTRAINT "order_items_pkey" PRIMARY KEY ("order_id", "line") INCLUDE ("sku") DEFERRABLE, CONSTRAINT "order_items_sku_key" UNIQUE ("order_id", "sku") )"#
+    );
 }
 
 #[test]
@@ -82,6 +118,245 @@ fn create_table_generated_column() {
     );
 }
 
+#[test]
+fn create_table_column_constraints() {
+    assert_snapshot!(
+        CreateTable::new()
+            .table("orders")
+            .column(ColumnDef::new("id").bigint().not_null())
+            .column(
+                ColumnDef::new("user_id")
+                    .bigint()
+                    .references(References::table("users").column("id")),
+            )
+            .column(
+                ColumnDef::new("quantity")
+                    .int()
+                    .check(Expr::column("quantity").gt(0)),
+            )
+            .column(
+                ColumnDef::new("product_id")
+                    .bigint()
+                    .references(References::table(("shop", "products")).on_delete(ReferentialAction::SetNull)),
+            )
+            .to_sql(),
+        @r#"CREATE TABLE "orders" ( "id" bigint NOT NULL, "user_id" bigint REFERENCES "users" ("id"), "quantity" integer CHECK ("quantity" > 0), "product_id" bigint REFERENCES "shop"."products" ON DELETE SET NULL )"#
+    );
+}
+
+#[test]
+fn create_table_table_constraints() {
+    assert_snapshot!(
+        CreateTable::new()
+            .table("bookings")
+            .column(ColumnDef::new("room_id").int())
+            .column(ColumnDef::new("user_id").int())
+            .column(ColumnDef::new("code").text())
+            .column(ColumnDef::new("during").ts_range())
+            .constraint(
+                TableConstraint::check(Expr::column("room_id").gt(0)).name("positive_room"),
+            )
+            .constraint(
+                TableConstraint::foreign_key(
+                    ["user_id"],
+                    References::table("users")
+                        .columns(["id"])
+                        .match_type(ForeignKeyMatch::Full)
+                        .on_delete(ReferentialAction::Cascade)
+                        .on_update(ReferentialAction::NoAction),
+                )
+                .name("bookings_user_fk")
+                .deferrable()
+                .initially_deferred(),
+            )
+            .constraint(TableConstraint::unique(
+                Unique::columns(["room_id", "code"])
+                    .nulls_not_distinct()
+                    .include_columns(["user_id"]),
+            ))
+            .constraint(
+                TableConstraint::exclude(
+                    Exclude::new()
+                        .using(IndexMethod::Gist)
+                        .element(Expr::column("room_id"), "=")
+                        .element(Expr::column("during"), "&&")
+                        .index_where(Expr::column("code").is_not_null()),
+                )
+                .name("no_overlap"),
+            )
+            .to_sql(),
+        @r#"CREATE TABLE "bookings" ( "room_id" integer, "user_id" integer, "code" text, "during" tsrange, CONSTRAINT "positive_room" CHECK ("room_id" > 0), CONSTRAINT "bookings_user_fk" FOREIGN KEY ("user_id") REFERENCES "users" ("id") MATCH FULL ON DELETE CASCADE ON UPDATE NO ACTION DEFERRABLE INITIALLY DEFERRED, UNIQUE NULLS NOT DISTINCT ("room_id", "code") INCLUDE ("user_id"), CONSTRAINT "no_overlap" EXCLUDE USING gist ("room_id" WITH =, "during" WITH &&) WHERE ("code" IS NOT NULL) )"#
+    );
+}
+
+#[test]
+fn create_table_identity_column() {
+    assert_snapshot!(
+        CreateTable::new()
+            .table("users")
+            .column(ColumnDef::new("id").bigint().generated_always_as_identity())
+            .column(
+                ColumnDef::new("legacy_id")
+                    .int()
+                    .not_null()
+                    .generated_by_default_as_identity()
+                    .identity_options(SequenceOptions::new().start_with(1000).increment_by(10)),
+            )
+            .to_sql(),
+        @r#"CREATE TABLE "users" ( "id" bigint GENERATED ALWAYS AS IDENTITY, "legacy_id" integer NOT NULL GENERATED BY DEFAULT AS IDENTITY (INCREMENT BY 10 START WITH 1000) )"#
+    );
+}
+
+#[test]
+fn create_table_partitioned() {
+    assert_snapshot!(
+        CreateTable::new()
+            .table("measurements")
+            .column(ColumnDef::new("city_id").int().not_null())
+            .column(ColumnDef::new("logdate").date().not_null())
+            .partition_by(PartitionBy::range(["logdate"]))
+            .to_sql(),
+        @r#"CREATE TABLE "measurements" ( "city_id" integer NOT NULL, "logdate" date NOT NULL ) PARTITION BY RANGE ("logdate")"#
+    );
+    assert_snapshot!(
+        CreateTable::new()
+            .table("events")
+            .column(ColumnDef::new("created_at").timestamp_with_time_zone())
+            .partition_by(
+                PartitionBy::new(PartitionStrategy::Range).expr(FunctionCall::custom(
+                    "date_trunc",
+                    [Expr::value("month"), Expr::column("created_at")],
+                )),
+            )
+            .to_sql(),
+        @r#"CREATE TABLE "events" ( "created_at" timestamp with time zone ) PARTITION BY RANGE (date_trunc('month', "created_at"))"#
+    );
+}
+
+#[test]
+fn create_table_partition_of() {
+    assert_snapshot!(
+        CreateTable::new()
+            .table("measurements_2025_01")
+            .partition_of(
+                "measurements",
+                PartitionBound::range(["2025-01-01"], ["2025-02-01"]),
+            )
+            .to_sql(),
+        @r#"CREATE TABLE "measurements_2025_01" PARTITION OF "measurements" FOR VALUES FROM ('2025-01-01') TO ('2025-02-01')"#
+    );
+    assert_snapshot!(
+        CreateTable::new()
+            .table("measurements_old")
+            .partition_of(
+                "measurements",
+                PartitionBound::range([Expr::custom("MINVALUE")], [Expr::value("2020-01-01")]),
+            )
+            .to_sql(),
+        @r#"CREATE TABLE "measurements_old" PARTITION OF "measurements" FOR VALUES FROM (MINVALUE) TO ('2020-01-01')"#
+    );
+    assert_snapshot!(
+        CreateTable::new()
+            .table("cities_west")
+            .partition_of("cities", PartitionBound::list(["Oakland", "San Jose"]))
+            .constraint(TableConstraint::check(Expr::column("population").gt(0)))
+            .partition_by(PartitionBy::hash(["id"]))
+            .to_sql(),
+        @r#"CREATE TABLE "cities_west" PARTITION OF "cities" ( CHECK ("population" > 0) ) FOR VALUES IN ('Oakland', 'San Jose') PARTITION BY HASH ("id")"#
+    );
+    assert_snapshot!(
+        CreateTable::new()
+            .table("orders_p0")
+            .partition_of("orders", PartitionBound::hash(4, 0))
+            .to_sql(),
+        @r#"CREATE TABLE "orders_p0" PARTITION OF "orders" FOR VALUES WITH (MODULUS 4, REMAINDER 0)"#
+    );
+    assert_snapshot!(
+        CreateTable::new()
+            .table("cities_other")
+            .partition_of("cities", PartitionBound::Default)
+            .to_sql(),
+        @r#"CREATE TABLE "cities_other" PARTITION OF "cities" DEFAULT"#
+    );
+}
+
+#[test]
+fn create_table_storage_options() {
+    assert_snapshot!(
+        CreateTable::new()
+            .unlogged()
+            .table("cache")
+            .column(ColumnDef::new("key").text().not_null())
+            .using("heap")
+            .with_option("fillfactor", 70)
+            .with_option("autovacuum_enabled", false)
+            .tablespace("fast_ssd")
+            .to_sql(),
+        @r#"CREATE UNLOGGED TABLE "cache" ( "key" text NOT NULL ) USING "heap" WITH ("fillfactor" = 70, "autovacuum_enabled" = FALSE) TABLESPACE "fast_ssd""#
+    );
+    assert_snapshot!(
+        CreateTable::new()
+            .temporary()
+            .table("scratch")
+            .column(ColumnDef::new("id").int())
+            .on_commit(OnCommit::DeleteRows)
+            .to_sql(),
+        @r#"CREATE TEMPORARY TABLE "scratch" ( "id" integer ) ON COMMIT DELETE ROWS"#
+    );
+}
+
+#[test]
+fn create_table_like_and_inherits() {
+    assert_snapshot!(
+        CreateTable::new()
+            .table("users_archive")
+            .like(
+                TableLike::new("users")
+                    .including(LikeOption::All)
+                    .excluding(LikeOption::Indexes),
+            )
+            .column(ColumnDef::new("archived_at").timestamp_with_time_zone())
+            .to_sql(),
+        @r#"CREATE TABLE "users_archive" ( LIKE "users" INCLUDING ALL EXCLUDING INDEXES, "archived_at" timestamp with time zone )"#
+    );
+    assert_snapshot!(
+        CreateTable::new()
+            .table("capitals")
+            .column(ColumnDef::new("state").char(2))
+            .inherits("cities")
+            .inherits(("geo", "places"))
+            .to_sql(),
+        @r#"CREATE TABLE "capitals" ( "state" char(2) ) INHERITS ("cities", "geo"."places")"#
+    );
+}
+
+#[test]
+fn create_table_as_select() {
+    let select = Select::new()
+        .columns(["id", "name"])
+        .from("users")
+        .and_where(Expr::column("active").eq(true));
+    assert_snapshot!(
+        CreateTable::new()
+            .table("active_users")
+            .as_select(select.clone())
+            .to_sql(),
+        @r#"CREATE TABLE "active_users" AS SELECT "id", "name" FROM "users" WHERE "active" = TRUE"#
+    );
+    assert_snapshot!(
+        CreateTable::new()
+            .temporary()
+            .table("active_users")
+            .column(ColumnDef::new("user_id"))
+            .column(ColumnDef::new("user_name"))
+            .on_commit(OnCommit::Drop)
+            .as_select(select)
+            .with_data(false)
+            .to_sql(),
+        @r#"CREATE TEMPORARY TABLE "active_users" ("user_id", "user_name") ON COMMIT DROP AS SELECT "id", "name" FROM "users" WHERE "active" = TRUE WITH NO DATA"#
+    );
+}
+
 #[test]
 fn create_table_all_column_types() {
     assert_snapshot!(
@@ -148,3 +423,77 @@ fn create_table_default_with_generated_column_should_panic() {
         )
         .to_sql();
 }
+
+#[test]
+#[should_panic(expected = "An identity column cannot have a default value.")]
+fn create_table_identity_column_with_default_should_panic() {
+    let _ = CreateTable::new()
+        .table("bad_table")
+        .column(
+            ColumnDef::new("bad_column")
+                .int()
+                .default(Expr::value(10))
+                .generated_always_as_identity(),
+        )
+        .to_sql();
+}
+
+#[test]
+#[should_panic(expected = "An identity column cannot have a default value.")]
+fn create_table_default_with_identity_column_should_panic() {
+    let _ = CreateTable::new()
+        .table("bad_table")
+        .column(
+            ColumnDef::new("bad_column")
+                .int()
+                .generated_always_as_identity()
+                .default(Expr::value(10)),
+        )
+        .to_sql();
+}
+
+#[test]
+#[should_panic(expected = "A column cannot be both a generated and an identity column.")]
+fn create_table_identity_column_with_generated_should_panic() {
+    let _ = CreateTable::new()
+        .table("bad_table")
+        .column(
+            ColumnDef::new("bad_column")
+                .int()
+                .generated_as_stored(Expr::value(5))
+                .generated_by_default_as_identity(),
+        )
+        .to_sql();
+}
+
+#[test]
+#[should_panic(expected = "A column cannot be both a generated and an identity column.")]
+fn create_table_generated_column_with_identity_should_panic() {
+    let _ = CreateTable::new()
+        .table("bad_table")
+        .column(
+            ColumnDef::new("bad_column")
+                .int()
+                .generated_always_as_identity()
+                .generated_as_virtual(Expr::value(5)),
+        )
+        .to_sql();
+}
+
+#[test]
+#[should_panic(expected = "CHECK constraints cannot be marked DEFERRABLE.")]
+fn create_table_deferrable_check_should_panic() {
+    let _ = CreateTable::new()
+        .table("bad_table")
+        .constraint(TableConstraint::check(Expr::column("price").gt(0)).deferrable())
+        .to_sql();
+}
+
+#[test]
+#[should_panic(expected = "USING INDEX constraints are only allowed in ALTER TABLE.")]
+fn create_table_constraint_using_index_should_panic() {
+    let _ = CreateTable::new()
+        .table("bad_table")
+        .primary_key(PrimaryKey::using_index("bad_table_id_idx"))
+        .to_sql();
+}
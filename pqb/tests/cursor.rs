@@ -0,0 +1,108 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use insta::assert_snapshot;
+use pqb::cursor::Close;
+use pqb::cursor::DeclareCursor;
+use pqb::cursor::Fetch;
+use pqb::cursor::FetchDirection;
+use pqb::cursor::Move;
+use pqb::expr::Expr;
+use pqb::query::Select;
+
+#[test]
+fn declare_cursor() {
+    let declare = DeclareCursor::new()
+        .name("big_orders")
+        .binary()
+        .scroll()
+        .with_hold()
+        .query(
+            Select::new()
+                .column("id")
+                .from("orders")
+                .and_where(Expr::column("total").gt(1000)),
+        );
+    let (sql, values) = declare.to_values().into_parts();
+    assert_snapshot!(sql, @r#"DECLARE "big_orders" BINARY SCROLL CURSOR WITH HOLD FOR SELECT "id" FROM "orders" WHERE "total" > $1"#);
+    assert_eq!(values.len(), 1);
+    assert_snapshot!(
+        DeclareCursor::new()
+            .name("recent")
+            .no_scroll()
+            .query(Select::new().column("id").from("orders"))
+            .to_sql(),
+        @r#"DECLARE "recent" NO SCROLL CURSOR FOR SELECT "id" FROM "orders""#
+    );
+}
+
+#[test]
+fn fetch_and_move() {
+    assert_snapshot!(Fetch::new().cursor("big_orders").to_sql(), @r#"FETCH FROM "big_orders""#);
+    let directions = [
+        FetchDirection::Next,
+        FetchDirection::Prior,
+        FetchDirection::First,
+        FetchDirection::Last,
+        FetchDirection::Absolute(-1),
+        FetchDirection::Relative(3),
+        FetchDirection::Count(100),
+        FetchDirection::All,
+        FetchDirection::Forward,
+        FetchDirection::ForwardCount(50),
+        FetchDirection::ForwardAll,
+        FetchDirection::Backward,
+        FetchDirection::BackwardCount(2),
+        FetchDirection::BackwardAll,
+    ];
+    let sql = directions
+        .into_iter()
+        .map(|direction| {
+            Fetch::new()
+                .direction(direction)
+                .cursor("big_orders")
+                .to_sql()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    assert_snapshot!(sql, @r#"
+    FETCH NEXT FROM "big_orders"
+    FETCH PRIOR FROM "big_orders"
+    FETCH FIRST FROM "big_orders"
+    FETCH LAST FROM "big_orders"
+    FETCH ABSOLUTE -1 FROM "big_orders"
+    FETCH RELATIVE 3 FROM "big_orders"
+    FETCH 100 FROM "big_orders"
+    FETCH ALL FROM "big_orders"
+    FETCH FORWARD FROM "big_orders"
+    FETCH FORWARD 50 FROM "big_orders"
+    FETCH FORWARD ALL FROM "big_orders"
+    FETCH BACKWARD FROM "big_orders"
+    FETCH BACKWARD 2 FROM "big_orders"
+    FETCH BACKWARD ALL FROM "big_orders"
+    "#);
+    assert_snapshot!(
+        Move::new()
+            .direction(FetchDirection::Absolute(0))
+            .cursor("big_orders")
+            .to_sql(),
+        @r#"MOVE ABSOLUTE 0 FROM "big_orders""#
+    );
+}
+
+#[test]
+fn close() {
+    assert_snapshot!(Close::new().cursor("big_orders").to_sql(), @r#"CLOSE "big_orders""#);
+    assert_snapshot!(Close::new().to_sql(), @"CLOSE ALL");
+}
@@ -0,0 +1,113 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use pqb::expr::Expr;
+use pqb::query::Delete;
+use pqb::query::Insert;
+use pqb::query::Select;
+use pqb::query::TableSample;
+use pqb::query::Update;
+use pqb::value::Value;
+
+fn select_by_id(id: i32) -> Select {
+    Select::new()
+        .columns(["id", "name"])
+        .from("users")
+        .and_where(Expr::column("id").eq(id))
+}
+
+#[test]
+fn hash_eq_statements() {
+    let mut cache = HashSet::new();
+    assert!(cache.insert(select_by_id(1)));
+    assert!(!cache.insert(select_by_id(1)));
+    assert!(cache.insert(select_by_id(2)));
+    assert_eq!(cache.len(), 2);
+}
+
+#[test]
+fn hash_eq_floats() {
+    assert_eq!(Value::from(f64::NAN), Value::from(f64::NAN));
+    assert_ne!(Value::from(0.0f64), Value::from(-0.0f64));
+    assert_ne!(Value::from(1.0f32), Value::from(1.0f64));
+
+    let mut values = HashSet::new();
+    values.insert(Value::from(f64::NAN));
+    assert!(values.contains(&Value::from(f64::NAN)));
+
+    let sampled = |percentage| {
+        Select::new()
+            .expr(Expr::asterisk())
+            .from("users")
+            .table_sample(TableSample::bernoulli().percentage(percentage))
+    };
+    assert_eq!(sampled(f64::NAN), sampled(f64::NAN));
+    assert_ne!(sampled(10.0), sampled(20.0));
+}
+
+#[test]
+fn fingerprint_ignores_values() {
+    assert_eq!(select_by_id(1).fingerprint(), select_by_id(2).fingerprint());
+    assert_ne!(
+        select_by_id(1).fingerprint(),
+        select_by_id(1).limit(1).fingerprint()
+    );
+    assert_ne!(
+        select_by_id(1).fingerprint(),
+        Select::new()
+            .columns(["id", "name"])
+            .from("users")
+            .and_where(Expr::column("id").eq(Expr::custom("1")))
+            .fingerprint()
+    );
+
+    let mut prepared = HashMap::new();
+    for id in 0..10 {
+        let (sql, _) = select_by_id(id).to_values().into_parts();
+        prepared
+            .entry(select_by_id(id).fingerprint())
+            .or_insert(sql);
+    }
+    assert_eq!(prepared.len(), 1);
+}
+
+#[test]
+fn fingerprint_statements() {
+    let insert = |name: &str| {
+        Insert::new()
+            .into_table("users")
+            .columns(["name"])
+            .values([name.into()])
+    };
+    assert_eq!(insert("a").fingerprint(), insert("b").fingerprint());
+
+    let update = |name: &str| {
+        Update::new()
+            .table("users")
+            .values([("name", Expr::value(name))])
+            .and_where(Expr::column("id").eq(1))
+    };
+    assert_eq!(update("a").fingerprint(), update("b").fingerprint());
+
+    let delete = |id: i64| {
+        Delete::new()
+            .from_table("users")
+            .and_where(Expr::column("id").eq(id))
+    };
+    assert_eq!(delete(1).fingerprint(), delete(2).fingerprint());
+    assert_ne!(delete(1).fingerprint(), update("a").fingerprint());
+}
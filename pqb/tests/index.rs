@@ -14,7 +14,11 @@
 
 use insta::assert_snapshot;
 use pqb::expr::Expr;
+use pqb::func::FunctionCall;
+use pqb::index::AlterIndex;
 use pqb::index::CreateIndex;
+use pqb::index::IndexElement;
+use pqb::index::Reindex;
 
 #[test]
 fn create_index_gist_with_options() {
@@ -172,3 +176,132 @@ fn create_index_concurrently() {
         @r#"CREATE INDEX CONCURRENTLY IF NOT EXISTS "idx_orders_customer" ON "orders" ("customer_id")"#
     );
 }
+
+#[test]
+fn create_index_elements() {
+    assert_snapshot!(
+        CreateIndex::new()
+            .name("idx_users_email")
+            .table("users")
+            .element(
+                IndexElement::column("email")
+                    .collate("C")
+                    .opclass("text_pattern_ops"),
+            )
+            .element(IndexElement::column("created_at").desc().nulls_last())
+            .element(IndexElement::expr(FunctionCall::lower(Expr::column("name"))).asc().nulls_first())
+            .to_sql(),
+        @r#"CREATE INDEX "idx_users_email" ON "users" ("email" COLLATE "C" "text_pattern_ops", "created_at" DESC NULLS LAST, LOWER("name") ASC NULLS FIRST)"#
+    );
+    assert_snapshot!(
+        CreateIndex::new()
+            .table("documents")
+            .gist()
+            .element(
+                IndexElement::column("body")
+                    .opclass("gist_trgm_ops")
+                    .opclass_param("siglen", 32),
+            )
+            .to_sql(),
+        @r#"CREATE INDEX ON "documents" USING gist ("body" "gist_trgm_ops" ("siglen" = 32))"#
+    );
+}
+
+#[test]
+fn create_index_unique() {
+    assert_snapshot!(
+        CreateIndex::new()
+            .name("idx_users_email")
+            .table("users")
+            .column("email")
+            .to_sql(),
+        @r#"CREATE INDEX "idx_users_email" ON "users" ("email")"#
+    );
+    assert_snapshot!(
+        CreateIndex::new()
+            .unique()
+            .concurrently()
+            .if_not_exists()
+            .name("idx_users_email")
+            .table("users")
+            .column("email")
+            .to_sql(),
+        @r#"CREATE UNIQUE INDEX CONCURRENTLY IF NOT EXISTS "idx_users_email" ON "users" ("email")"#
+    );
+}
+
+#[test]
+fn create_index_table_options() {
+    assert_snapshot!(
+        CreateIndex::new()
+            .unique()
+            .table("measurements")
+            .only()
+            .column("city_id")
+            .column("logdate")
+            .nulls_not_distinct()
+            .with_option("fillfactor", 90)
+            .tablespace("fast_ssd")
+            .index_where(Expr::column("logdate").is_not_null())
+            .to_sql(),
+        @r#"CREATE UNIQUE INDEX ON ONLY "measurements" ("city_id", "logdate") NULLS NOT DISTINCT WITH ("fillfactor" = 90) TABLESPACE "fast_ssd" WHERE "logdate" IS NOT NULL"#
+    );
+}
+
+#[test]
+fn alter_index() {
+    assert_snapshot!(
+        AlterIndex::new()
+            .name(("public", "idx_users_email"))
+            .if_exists()
+            .rename_to("idx_users_email_lower")
+            .to_sql(),
+        @r#"ALTER INDEX IF EXISTS "public"."idx_users_email" RENAME TO "idx_users_email_lower""#
+    );
+    assert_snapshot!(
+        AlterIndex::new()
+            .name("idx_users_email")
+            .set_option("fillfactor", 70)
+            .set_option("deduplicate_items", false)
+            .to_sql(),
+        @r#"ALTER INDEX "idx_users_email" SET ("fillfactor" = 70, "deduplicate_items" = FALSE)"#
+    );
+    assert_snapshot!(
+        AlterIndex::new()
+            .name("idx_measurements")
+            .attach_partition("idx_measurements_2025_01")
+            .to_sql(),
+        @r#"ALTER INDEX "idx_measurements" ATTACH PARTITION "idx_measurements_2025_01""#
+    );
+    assert_snapshot!(
+        AlterIndex::new()
+            .name("idx_measurements")
+            .set_tablespace("archive")
+            .to_sql(),
+        @r#"ALTER INDEX "idx_measurements" SET TABLESPACE "archive""#
+    );
+}
+
+#[test]
+fn reindex() {
+    assert_snapshot!(
+        Reindex::new().index("idx_users_email").concurrently().to_sql(),
+        @r#"REINDEX INDEX CONCURRENTLY "idx_users_email""#
+    );
+    assert_snapshot!(
+        Reindex::new().table(("public", "users")).verbose().to_sql(),
+        @r#"REINDEX (VERBOSE) TABLE "public"."users""#
+    );
+    assert_snapshot!(Reindex::new().schema("app").to_sql(), @r#"REINDEX SCHEMA "app""#);
+}
+
+#[test]
+#[should_panic(expected = "NULLS NOT DISTINCT is only allowed for unique indexes.")]
+fn create_index_nulls_not_distinct_without_unique_should_panic() {
+    let _ = CreateIndex::new()
+        .name("idx_users_email")
+        .table("users")
+        .column("email")
+        .nulls_not_distinct()
+        .to_sql();
+}
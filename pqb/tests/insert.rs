@@ -12,11 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use insta::assert_snapshot;
 use pqb::expr::Expr;
 use pqb::func::FunctionCall;
 use pqb::query::Insert;
+use pqb::query::InsertBatchLimits;
 use pqb::query::OnConflict;
+use pqb::query::Returning;
+use pqb::query::Select;
+use pqb::table::ColumnType;
+use pqb::value::Value;
 
 #[test]
 fn insert_on_conflict_1() {
@@ -231,3 +238,188 @@ fn insert_on_conflict_do_nothing() {
         @r#"INSERT INTO "glyph" ("aspect", "image") VALUES ('abcd', 42.0321) ON CONFLICT ("id", "aspect") DO NOTHING"#
     );
 }
+
+fn batch_sqls(batches: &[(String, Vec<Value>)]) -> String {
+    batches
+        .iter()
+        .map(|(sql, _)| sql.as_str())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn insert_batches() {
+    let mut insert = Insert::new()
+        .into_table("glyph")
+        .columns(["id", "aspect"])
+        .on_conflict(
+            OnConflict::column("id")
+                .update_column("aspect")
+                .action_and_where(Expr::column("aspect").gt(0)),
+        )
+        .returning(Returning::column("id"));
+    for id in 1..=5 {
+        insert = insert.values([id.into(), Expr::value(id * 10)]);
+    }
+
+    let batches = insert.batches().collect::<Vec<_>>();
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0], insert.to_values().into_parts());
+
+    let batches = insert
+        .batches_with(InsertBatchLimits::new().max_params(5))
+        .collect::<Vec<_>>();
+    assert_snapshot!(
+        batch_sqls(&batches),
+        @r#"
+    INSERT INTO "glyph" ("id", "aspect") VALUES ($1, $2), ($3, $4) ON CONFLICT ("id") DO UPDATE SET "aspect" = "excluded"."aspect" WHERE "aspect" > $5 RETURNING "id"
+    INSERT INTO "glyph" ("id", "aspect") VALUES ($1, $2), ($3, $4) ON CONFLICT ("id") DO UPDATE SET "aspect" = "excluded"."aspect" WHERE "aspect" > $5 RETURNING "id"
+    INSERT INTO "glyph" ("id", "aspect") VALUES ($1, $2) ON CONFLICT ("id") DO UPDATE SET "aspect" = "excluded"."aspect" WHERE "aspect" > $3 RETURNING "id"
+    "#
+    );
+    assert_eq!(
+        batches
+            .into_iter()
+            .map(|(_, values)| values)
+            .collect::<Vec<_>>(),
+        vec![
+            vec![1.into(), 10.into(), 2.into(), 20.into(), 0.into()],
+            vec![3.into(), 30.into(), 4.into(), 40.into(), 0.into()],
+            vec![5.into(), 50.into(), 0.into()],
+        ]
+    );
+}
+
+#[test]
+fn insert_batches_max_bytes() {
+    let insert = Insert::new()
+        .into_table("notes")
+        .columns(["body"])
+        .values([Expr::value("a")])
+        .values([Expr::custom("repeat('b', 100)")])
+        .values([Expr::value("c")])
+        .values([Expr::value("d")])
+        .values([Expr::value("e")]);
+    let batches = insert
+        .batches_with(InsertBatchLimits::new().max_params(9).max_bytes(50))
+        .collect::<Vec<_>>();
+    assert_snapshot!(
+        batch_sqls(&batches),
+        @r#"
+    INSERT INTO "notes" ("body") VALUES ($1)
+    INSERT INTO "notes" ("body") VALUES (repeat('b', 100))
+    INSERT INTO "notes" ("body") VALUES ($1), ($2)
+    INSERT INTO "notes" ("body") VALUES ($1)
+    "#
+    );
+    assert!(
+        batches
+            .iter()
+            .all(|(sql, _)| sql.len() <= 50 || !sql.contains('$'))
+    );
+}
+
+#[test]
+fn insert_batches_without_rows() {
+    let insert = Insert::new()
+        .into_table("archive")
+        .columns(["id"])
+        .select_from(Select::new().column("id").from("glyph"));
+    assert_eq!(
+        insert.batches().collect::<Vec<_>>(),
+        vec![insert.to_values().into_parts()]
+    );
+    let insert = Insert::new().into_table("glyph").or_default_values(3);
+    assert_snapshot!(
+        batch_sqls(&insert.batches().collect::<Vec<_>>()),
+        @r#"INSERT INTO "glyph" VALUES (DEFAULT), (DEFAULT), (DEFAULT)"#
+    );
+}
+
+#[test]
+fn insert_unnest() {
+    let insert = Insert::new()
+        .into_table("glyph")
+        .columns(["id", "aspect", "image"])
+        .unnest_rows(
+            [ColumnType::BigSerial, ColumnType::Double, ColumnType::Text],
+            [
+                [Value::from(1), Value::from(2.5), Value::from("a")],
+                [Value::from(2), Value::Double(None), Value::from("b")],
+            ],
+        )
+        .on_conflict(OnConflict::column("id").update_column("aspect"))
+        .returning(Returning::column("id"));
+    let (sql, values) = insert.to_values().into_parts();
+    assert_snapshot!(
+        sql,
+        @r#"INSERT INTO "glyph" ("id", "aspect", "image") SELECT * FROM UNNEST(CAST($1 AS bigint[]), CAST($2 AS double precision[]), CAST($3 AS text[])) ON CONFLICT ("id") DO UPDATE SET "aspect" = "excluded"."aspect" RETURNING "id""#
+    );
+    assert_eq!(
+        values,
+        vec![
+            Value::array([1, 2]),
+            Value::Array(Some(vec![Value::from(2.5), Value::Double(None)])),
+            Value::array(["a", "b"]),
+        ]
+    );
+    assert_eq!(insert.batches().count(), 1);
+
+    assert_snapshot!(
+        Insert::new()
+            .into_table("glyph")
+            .columns(["id", "kind"])
+            .unnest_columns([
+                (ColumnType::Int, vec![Value::from(1), Value::from(2)]),
+                (ColumnType::Varchar(8), vec![Value::from("x"), Value::from("y")]),
+            ])
+            .to_sql(),
+        @r#"INSERT INTO "glyph" ("id", "kind") SELECT * FROM UNNEST(CAST(ARRAY [1,2] AS integer[]), CAST(ARRAY ['x','y'] AS varchar(8)[]))"#
+    );
+}
+
+#[test]
+fn insert_unnest_array_columns() {
+    let insert = Insert::new()
+        .into_table("posts")
+        .columns(["id", "tags"])
+        .unnest_columns([
+            (
+                ColumnType::Int,
+                vec![Value::from(1), Value::from(2), Value::from(3)],
+            ),
+            (
+                ColumnType::Array(Arc::new(ColumnType::Text)),
+                vec![
+                    Value::array(["rust", "sql, \"quoted\""]),
+                    Value::Array(Some(vec![])),
+                    Value::Array(None),
+                ],
+            ),
+        ]);
+    let (sql, values) = insert.to_values().into_parts();
+    assert_snapshot!(sql, @r#"INSERT INTO "posts" ("id", "tags") SELECT "id", CAST("tags" AS text[]) FROM UNNEST(CAST($1 AS integer[]), CAST($2 AS text[])) AS "unnest" ("id", "tags")"#);
+    assert_eq!(
+        values,
+        vec![
+            Value::array([1, 2, 3]),
+            Value::Array(Some(vec![
+                Value::from(r#"{rust,"sql, \"quoted\""}"#),
+                Value::from("{}"),
+                Value::String(None),
+            ])),
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "UNNEST arrays must have the same length")]
+fn insert_unnest_uneven_columns() {
+    let _ = Insert::new()
+        .into_table("glyph")
+        .columns(["id", "aspect"])
+        .unnest_columns([
+            (ColumnType::Int, vec![Value::from(1), Value::from(2)]),
+            (ColumnType::Double, vec![Value::from(2.5)]),
+        ]);
+}
@@ -0,0 +1,108 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use insta::assert_snapshot;
+use pqb::maintenance::Analyze;
+use pqb::maintenance::Checkpoint;
+use pqb::maintenance::Cluster;
+use pqb::maintenance::IndexCleanup;
+use pqb::maintenance::Vacuum;
+use pqb::table::Truncate;
+
+#[test]
+fn truncate() {
+    assert_snapshot!(Truncate::new().table("events").to_sql(), @r#"TRUNCATE "events""#);
+    assert_snapshot!(
+        Truncate::new()
+            .tables([("app", "orders"), ("app", "order_items")])
+            .only()
+            .restart_identity()
+            .cascade()
+            .to_sql(),
+        @r#"TRUNCATE ONLY "app"."orders", ONLY "app"."order_items" RESTART IDENTITY CASCADE"#
+    );
+    assert_snapshot!(
+        Truncate::new()
+            .table("events")
+            .continue_identity()
+            .restrict()
+            .to_sql(),
+        @r#"TRUNCATE "events" CONTINUE IDENTITY RESTRICT"#
+    );
+}
+
+#[test]
+fn vacuum() {
+    assert_snapshot!(Vacuum::new().to_sql(), @"VACUUM");
+    assert_snapshot!(
+        Vacuum::new()
+            .full(true)
+            .freeze(true)
+            .verbose(true)
+            .analyze(true)
+            .skip_locked(false)
+            .parallel(4)
+            .table("orders")
+            .to_sql(),
+        @r#"VACUUM (FULL, FREEZE, VERBOSE, ANALYZE, SKIP_LOCKED 0, PARALLEL 4) "orders""#
+    );
+    assert_snapshot!(
+        Vacuum::new()
+            .analyze(true)
+            .disable_page_skipping(true)
+            .index_cleanup(IndexCleanup::Off)
+            .process_main(true)
+            .process_toast(false)
+            .truncate(false)
+            .buffer_usage_limit("256MB")
+            .table_columns(("app", "orders"), ["total", "created_at"])
+            .table("order_items")
+            .to_sql(),
+        @r#"VACUUM (ANALYZE, DISABLE_PAGE_SKIPPING, INDEX_CLEANUP OFF, PROCESS_MAIN, PROCESS_TOAST 0, TRUNCATE 0, BUFFER_USAGE_LIMIT '256MB') "app"."orders" ("total", "created_at"), "order_items""#
+    );
+}
+
+#[test]
+fn analyze() {
+    assert_snapshot!(Analyze::new().to_sql(), @"ANALYZE");
+    assert_snapshot!(
+        Analyze::new()
+            .verbose(true)
+            .skip_locked(true)
+            .buffer_usage_limit("1GB")
+            .table_columns("orders", ["total"])
+            .table("users")
+            .to_sql(),
+        @r#"ANALYZE (VERBOSE, SKIP_LOCKED, BUFFER_USAGE_LIMIT '1GB') "orders" ("total"), "users""#
+    );
+}
+
+#[test]
+fn cluster() {
+    assert_snapshot!(Cluster::new().to_sql(), @"CLUSTER");
+    assert_snapshot!(
+        Cluster::new()
+            .verbose(true)
+            .table(("app", "orders"))
+            .using_index("orders_created_at_idx")
+            .to_sql(),
+        @r#"CLUSTER (VERBOSE) "app"."orders" USING "orders_created_at_idx""#
+    );
+    assert_snapshot!(Cluster::new().table("orders").to_sql(), @r#"CLUSTER "orders""#);
+}
+
+#[test]
+fn checkpoint() {
+    assert_snapshot!(Checkpoint::new().to_sql(), @"CHECKPOINT");
+}
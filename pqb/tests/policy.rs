@@ -0,0 +1,123 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use insta::assert_snapshot;
+use pqb::expr::Expr;
+use pqb::policy::AlterPolicy;
+use pqb::policy::CreatePolicy;
+use pqb::policy::DropPolicy;
+use pqb::policy::PolicyCommand;
+
+fn current_tenant() -> Expr {
+    Expr::custom("current_setting('app.tenant_id')::uuid")
+}
+
+#[test]
+fn create_policy() {
+    assert_snapshot!(
+        CreatePolicy::new()
+            .name("tenant_isolation")
+            .table(("app", "documents"))
+            .using(Expr::column("tenant_id").eq(current_tenant()))
+            .to_sql(),
+        @r#"CREATE POLICY "tenant_isolation" ON "app"."documents" USING ("tenant_id" = (current_setting('app.tenant_id')::uuid))"#
+    );
+    assert_snapshot!(
+        CreatePolicy::new()
+            .name("tenant_writes")
+            .table("documents")
+            .restrictive()
+            .command(PolicyCommand::Update)
+            .to("app_rw")
+            .to("app_admin")
+            .using(Expr::column("tenant_id").eq(current_tenant()))
+            .with_check(
+                Expr::column("tenant_id")
+                    .eq(current_tenant())
+                    .and(Expr::column("locked").eq(false)),
+            )
+            .to_sql(),
+        @r#"CREATE POLICY "tenant_writes" ON "documents" AS RESTRICTIVE FOR UPDATE TO "app_rw", "app_admin" USING ("tenant_id" = (current_setting('app.tenant_id')::uuid)) WITH CHECK ("tenant_id" = (current_setting('app.tenant_id')::uuid) AND "locked" = FALSE)"#
+    );
+    assert_snapshot!(
+        CreatePolicy::new()
+            .name("public_read")
+            .table("documents")
+            .permissive()
+            .command(PolicyCommand::Select)
+            .to_public()
+            .using(Expr::column("published").eq(true))
+            .to_sql(),
+        @r#"CREATE POLICY "public_read" ON "documents" AS PERMISSIVE FOR SELECT TO PUBLIC USING ("published" = TRUE)"#
+    );
+}
+
+#[test]
+fn create_policy_values() {
+    let (sql, values) = CreatePolicy::new()
+        .name("own_rows")
+        .table("documents")
+        .command(PolicyCommand::All)
+        .using(Expr::column("owner").eq(Expr::custom("current_user")))
+        .to_values()
+        .into_parts();
+    assert_snapshot!(sql, @r#"CREATE POLICY "own_rows" ON "documents" FOR ALL USING ("owner" = (current_user))"#);
+    assert!(values.is_empty());
+}
+
+#[test]
+fn alter_policy() {
+    assert_snapshot!(
+        AlterPolicy::new()
+            .name("tenant_writes")
+            .table("documents")
+            .to("app_rw")
+            .using(Expr::column("tenant_id").eq(current_tenant()))
+            .with_check(Expr::column("tenant_id").eq(current_tenant()))
+            .to_sql(),
+        @r#"ALTER POLICY "tenant_writes" ON "documents" TO "app_rw" USING ("tenant_id" = (current_setting('app.tenant_id')::uuid)) WITH CHECK ("tenant_id" = (current_setting('app.tenant_id')::uuid))"#
+    );
+    assert_snapshot!(
+        AlterPolicy::new()
+            .name("tenant_writes")
+            .table("documents")
+            .rename_to("tenant_updates")
+            .to_sql(),
+        @r#"ALTER POLICY "tenant_writes" ON "documents" RENAME TO "tenant_updates""#
+    );
+}
+
+#[test]
+#[should_panic(expected = "RENAME TO cannot be combined with other changes of a policy.")]
+fn alter_policy_rename_with_other_changes_should_panic() {
+    let _ = AlterPolicy::new()
+        .name("tenant_writes")
+        .table("documents")
+        .rename_to("tenant_updates")
+        .to("app_rw")
+        .to_sql();
+}
+
+#[test]
+fn drop_policy() {
+    assert_snapshot!(
+        DropPolicy::new()
+            .if_exists()
+            .name("tenant_isolation")
+            .table(("app", "documents"))
+            .cascade()
+            .to_sql(),
+        @r#"DROP POLICY IF EXISTS "tenant_isolation" ON "app"."documents" CASCADE"#
+    );
+}
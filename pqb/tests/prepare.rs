@@ -0,0 +1,80 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use insta::assert_snapshot;
+use pqb::expr::Expr;
+use pqb::prepare::Deallocate;
+use pqb::prepare::Execute;
+use pqb::prepare::Prepare;
+use pqb::query::Insert;
+use pqb::query::Select;
+use pqb::table::ColumnType;
+use pqb::value::Value;
+
+#[test]
+fn prepare() {
+    let prepare = Prepare::new()
+        .name("insert_user")
+        .param_types([ColumnType::Text, ColumnType::Int])
+        .statement(
+            Insert::new()
+                .into_table("users")
+                .columns(["name", "nickname", "age", "active"])
+                .values([
+                    Expr::param(1),
+                    Expr::param(1),
+                    Expr::param(2),
+                    Expr::value(true),
+                ]),
+        );
+    let (sql, values) = prepare.to_values().into_parts();
+    assert_snapshot!(sql, @r#"PREPARE "insert_user" (text, integer) AS INSERT INTO "users" ("name", "nickname", "age", "active") VALUES ($1, $1, $2, TRUE)"#);
+    assert!(values.is_empty());
+    assert_eq!(prepare.to_sql(), sql);
+
+    assert_snapshot!(
+        Prepare::new()
+            .name("active_users")
+            .statement(
+                Select::new()
+                    .column("id")
+                    .from("users")
+                    .and_where(Expr::column("active")),
+            )
+            .to_sql(),
+        @r#"PREPARE "active_users" AS SELECT "id" FROM "users" WHERE "active""#
+    );
+}
+
+#[test]
+fn execute() {
+    assert_snapshot!(
+        Execute::new()
+            .name("insert_user")
+            .arg("O'Brien")
+            .arg(30)
+            .arg(Value::String(None))
+            .to_sql(),
+        @r#"EXECUTE "insert_user" (E'O\'Brien', 30, NULL)"#
+    );
+    let execute = Execute::new().name("active_users");
+    assert_snapshot!(execute.to_sql(), @r#"EXECUTE "active_users""#);
+    assert!(execute.to_values().into_parts().1.is_empty());
+}
+
+#[test]
+fn deallocate() {
+    assert_snapshot!(Deallocate::new().name("insert_user").to_sql(), @r#"DEALLOCATE "insert_user""#);
+    assert_snapshot!(Deallocate::new().to_sql(), @"DEALLOCATE ALL");
+}
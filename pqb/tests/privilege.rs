@@ -0,0 +1,149 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use insta::assert_snapshot;
+use pqb::privilege::AlterDefaultPrivileges;
+use pqb::privilege::DefaultPrivilegeObject;
+use pqb::privilege::Grant;
+use pqb::privilege::Privilege;
+use pqb::privilege::PrivilegeObject;
+use pqb::privilege::Revoke;
+
+#[test]
+fn grant_on_tables() {
+    assert_snapshot!(
+        Grant::new()
+            .privileges([Privilege::Select, Privilege::Insert])
+            .column_privilege(Privilege::Update, ["name", "email"])
+            .on(PrivilegeObject::tables(["users", "profiles"]))
+            .to("app_rw")
+            .to("auditor")
+            .with_grant_option()
+            .granted_by("admin")
+            .to_sql(),
+        @r#"GRANT SELECT, INSERT, UPDATE ("name", "email") ON TABLE "users", "profiles" TO "app_rw", "auditor" WITH GRANT OPTION GRANTED BY "admin""#
+    );
+    assert_snapshot!(
+        Grant::new()
+            .privilege(Privilege::Select)
+            .on(PrivilegeObject::all_tables_in_schema("app"))
+            .to_public()
+            .to_sql(),
+        @r#"GRANT SELECT ON ALL TABLES IN SCHEMA "app" TO PUBLIC"#
+    );
+}
+
+#[test]
+fn grant_on_other_objects() {
+    assert_snapshot!(
+        Grant::new()
+            .privileges([Privilege::Usage, Privilege::Create])
+            .on(PrivilegeObject::schema("app"))
+            .to("app_owner")
+            .to_sql(),
+        @r#"GRANT USAGE, CREATE ON SCHEMA "app" TO "app_owner""#
+    );
+    assert_snapshot!(
+        Grant::new()
+            .privilege(Privilege::Usage)
+            .on(PrivilegeObject::sequence(("app", "users_id_seq")))
+            .to("app_rw")
+            .to_sql(),
+        @r#"GRANT USAGE ON SEQUENCE "app"."users_id_seq" TO "app_rw""#
+    );
+    assert_snapshot!(
+        Grant::new()
+            .privilege(Privilege::Usage)
+            .on(PrivilegeObject::all_sequences_in_schema("app"))
+            .to("app_rw")
+            .to_sql(),
+        @r#"GRANT USAGE ON ALL SEQUENCES IN SCHEMA "app" TO "app_rw""#
+    );
+    assert_snapshot!(
+        Grant::new()
+            .privilege(Privilege::Execute)
+            .on(PrivilegeObject::function(("app", "touch_updated_at")))
+            .to("app_rw")
+            .to_sql(),
+        @r#"GRANT EXECUTE ON FUNCTION "app"."touch_updated_at" TO "app_rw""#
+    );
+    assert_snapshot!(
+        Grant::new()
+            .privilege(Privilege::Execute)
+            .on(PrivilegeObject::all_functions_in_schema("app"))
+            .to("app_rw")
+            .to_sql(),
+        @r#"GRANT EXECUTE ON ALL FUNCTIONS IN SCHEMA "app" TO "app_rw""#
+    );
+    assert_snapshot!(
+        Grant::new()
+            .privileges([Privilege::Connect, Privilege::Temporary])
+            .on(PrivilegeObject::database("appdb"))
+            .to("app_rw")
+            .to_sql(),
+        @r#"GRANT CONNECT, TEMPORARY ON DATABASE "appdb" TO "app_rw""#
+    );
+}
+
+#[test]
+fn revoke_privileges() {
+    assert_snapshot!(
+        Revoke::new()
+            .all_privileges()
+            .on(PrivilegeObject::table(("app", "users")))
+            .from("app_rw")
+            .from_public()
+            .cascade()
+            .to_sql(),
+        @r#"REVOKE ALL PRIVILEGES ON TABLE "app"."users" FROM "app_rw", PUBLIC CASCADE"#
+    );
+    assert_snapshot!(
+        Revoke::new()
+            .grant_option_for()
+            .column_privilege(Privilege::Select, ["ssn"])
+            .on(PrivilegeObject::table("users"))
+            .from("auditor")
+            .granted_by("admin")
+            .restrict()
+            .to_sql(),
+        @r#"REVOKE GRANT OPTION FOR SELECT ("ssn") ON TABLE "users" FROM "auditor" GRANTED BY "admin" RESTRICT"#
+    );
+}
+
+#[test]
+fn alter_default_privileges() {
+    assert_snapshot!(
+        AlterDefaultPrivileges::new()
+            .for_role("app_owner")
+            .in_schema("app")
+            .privileges([Privilege::Select, Privilege::Insert, Privilege::Update])
+            .on(DefaultPrivilegeObject::Tables)
+            .grantee("app_rw")
+            .grant_option()
+            .to_sql(),
+        @r#"ALTER DEFAULT PRIVILEGES FOR ROLE "app_owner" IN SCHEMA "app" GRANT SELECT, INSERT, UPDATE ON TABLES TO "app_rw" WITH GRANT OPTION"#
+    );
+    assert_snapshot!(
+        AlterDefaultPrivileges::new()
+            .in_schema("app")
+            .revoke()
+            .grant_option()
+            .privilege(Privilege::Execute)
+            .on(DefaultPrivilegeObject::Functions)
+            .grantee_public()
+            .cascade()
+            .to_sql(),
+        @r#"ALTER DEFAULT PRIVILEGES IN SCHEMA "app" REVOKE GRANT OPTION FOR EXECUTE ON FUNCTIONS FROM PUBLIC CASCADE"#
+    );
+}
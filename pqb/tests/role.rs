@@ -0,0 +1,75 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use insta::assert_snapshot;
+use pqb::role::AlterRole;
+use pqb::role::CreateRole;
+use pqb::role::DropRole;
+use pqb::role::RoleOptions;
+
+#[test]
+fn create_role() {
+    assert_snapshot!(
+        CreateRole::new()
+            .role("app_rw")
+            .options(
+                RoleOptions::new()
+                    .login()
+                    .no_inherit()
+                    .connection_limit(20)
+                    .password("s3cr'et")
+                    .valid_until("2030-01-01"),
+            )
+            .in_roles(["readers", "writers"])
+            .to_sql(),
+        @r#"CREATE ROLE "app_rw" WITH NOINHERIT LOGIN CONNECTION LIMIT 20 PASSWORD E's3cr\'et' VALID UNTIL '2030-01-01' IN ROLE "readers", "writers""#
+    );
+    assert_snapshot!(CreateRole::new().role("readers").to_sql(), @r#"CREATE ROLE "readers""#);
+}
+
+#[test]
+fn alter_role() {
+    assert_snapshot!(
+        AlterRole::new()
+            .role("app_rw")
+            .options(
+                RoleOptions::new()
+                    .no_superuser()
+                    .create_db()
+                    .no_create_role()
+                    .replication()
+                    .bypass_rls()
+                    .connection_limit(-1)
+                    .password_null(),
+            )
+            .to_sql(),
+        @r#"ALTER ROLE "app_rw" WITH NOSUPERUSER CREATEDB NOCREATEROLE REPLICATION BYPASSRLS CONNECTION LIMIT -1 PASSWORD NULL"#
+    );
+    assert_snapshot!(
+        AlterRole::new().role("app_rw").rename_to("app_writer").to_sql(),
+        @r#"ALTER ROLE "app_rw" RENAME TO "app_writer""#
+    );
+    assert_snapshot!(
+        AlterRole::new().role("app_rw").options(RoleOptions::new()).to_sql(),
+        @r#"ALTER ROLE "app_rw""#
+    );
+}
+
+#[test]
+fn drop_role() {
+    assert_snapshot!(
+        DropRole::new().if_exists().roles(["app_rw", "auditor"]).to_sql(),
+        @r#"DROP ROLE IF EXISTS "app_rw", "auditor""#
+    );
+}
@@ -0,0 +1,164 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use insta::assert_snapshot;
+use pqb::expr::Expr;
+use pqb::expr::Keyword;
+use pqb::routine::ArgMode;
+use pqb::routine::Call;
+use pqb::routine::CreateFunction;
+use pqb::routine::CreateProcedure;
+use pqb::routine::Do;
+use pqb::routine::DropFunction;
+use pqb::routine::FunctionArg;
+use pqb::routine::Language;
+use pqb::routine::Parallel;
+use pqb::routine::Volatility;
+use pqb::table::ColumnType;
+
+#[test]
+fn create_function_basic() {
+    assert_snapshot!(
+        CreateFunction::new()
+            .or_replace()
+            .name("add")
+            .arg(FunctionArg::new("a", ColumnType::Int))
+            .arg(FunctionArg::new("b", ColumnType::Int).default(0))
+            .returns(ColumnType::Int)
+            .language(Language::Sql)
+            .volatility(Volatility::Immutable)
+            .strict()
+            .parallel(Parallel::Safe)
+            .body("SELECT a + b")
+            .to_sql(),
+        @r#"CREATE OR REPLACE FUNCTION "add"("a" integer, "b" integer DEFAULT 0) RETURNS integer LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE AS $$SELECT a + b$$"#
+    );
+}
+
+#[test]
+fn create_function_returns_table() {
+    assert_snapshot!(
+        CreateFunction::new()
+            .name(("app", "active_users"))
+            .arg(FunctionArg::new("since", ColumnType::TimestampWithTimeZone))
+            .arg(FunctionArg::new("total", ColumnType::BigInt).mode(ArgMode::Out))
+            .returns_table([("id", ColumnType::BigInt), ("name", ColumnType::Text)])
+            .language(Language::PlPgSql)
+            .volatility(Volatility::Stable)
+            .security_definer()
+            .set_search_path(["pg_catalog", "app"])
+            .set("work_mem", "64MB")
+            .set_from_current("timezone")
+            .body("BEGIN RETURN QUERY SELECT id, name FROM users WHERE seen_at >= since; END")
+            .to_sql(),
+        @r#"CREATE FUNCTION "app"."active_users"("since" timestamp with time zone, OUT "total" bigint) RETURNS TABLE ("id" bigint, "name" text) LANGUAGE plpgsql STABLE SECURITY DEFINER SET "search_path" = "pg_catalog", "app" SET "work_mem" = '64MB' SET "timezone" FROM CURRENT AS $$BEGIN RETURN QUERY SELECT id, name FROM users WHERE seen_at >= since; END$$"#
+    );
+    assert_snapshot!(
+        CreateFunction::new()
+            .name("all_ids")
+            .arg(FunctionArg::unnamed(ColumnType::Int).mode(ArgMode::Variadic))
+            .returns_setof(ColumnType::Int)
+            .language("sql")
+            .body("SELECT unnest($1)")
+            .to_sql(),
+        @r#"CREATE FUNCTION "all_ids"(VARIADIC integer) RETURNS SETOF integer LANGUAGE sql AS $$SELECT unnest($1)$$"#
+    );
+}
+
+#[test]
+fn create_function_dollar_quote_tag() {
+    assert_snapshot!(
+        CreateFunction::new()
+            .name("noop")
+            .returns_void()
+            .language(Language::PlPgSql)
+            .body("BEGIN EXECUTE $$SELECT 1$$; END")
+            .to_sql(),
+        @r#"CREATE FUNCTION "noop"() RETURNS void LANGUAGE plpgsql AS $body$BEGIN EXECUTE $$SELECT 1$$; END$body$"#
+    );
+    assert_snapshot!(
+        CreateFunction::new()
+            .name("noop")
+            .returns_void()
+            .language(Language::PlPgSql)
+            .body("BEGIN EXECUTE $body$SELECT $$1$$$body$; END")
+            .to_sql(),
+        @r#"CREATE FUNCTION "noop"() RETURNS void LANGUAGE plpgsql AS $body1$BEGIN EXECUTE $body$SELECT $$1$$$body$; END$body1$"#
+    );
+    assert_snapshot!(
+        Do::new().body("SELECT 1 AS cost_$").to_sql(),
+        @r#"DO $body$SELECT 1 AS cost_$$body$"#
+    );
+}
+
+#[test]
+fn create_procedure() {
+    assert_snapshot!(
+        CreateProcedure::new()
+            .or_replace()
+            .name("archive_orders")
+            .arg(FunctionArg::new("cutoff", ColumnType::Date))
+            .arg(FunctionArg::new("moved", ColumnType::Int).mode(ArgMode::InOut))
+            .language(Language::PlPgSql)
+            .security_invoker()
+            .set("lock_timeout", "5s")
+            .body("BEGIN DELETE FROM orders WHERE created_at < cutoff; COMMIT; END")
+            .to_sql(),
+        @r#"CREATE OR REPLACE PROCEDURE "archive_orders"("cutoff" date, INOUT "moved" integer) LANGUAGE plpgsql SECURITY INVOKER SET "lock_timeout" = '5s' AS $$BEGIN DELETE FROM orders WHERE created_at < cutoff; COMMIT; END$$"#
+    );
+}
+
+#[test]
+fn drop_function() {
+    assert_snapshot!(
+        DropFunction::new()
+            .if_exists()
+            .function("add")
+            .function_with_args(("app", "active_users"), [ColumnType::TimestampWithTimeZone])
+            .function_with_args("now_utc", Vec::<ColumnType>::new())
+            .cascade()
+            .to_sql(),
+        @r#"DROP FUNCTION IF EXISTS "add", "app"."active_users"(timestamp with time zone), "now_utc"() CASCADE"#
+    );
+}
+
+#[test]
+fn call_procedure() {
+    assert_snapshot!(
+        Call::new()
+            .procedure("archive_orders")
+            .arg(Expr::current_timestamp())
+            .arg(Expr::Keyword(Keyword::Null))
+            .to_sql(),
+        @r#"CALL "archive_orders"(CURRENT_TIMESTAMP, NULL)"#
+    );
+    let (sql, values) = Call::new()
+        .procedure("transfer")
+        .args([1, 2])
+        .to_values()
+        .into_parts();
+    assert_snapshot!(sql, @r#"CALL "transfer"($1, $2)"#);
+    assert_eq!(values.len(), 2);
+}
+
+#[test]
+fn do_block() {
+    assert_snapshot!(
+        Do::new()
+            .language(Language::PlPgSql)
+            .body("BEGIN PERFORM pg_sleep(1); END")
+            .to_sql(),
+        @r#"DO LANGUAGE plpgsql $$BEGIN PERFORM pg_sleep(1); END$$"#
+    );
+}
@@ -0,0 +1,67 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use insta::assert_snapshot;
+use pqb::index::CreateIndex;
+use pqb::schema::AlterSchema;
+use pqb::schema::CreateSchema;
+use pqb::table::ColumnDef;
+use pqb::table::CreateTable;
+
+#[test]
+fn create_schema() {
+    assert_snapshot!(
+        CreateSchema::new().schema("tenant_42").if_not_exists().to_sql(),
+        @r#"CREATE SCHEMA IF NOT EXISTS "tenant_42""#
+    );
+    assert_snapshot!(
+        CreateSchema::new().authorization("alice").to_sql(),
+        @r#"CREATE SCHEMA AUTHORIZATION "alice""#
+    );
+}
+
+#[test]
+fn create_schema_with_elements() {
+    assert_snapshot!(
+        CreateSchema::new()
+            .schema("tenant_42")
+            .authorization("tenant_42_owner")
+            .element(
+                CreateTable::new()
+                    .table("users")
+                    .column(ColumnDef::new("id").bigint().not_null())
+                    .column(ColumnDef::new("email").text()),
+            )
+            .element(
+                CreateIndex::new()
+                    .name("idx_users_email")
+                    .table("users")
+                    .column("email"),
+            )
+            .to_sql(),
+        @r#"CREATE SCHEMA "tenant_42" AUTHORIZATION "tenant_42_owner" CREATE TABLE "users" ( "id" bigint NOT NULL, "email" text ) CREATE INDEX "idx_users_email" ON "users" ("email")"#
+    );
+}
+
+#[test]
+fn alter_schema() {
+    assert_snapshot!(
+        AlterSchema::new().schema("tenant_42").rename_to("tenant_43").to_sql(),
+        @r#"ALTER SCHEMA "tenant_42" RENAME TO "tenant_43""#
+    );
+    assert_snapshot!(
+        AlterSchema::new().schema("tenant_42").owner_to("admin").to_sql(),
+        @r#"ALTER SCHEMA "tenant_42" OWNER TO "admin""#
+    );
+}
@@ -0,0 +1,109 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use insta::assert_snapshot;
+use pqb::expr::Expr;
+use pqb::func::FunctionCall;
+use pqb::query::Select;
+use pqb::sequence::AlterSequence;
+use pqb::sequence::CreateSequence;
+use pqb::sequence::DropSequence;
+use pqb::sequence::SequenceOptions;
+use pqb::table::ColumnType;
+
+#[test]
+fn create_sequence() {
+    assert_snapshot!(
+        CreateSequence::new().sequence("order_seq").to_sql(),
+        @r#"CREATE SEQUENCE "order_seq""#
+    );
+    assert_snapshot!(
+        CreateSequence::new()
+            .temporary()
+            .if_not_exists()
+            .sequence(("public", "order_seq"))
+            .options(
+                SequenceOptions::new()
+                    .as_type(ColumnType::BigInt)
+                    .increment_by(5)
+                    .min_value(1)
+                    .no_max_value()
+                    .start_with(100)
+                    .cache(20)
+                    .cycle()
+                    .owned_by(("orders", "id")),
+            )
+            .to_sql(),
+        @r#"CREATE TEMPORARY SEQUENCE IF NOT EXISTS "public"."order_seq" AS bigint INCREMENT BY 5 MINVALUE 1 NO MAXVALUE START WITH 100 CACHE 20 CYCLE OWNED BY "orders"."id""#
+    );
+}
+
+#[test]
+fn alter_sequence() {
+    assert_snapshot!(
+        AlterSequence::new()
+            .if_exists()
+            .sequence("order_seq")
+            .options(
+                SequenceOptions::new()
+                    .no_min_value()
+                    .max_value(1_000_000)
+                    .no_cycle()
+                    .owned_by_none(),
+            )
+            .restart_with(1)
+            .to_sql(),
+        @r#"ALTER SEQUENCE IF EXISTS "order_seq" NO MINVALUE MAXVALUE 1000000 NO CYCLE OWNED BY NONE RESTART WITH 1"#
+    );
+    assert_snapshot!(
+        AlterSequence::new().sequence("order_seq").restart().to_sql(),
+        @r#"ALTER SEQUENCE "order_seq" RESTART"#
+    );
+}
+
+#[test]
+fn drop_sequence() {
+    assert_snapshot!(
+        DropSequence::new()
+            .sequences(["a_seq", "b_seq"])
+            .if_exists()
+            .cascade()
+            .to_sql(),
+        @r#"DROP SEQUENCE IF EXISTS "a_seq", "b_seq" CASCADE"#
+    );
+}
+
+#[test]
+fn sequence_functions() {
+    assert_snapshot!(
+        Select::new()
+            .expr(FunctionCall::nextval(("public", "Order_seq")))
+            .expr(FunctionCall::currval("order_seq"))
+            .expr(FunctionCall::setval("order_seq", 42))
+            .expr(FunctionCall::setval_with_is_called(
+                "order_seq",
+                Expr::column("max_id"),
+                false
+            ))
+            .to_sql(),
+        @r#"SELECT NEXTVAL('"public"."Order_seq"'), CURRVAL('"order_seq"'), SETVAL('"order_seq"', 42), SETVAL('"order_seq"', "max_id", FALSE)"#
+    );
+
+    let (sql, values) = Select::new()
+        .expr(FunctionCall::nextval("order_seq"))
+        .to_values()
+        .into_parts();
+    assert_snapshot!(sql, @r#"SELECT NEXTVAL($1)"#);
+    assert_eq!(values.len(), 1);
+}
@@ -0,0 +1,131 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "serde")]
+
+use insta::assert_snapshot;
+use pqb::expr::Expr;
+use pqb::func::FunctionCall;
+use pqb::query::CommonTableExpression;
+use pqb::query::Insert;
+use pqb::query::OnConflict;
+use pqb::query::Order;
+use pqb::query::RowLevelLock;
+use pqb::query::Select;
+use pqb::query::With;
+use pqb::table::ColumnDef;
+use pqb::table::ColumnType;
+use pqb::table::CreateTable;
+use pqb::types::Asterisk;
+use pqb::types::ColumnRef;
+use pqb::types::Iden;
+use pqb::types::TableName;
+
+fn round_trip<T>(value: &T) -> T
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    let json = serde_json::to_string(value).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn serde_names() {
+    assert_snapshot!(serde_json::to_string(&Iden::new("user id")).unwrap(), @r#""user id""#);
+    assert_snapshot!(
+        serde_json::to_string(&TableName::from(("public", "users"))).unwrap(),
+        @r#"["public","users"]"#
+    );
+    assert_snapshot!(
+        serde_json::to_string(&ColumnRef::from(("users", "id"))).unwrap(),
+        @r#"{"Column":["users","id"]}"#
+    );
+    assert_snapshot!(
+        serde_json::to_string(&ColumnRef::from(Asterisk)).unwrap(),
+        @r#"{"Asterisk":null}"#
+    );
+
+    let table: TableName = serde_json::from_str(r#"["db", "public", "users"]"#).unwrap();
+    assert_eq!(table, TableName::from(("db", "public", "users")));
+    assert!(serde_json::from_str::<TableName>("[]").is_err());
+    assert!(serde_json::from_str::<TableName>(r#"["a", "b", "c", "d"]"#).is_err());
+}
+
+#[test]
+fn serde_expr() {
+    let expr = Expr::column("id")
+        .eq(1)
+        .and(Expr::column("name").is_null().not());
+    assert_snapshot!(
+        serde_json::to_string(&expr).unwrap(),
+        @r#"{"Binary":[{"Binary":[{"Column":{"Column":["id"]}},"Equal",{"Value":{"Int":1}}]},"And",{"Unary":["Not",{"Binary":[{"Column":{"Column":["name"]}},"Is",{"Keyword":"Null"}]}]}]}"#
+    );
+    assert_eq!(round_trip(&expr), expr);
+}
+
+#[test]
+fn serde_select() {
+    let select = Select::new()
+        .with(
+            With::new().cte(
+                CommonTableExpression::new("recent")
+                    .select(Select::new().expr(Expr::asterisk()).from("events")),
+            ),
+        )
+        .column(("r", "id"))
+        .expr_as(FunctionCall::count(Expr::asterisk()), "n")
+        .from_as(("public", "recent"), "r")
+        .left_join(
+            "users",
+            Expr::column(("users", "id")).eq(Expr::column(("r", "user_id"))),
+        )
+        .and_where(Expr::column(("r", "kind")).is_in(["a", "b"]))
+        .group_by_columns([("r", "id")])
+        .order_by([Order::column("n").desc().nulls_last()])
+        .limit(10)
+        .lock(RowLevelLock::for_update().skip_locked());
+
+    let restored = round_trip(&select);
+    assert_eq!(restored, select);
+    assert_eq!(restored.to_sql(), select.to_sql());
+    assert_snapshot!(
+        restored.to_sql(),
+        @r#"WITH "recent" AS (SELECT * FROM "events") SELECT "r"."id", COUNT(*) AS "n" FROM "public"."recent" AS "r" LEFT JOIN "users" ON "users"."id" = "r"."user_id" WHERE "r"."kind" IN ('a', 'b') GROUP BY "r"."id" ORDER BY "n" DESC NULLS LAST LIMIT 10 FOR UPDATE SKIP LOCKED"#
+    );
+}
+
+#[test]
+fn serde_insert() {
+    let insert = Insert::new()
+        .into_table("glyph")
+        .columns(["aspect", "image"])
+        .values(["abc".into(), 1.5.into()])
+        .on_conflict(OnConflict::column("id").update_column("aspect"));
+
+    let restored = round_trip(&insert);
+    assert_eq!(restored, insert);
+    assert_eq!(restored.to_sql(), insert.to_sql());
+}
+
+#[test]
+fn serde_create_table() {
+    let create = CreateTable::new()
+        .table("users")
+        .column(ColumnDef::new("id").bigint().not_null())
+        .column(ColumnDef::new("tags").array_of(ColumnType::Text))
+        .column(ColumnDef::new("created_at").timestamp_with_time_zone());
+
+    let restored = round_trip(&create);
+    assert_eq!(restored.to_sql(), create.to_sql());
+}
@@ -0,0 +1,202 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use insta::assert_snapshot;
+use pqb::expr::Expr;
+use pqb::func::FunctionCall;
+use pqb::query::Select;
+use pqb::session::Begin;
+use pqb::session::Commit;
+use pqb::session::IsolationLevel;
+use pqb::session::Listen;
+use pqb::session::LockMode;
+use pqb::session::LockTable;
+use pqb::session::Notify;
+use pqb::session::ReleaseSavepoint;
+use pqb::session::Reset;
+use pqb::session::Rollback;
+use pqb::session::RollbackToSavepoint;
+use pqb::session::Savepoint;
+use pqb::session::Set;
+use pqb::session::SetConstraints;
+use pqb::session::SetTransaction;
+use pqb::session::Show;
+use pqb::session::Unlisten;
+use pqb::value::Value;
+
+#[test]
+fn begin() {
+    assert_snapshot!(Begin::new().to_sql(), @"BEGIN");
+    assert_snapshot!(
+        Begin::new()
+            .isolation_level(IsolationLevel::Serializable)
+            .read_only()
+            .deferrable()
+            .to_sql(),
+        @"BEGIN ISOLATION LEVEL SERIALIZABLE, READ ONLY, DEFERRABLE"
+    );
+    assert_snapshot!(
+        Begin::new()
+            .isolation_level(IsolationLevel::RepeatableRead)
+            .read_write()
+            .not_deferrable()
+            .to_sql(),
+        @"BEGIN ISOLATION LEVEL REPEATABLE READ, READ WRITE, NOT DEFERRABLE"
+    );
+}
+
+#[test]
+fn commit_and_rollback() {
+    assert_snapshot!(Commit::new().to_sql(), @"COMMIT");
+    assert_snapshot!(Commit::new().and_chain().to_sql(), @"COMMIT AND CHAIN");
+    assert_snapshot!(Rollback::new().to_sql(), @"ROLLBACK");
+    assert_snapshot!(Rollback::new().and_chain().to_sql(), @"ROLLBACK AND CHAIN");
+}
+
+#[test]
+fn savepoints() {
+    assert_snapshot!(Savepoint::new().name("before_import").to_sql(), @r#"SAVEPOINT "before_import""#);
+    assert_snapshot!(ReleaseSavepoint::new().name("before_import").to_sql(), @r#"RELEASE SAVEPOINT "before_import""#);
+    assert_snapshot!(RollbackToSavepoint::new().name("before_import").to_sql(), @r#"ROLLBACK TO SAVEPOINT "before_import""#);
+}
+
+#[test]
+fn set_transaction() {
+    assert_snapshot!(
+        SetTransaction::new()
+            .isolation_level(IsolationLevel::ReadCommitted)
+            .to_sql(),
+        @"SET TRANSACTION ISOLATION LEVEL READ COMMITTED"
+    );
+    assert_snapshot!(
+        SetTransaction::new()
+            .session()
+            .isolation_level(IsolationLevel::ReadUncommitted)
+            .read_only()
+            .to_sql(),
+        @"SET SESSION CHARACTERISTICS AS TRANSACTION ISOLATION LEVEL READ UNCOMMITTED, READ ONLY"
+    );
+    assert_snapshot!(
+        SetTransaction::new()
+            .snapshot("00000003-0000001B-1")
+            .to_sql(),
+        @"SET TRANSACTION SNAPSHOT '00000003-0000001B-1'"
+    );
+}
+
+#[test]
+#[should_panic(expected = "SET SESSION CHARACTERISTICS cannot set a transaction snapshot.")]
+fn set_session_transaction_snapshot_should_panic() {
+    let _ = SetTransaction::new()
+        .session()
+        .snapshot("00000003-0000001B-1")
+        .to_sql();
+}
+
+#[test]
+#[should_panic(expected = "A transaction snapshot cannot be combined with transaction modes.")]
+fn set_transaction_snapshot_with_modes_should_panic() {
+    let _ = SetTransaction::new()
+        .snapshot("00000003-0000001B-1")
+        .read_only()
+        .to_sql();
+}
+
+#[test]
+fn set_constraints() {
+    assert_snapshot!(SetConstraints::new().deferred().to_sql(), @"SET CONSTRAINTS ALL DEFERRED");
+    assert_snapshot!(
+        SetConstraints::new()
+            .constraints(["orders_user_fk", "orders_item_fk"])
+            .immediate()
+            .to_sql(),
+        @r#"SET CONSTRAINTS "orders_user_fk", "orders_item_fk" IMMEDIATE"#
+    );
+}
+
+#[test]
+fn set_reset_show() {
+    assert_snapshot!(
+        Set::new().name("statement_timeout").value("5s").to_sql(),
+        @r#"SET "statement_timeout" = '5s'"#
+    );
+    assert_snapshot!(
+        Set::new().local().name("app.user_id").value("42").to_sql(),
+        @r#"SET LOCAL "app.user_id" = '42'"#
+    );
+    assert_snapshot!(
+        Set::new()
+            .name("search_path")
+            .values(["app", "public"])
+            .to_sql(),
+        @r#"SET "search_path" = 'app', 'public'"#
+    );
+    assert_snapshot!(Set::new().name("work_mem").to_sql(), @r#"SET "work_mem" TO DEFAULT"#);
+    assert_snapshot!(Reset::new().name("work_mem").to_sql(), @r#"RESET "work_mem""#);
+    assert_snapshot!(Reset::new().to_sql(), @"RESET ALL");
+    assert_snapshot!(Show::new().name("server_version").to_sql(), @r#"SHOW "server_version""#);
+    assert_snapshot!(Show::new().to_sql(), @"SHOW ALL");
+}
+
+#[test]
+fn lock_table() {
+    assert_snapshot!(LockTable::new().table("accounts").to_sql(), @r#"LOCK TABLE "accounts""#);
+    assert_snapshot!(
+        LockTable::new()
+            .only()
+            .tables([("app", "accounts"), ("app", "ledger")])
+            .mode(LockMode::ShareRowExclusive)
+            .nowait()
+            .to_sql(),
+        @r#"LOCK TABLE ONLY "app"."accounts", ONLY "app"."ledger" IN SHARE ROW EXCLUSIVE MODE NOWAIT"#
+    );
+    assert_snapshot!(
+        LockTable::new()
+            .table("accounts")
+            .mode(LockMode::AccessShare)
+            .to_sql(),
+        @r#"LOCK TABLE "accounts" IN ACCESS SHARE MODE"#
+    );
+}
+
+#[test]
+fn listen_notify() {
+    assert_snapshot!(Listen::new().channel("cache_invalidation").to_sql(), @r#"LISTEN "cache_invalidation""#);
+    assert_snapshot!(Unlisten::new().channel("Cache Invalidation").to_sql(), @r#"UNLISTEN "Cache Invalidation""#);
+    assert_snapshot!(Unlisten::new().to_sql(), @"UNLISTEN *");
+    assert_snapshot!(Notify::new().channel("cache_invalidation").to_sql(), @r#"NOTIFY "cache_invalidation""#);
+    let notify = Notify::new()
+        .channel("cache_invalidation")
+        .payload("users:42 isn't fresh");
+    assert_snapshot!(notify.to_sql(), @r#"NOTIFY "cache_invalidation", E'users:42 isn\'t fresh'"#);
+    let (sql, values) = notify.to_values().into_parts();
+    assert_eq!(sql, notify.to_sql());
+    assert!(values.is_empty());
+}
+
+#[test]
+fn pg_notify() {
+    let (sql, values) = Select::new()
+        .expr(FunctionCall::pg_notify(
+            "Cache Invalidation",
+            Expr::value("users:42"),
+        ))
+        .to_values()
+        .into_parts();
+    assert_snapshot!(sql, @"SELECT PG_NOTIFY($1, $2)");
+    assert_eq!(
+        values,
+        vec![Value::from("Cache Invalidation"), Value::from("users:42")]
+    );
+}
@@ -0,0 +1,125 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use insta::assert_snapshot;
+use pqb::expr::Expr;
+use pqb::routine::CreateFunction;
+use pqb::routine::Language;
+use pqb::trigger::CreateTrigger;
+use pqb::trigger::DropTrigger;
+
+#[test]
+fn create_trigger_row() {
+    assert_snapshot!(
+        CreateTrigger::new()
+            .or_replace()
+            .name("users_audit")
+            .after()
+            .on_insert()
+            .on_update_of(["email", "name"])
+            .on_delete()
+            .table(("app", "users"))
+            .for_each_row()
+            .when(Expr::column(("old", "email")).ne(Expr::column(("new", "email"))))
+            .execute_function(("audit", "log_change"))
+            .args(["users", "full"])
+            .to_sql(),
+        @r#"CREATE OR REPLACE TRIGGER "users_audit" AFTER INSERT OR UPDATE OF "email", "name" OR DELETE ON "app"."users" FOR EACH ROW WHEN ("old"."email" <> "new"."email") EXECUTE FUNCTION "audit"."log_change"('users', 'full')"#
+    );
+}
+
+#[test]
+fn create_trigger_statement() {
+    assert_snapshot!(
+        CreateTrigger::new()
+            .name("orders_batch_audit")
+            .after()
+            .on_update()
+            .table("orders")
+            .referencing_old_table("old_rows")
+            .referencing_new_table("new_rows")
+            .for_each_statement()
+            .execute_function("log_order_batch")
+            .to_sql(),
+        @r#"CREATE TRIGGER "orders_batch_audit" AFTER UPDATE ON "orders" REFERENCING OLD TABLE AS "old_rows" NEW TABLE AS "new_rows" FOR EACH STATEMENT EXECUTE FUNCTION "log_order_batch"()"#
+    );
+    assert_snapshot!(
+        CreateTrigger::new()
+            .name("no_truncate")
+            .before()
+            .on_truncate()
+            .table("ledger")
+            .execute_function("reject")
+            .to_sql(),
+        @r#"CREATE TRIGGER "no_truncate" BEFORE TRUNCATE ON "ledger" EXECUTE FUNCTION "reject"()"#
+    );
+    assert_snapshot!(
+        CreateTrigger::new()
+            .name("view_insert")
+            .instead_of()
+            .on_insert()
+            .table("active_users")
+            .for_each_row()
+            .execute_function("insert_active_user")
+            .to_sql(),
+        @r#"CREATE TRIGGER "view_insert" INSTEAD OF INSERT ON "active_users" FOR EACH ROW EXECUTE FUNCTION "insert_active_user"()"#
+    );
+}
+
+#[test]
+fn create_constraint_trigger() {
+    assert_snapshot!(
+        CreateTrigger::new()
+            .constraint()
+            .name("check_balance")
+            .after()
+            .on_insert()
+            .on_update()
+            .table("entries")
+            .from("accounts")
+            .deferrable()
+            .initially_deferred()
+            .for_each_row()
+            .execute_function("verify_balance")
+            .to_sql(),
+        @r#"CREATE CONSTRAINT TRIGGER "check_balance" AFTER INSERT OR UPDATE ON "entries" FROM "accounts" DEFERRABLE INITIALLY DEFERRED FOR EACH ROW EXECUTE FUNCTION "verify_balance"()"#
+    );
+}
+
+#[test]
+fn create_trigger_function() {
+    assert_snapshot!(
+        CreateFunction::new()
+            .or_replace()
+            .name("touch_updated_at")
+            .returns_trigger()
+            .language(Language::PlPgSql)
+            .body("BEGIN NEW.updated_at := now(); RETURN NEW; END")
+            .to_sql(),
+        @r#"CREATE OR REPLACE FUNCTION "touch_updated_at"() RETURNS trigger LANGUAGE plpgsql AS $$BEGIN NEW.updated_at := now(); RETURN NEW; END$$"#
+    );
+}
+
+#[test]
+fn drop_trigger() {
+    assert_snapshot!(
+        DropTrigger::new()
+            .if_exists()
+            .name("users_audit")
+            .table(("app", "users"))
+            .cascade()
+            .to_sql(),
+        @r#"DROP TRIGGER IF EXISTS "users_audit" ON "app"."users" CASCADE"#
+    );
+}
@@ -0,0 +1,151 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use insta::assert_snapshot;
+use pqb::expr::Expr;
+use pqb::func::FunctionCall;
+use pqb::query::Select;
+use pqb::table::ColumnDef;
+use pqb::table::ColumnType;
+use pqb::table::CreateTable;
+use pqb::types::TypeName;
+use pqb::user_type::AlterType;
+use pqb::user_type::CreateDomain;
+use pqb::user_type::CreateType;
+use pqb::user_type::DropDomain;
+use pqb::user_type::DropType;
+use pqb::user_type::RangeType;
+
+#[test]
+fn create_enum_type() {
+    assert_snapshot!(
+        CreateType::new()
+            .name("mood")
+            .as_enum(["sad", "ok", "it's great"])
+            .to_sql(),
+        @r#"CREATE TYPE "mood" AS ENUM ('sad', 'ok', E'it\'s great')"#
+    );
+
+    let (sql, values) = CreateType::new()
+        .name(("app", "mood"))
+        .as_enum(["sad", "ok"])
+        .to_values()
+        .into_parts();
+    assert_snapshot!(sql, @r#"CREATE TYPE "app"."mood" AS ENUM ('sad', 'ok')"#);
+    assert!(values.is_empty());
+}
+
+#[test]
+fn create_composite_and_range_type() {
+    assert_snapshot!(
+        CreateType::new()
+            .name("complex")
+            .attribute("r", ColumnType::Double)
+            .attribute("i", ColumnType::Double)
+            .to_sql(),
+        @r#"CREATE TYPE "complex" AS ("r" double precision, "i" double precision)"#
+    );
+    assert_snapshot!(
+        CreateType::new()
+            .name("floatrange")
+            .as_range(
+                RangeType::subtype(ColumnType::Double)
+                    .subtype_diff("float8mi")
+                    .multirange_type_name("floatmultirange"),
+            )
+            .to_sql(),
+        @r#"CREATE TYPE "floatrange" AS RANGE (SUBTYPE = double precision, SUBTYPE_DIFF = "float8mi", MULTIRANGE_TYPE_NAME = "floatmultirange")"#
+    );
+    assert_snapshot!(CreateType::new().name("shell").to_sql(), @r#"CREATE TYPE "shell""#);
+}
+
+#[test]
+fn alter_type() {
+    assert_snapshot!(
+        AlterType::new().name("mood").add_value("happy").to_sql(),
+        @r#"ALTER TYPE "mood" ADD VALUE 'happy'"#
+    );
+    assert_snapshot!(
+        AlterType::new()
+            .name("mood")
+            .add_value_before("meh", "ok")
+            .if_not_exists()
+            .to_sql(),
+        @r#"ALTER TYPE "mood" ADD VALUE IF NOT EXISTS 'meh' BEFORE 'ok'"#
+    );
+    assert_snapshot!(
+        AlterType::new()
+            .name("mood")
+            .add_value_after("ecstatic", "happy")
+            .to_sql(),
+        @r#"ALTER TYPE "mood" ADD VALUE 'ecstatic' AFTER 'happy'"#
+    );
+    assert_snapshot!(
+        AlterType::new()
+            .name("mood")
+            .rename_value("sad", "blue")
+            .to_sql(),
+        @r#"ALTER TYPE "mood" RENAME VALUE 'sad' TO 'blue'"#
+    );
+}
+
+#[test]
+fn create_domain() {
+    assert_snapshot!(
+        CreateDomain::new()
+            .name("us_postal_code")
+            .as_type(ColumnType::Text)
+            .collate("C")
+            .default(Expr::value("00000"))
+            .not_null()
+            .check(Expr::custom("VALUE").like("_____"))
+            .named_check("not_zero", Expr::custom("VALUE").ne("00000"))
+            .to_sql(),
+        @r#"CREATE DOMAIN "us_postal_code" AS text COLLATE "C" DEFAULT '00000' NOT NULL CHECK ((VALUE) LIKE '_____') CONSTRAINT "not_zero" CHECK ((VALUE) <> '00000')"#
+    );
+}
+
+#[test]
+fn drop_types() {
+    assert_snapshot!(
+        DropType::new()
+            .names(["mood", "complex"])
+            .if_exists()
+            .cascade()
+            .to_sql(),
+        @r#"DROP TYPE IF EXISTS "mood", "complex" CASCADE"#
+    );
+    assert_snapshot!(
+        DropDomain::new().name("us_postal_code").restrict().to_sql(),
+        @r#"DROP DOMAIN "us_postal_code" RESTRICT"#
+    );
+}
+
+#[test]
+fn use_custom_types() {
+    assert_snapshot!(
+        CreateTable::new()
+            .table("person")
+            .column(ColumnDef::new("current_mood").custom("mood"))
+            .column(ColumnDef::new("moods").array_of(ColumnType::Custom(TypeName::from(("app", "mood")))))
+            .to_sql(),
+        @r#"CREATE TABLE "person" ( "current_mood" "mood", "moods" "app"."mood"[] )"#
+    );
+    assert_snapshot!(
+        Select::new()
+            .expr(FunctionCall::cast_as_type(Expr::value("ok"), TypeName::from("mood")))
+            .to_sql(),
+        @r#"SELECT CAST('ok' AS "mood")"#
+    );
+}
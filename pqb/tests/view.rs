@@ -0,0 +1,126 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use insta::assert_snapshot;
+use pqb::expr::Expr;
+use pqb::query::Select;
+use pqb::view::CreateMaterializedView;
+use pqb::view::CreateView;
+use pqb::view::DropMaterializedView;
+use pqb::view::DropView;
+use pqb::view::RefreshMaterializedView;
+use pqb::view::ViewCheckOption;
+
+fn active_users() -> Select {
+    Select::new()
+        .columns(["id", "name"])
+        .from("users")
+        .and_where(Expr::column("active").eq(true))
+}
+
+#[test]
+fn create_view() {
+    assert_snapshot!(
+        CreateView::new()
+            .view("active_users")
+            .select(active_users())
+            .to_sql(),
+        @r#"CREATE VIEW "active_users" AS SELECT "id", "name" FROM "users" WHERE "active" = TRUE"#
+    );
+    assert_snapshot!(
+        CreateView::new()
+            .or_replace()
+            .temporary()
+            .view(("public", "active_users"))
+            .columns(["user_id", "user_name"])
+            .with_option("security_barrier", true)
+            .select(active_users())
+            .check_option(ViewCheckOption::Local)
+            .to_sql(),
+        @r#"CREATE OR REPLACE TEMPORARY VIEW "public"."active_users" ("user_id", "user_name") WITH ("security_barrier" = TRUE) AS SELECT "id", "name" FROM "users" WHERE "active" = TRUE WITH LOCAL CHECK OPTION"#
+    );
+    assert_snapshot!(
+        CreateView::new()
+            .recursive()
+            .view("nums")
+            .column("n")
+            .with_option("check_option", "cascaded")
+            .select(Select::new().expr(Expr::value(1)))
+            .check_option(ViewCheckOption::Cascaded)
+            .to_sql(),
+        @r#"CREATE RECURSIVE VIEW "nums" ("n") WITH ("check_option" = 'cascaded') AS SELECT 1 WITH CASCADED CHECK OPTION"#
+    );
+}
+
+#[test]
+fn create_materialized_view() {
+    assert_snapshot!(
+        CreateMaterializedView::new()
+            .if_not_exists()
+            .view("user_totals")
+            .columns(["user_id", "total"])
+            .using("heap")
+            .with_option("fillfactor", 70)
+            .tablespace("fast")
+            .select(active_users())
+            .with_data(false)
+            .to_sql(),
+        @r#"CREATE MATERIALIZED VIEW IF NOT EXISTS "user_totals" ("user_id", "total") USING "heap" WITH ("fillfactor" = 70) TABLESPACE "fast" AS SELECT "id", "name" FROM "users" WHERE "active" = TRUE WITH NO DATA"#
+    );
+    assert_snapshot!(
+        CreateMaterializedView::new()
+            .view("user_totals")
+            .select(active_users())
+            .with_data(true)
+            .to_sql(),
+        @r#"CREATE MATERIALIZED VIEW "user_totals" AS SELECT "id", "name" FROM "users" WHERE "active" = TRUE WITH DATA"#
+    );
+}
+
+#[test]
+fn refresh_materialized_view() {
+    assert_snapshot!(
+        RefreshMaterializedView::new()
+            .concurrently()
+            .view("user_totals")
+            .to_sql(),
+        @r#"REFRESH MATERIALIZED VIEW CONCURRENTLY "user_totals""#
+    );
+    assert_snapshot!(
+        RefreshMaterializedView::new()
+            .view(("reports", "user_totals"))
+            .with_data(false)
+            .to_sql(),
+        @r#"REFRESH MATERIALIZED VIEW "reports"."user_totals" WITH NO DATA"#
+    );
+}
+
+#[test]
+fn drop_views() {
+    assert_snapshot!(
+        DropView::new()
+            .views(["a", "b"])
+            .if_exists()
+            .cascade()
+            .to_sql(),
+        @r#"DROP VIEW IF EXISTS "a", "b" CASCADE"#
+    );
+    assert_snapshot!(
+        DropMaterializedView::new()
+            .view("user_totals")
+            .restrict()
+            .to_sql(),
+        @r#"DROP MATERIALIZED VIEW "user_totals" RESTRICT"#
+    );
+}